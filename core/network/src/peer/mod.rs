@@ -1,6 +1,24 @@
+use std::{
+    io::{self, Error, ErrorKind},
+    net::IpAddr,
+    time::SystemTime,
+};
+
+use avalanche_types::message;
+
 pub mod inbound;
 pub mod outbound;
 
+/// Information the local node learns about a peer once the post-TLS
+/// handshake ("Version" + "PeerList" exchange) completes.
+/// ref. <https://github.com/ava-labs/avalanchego/blob/master/network/peer/peer.go> "readHandshake"
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HandshakeInfo {
+    pub remote_network_id: u32,
+    pub remote_version: String,
+    pub remote_peer_count: usize,
+}
+
 /// Represents a remote peer from the local node.
 /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/network/peer#Start>
 pub struct Peer {
@@ -16,13 +34,91 @@ impl Peer {
             ready: false,
         }
     }
+
+    /// Performs the AvalancheGo post-TLS handshake on top of `self.stream`:
+    /// sends our "Version" message, then reads back the remote's "Version"
+    /// followed by its "PeerList", and checks that the peer list includes an
+    /// entry certified by the same staking certificate the TLS connection
+    /// already authenticated (i.e. the peer is claiming an IP it can actually
+    /// back with the key the connection was established with). Marks the
+    /// peer "ready" once this all succeeds.
+    ///
+    /// ref. <https://github.com/ava-labs/avalanchego/blob/master/network/peer/peer.go> "readHandshake"/"sendVersion"
+    ///
+    /// NOTE: `outbound::Stream` is blocking I/O (see its doc comment); this
+    /// method is only `async` so it composes with the rest of the
+    /// tokio-based peer-management code, and will block its executor thread
+    /// for the duration of the handshake.
+    pub async fn handshake(
+        &mut self,
+        network_id: u32,
+        my_version: String,
+        my_ip: IpAddr,
+        my_ip_port: u16,
+    ) -> io::Result<HandshakeInfo> {
+        let my_time = SystemTime::now()
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .map_err(|e| {
+                Error::new(
+                    ErrorKind::Other,
+                    format!("system clock before unix epoch: {e}"),
+                )
+            })?
+            .as_secs();
+
+        let version_msg = message::version::Message::default()
+            .network_id(network_id)
+            .my_time(my_time)
+            .ip_addr(my_ip)
+            .ip_port(u32::from(my_ip_port))
+            .my_version(my_version);
+        self.stream.write_msg(version_msg.serialize()?)?;
+
+        let remote_version_frame = self.stream.read_msg()?;
+        let remote_version = message::version::Message::deserialize(&remote_version_frame)?;
+
+        let remote_peerlist_frame = self.stream.read_msg()?;
+        let remote_peerlist = message::peerlist::Message::deserialize(&remote_peerlist_frame)?;
+
+        // The peer's own entry in its peer list should be certified by the
+        // same certificate the TLS handshake already authenticated -- else
+        // it is advertising an IP it can't actually back with its staking
+        // key.
+        let remote_cert = self.stream.peer_certificate.as_ref();
+        let claims_own_cert = remote_peerlist
+            .msg
+            .claimed_ip_ports
+            .iter()
+            .any(|c| c.x509_certificate.as_ref() == remote_cert);
+        if !claims_own_cert {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                "peer list contained no entry certified by the peer's TLS certificate",
+            ));
+        }
+
+        self.ready = true;
+
+        Ok(HandshakeInfo {
+            remote_network_id: remote_version.msg.network_id,
+            remote_version: remote_version.msg.my_version,
+            remote_peer_count: remote_peerlist.msg.claimed_ip_ports.len(),
+        })
+    }
+
+    /// Sends a "Ping" and blocks for the matching "Pong", returning the
+    /// peer-reported uptime.
+    pub async fn ping_pong(&mut self, my_uptime: u32) -> io::Result<u32> {
+        let ping_msg = message::ping::Message::default().uptime(my_uptime);
+        self.stream.write_msg(ping_msg.serialize()?)?;
+
+        let pong_frame = self.stream.read_msg()?;
+        let pong_msg = message::pong::Message::deserialize(&pong_frame)?;
+        Ok(pong_msg.msg.uptime)
+    }
 }
 
 /// RUST_LOG=debug cargo test --package network --lib -- peer::test::test_listener --exact --show-output
-///
-/// TODO: make this test work. The client and server are both initialized correctly,
-/// but making a connection fails.
-/// Error is Os { code: 61, kind: ConnectionRefused, message: "Connection refused" } when connecting client to server.
 #[cfg(test)]
 mod test {
     use rcgen::CertificateParams;
@@ -33,21 +129,56 @@ mod test {
         sync::Arc,
         time::Duration,
     };
-    use tokio::net::TcpListener;
+    use tokio::{
+        io::{AsyncReadExt, AsyncWriteExt},
+        net::TcpListener,
+    };
     use tokio_rustls::TlsAcceptor;
 
+    use avalanche_types::message;
+
     use crate::cert_manager;
-    use crate::peer::outbound;
+    use crate::peer::{outbound, Peer};
+
+    /// Writes one AvalancheGo-framed message (4-byte big-endian length
+    /// prefix + body) to an async stream, mirroring
+    /// [`outbound::Stream::write_msg`] for the (tokio-based) server side of
+    /// the test.
+    async fn write_framed<S>(stream: &mut S, msg: &[u8]) -> io::Result<()>
+    where
+        S: tokio::io::AsyncWrite + Unpin,
+    {
+        let len = u32::try_from(msg.len())
+            .map_err(|_| io::Error::new(io::ErrorKind::InvalidInput, "message too large"))?;
+        stream.write_all(&len.to_be_bytes()).await?;
+        stream.write_all(msg).await?;
+        stream.flush().await
+    }
+
+    /// Reads one AvalancheGo-framed message, mirroring
+    /// [`outbound::Stream::read_msg`].
+    async fn read_framed<S>(stream: &mut S) -> io::Result<Vec<u8>>
+    where
+        S: tokio::io::AsyncRead + Unpin,
+    {
+        let mut len_buf = [0u8; 4];
+        stream.read_exact(&mut len_buf).await?;
+        let len = u32::from_be_bytes(len_buf) as usize;
+
+        let mut msg = vec![0u8; len];
+        stream.read_exact(&mut msg).await?;
+        Ok(msg)
+    }
 
     #[tokio::test]
-    #[ignore]
     async fn test_listener() -> io::Result<()> {
         let _ = env_logger::builder()
             .filter_level(log::LevelFilter::Trace)
-            // .is_test(true)
+            .is_test(true)
             .try_init();
 
         let addr = SocketAddr::new(IpAddr::V4(Ipv4Addr::LOCALHOST), 9649);
+        const NETWORK_ID: u32 = 9999;
 
         let server_key_path = random_manager::tmp_path(10, None)?;
         let server_cert_path = random_manager::tmp_path(10, None)?;
@@ -63,6 +194,7 @@ mod test {
             server_key_path.as_ref(),
             server_cert_path.as_ref(),
         )?;
+        let server_certificate_bytes = certificate.0.clone();
 
         let join_handle = tokio::task::spawn(async move {
             let server_config = ServerConfig::builder()
@@ -74,21 +206,58 @@ mod test {
             let tls_acceptor = TlsAcceptor::from(Arc::new(server_config));
             let tcp_listener = TcpListener::bind(addr).await.unwrap();
 
-            loop {
-                let (stream, _) = tcp_listener.accept().await.unwrap();
-                let tls_acceptor = tls_acceptor.clone();
-                log::info!("accepting TLS connection");
-                let _ = tokio::spawn(async move {
-                    match tls_acceptor.accept(stream).await {
-                        Ok(_tls_stream) => {
-                            println!("TLS connection accepted");
-                            // handle(tls_stream).await
-                        }
-                        Err(e) => eprintln!("Error accepting TLS connection: {:?}", e),
-                    }
-                })
-                .await;
-            }
+            // only one peer connects in this test, so handle it and return
+            // instead of looping forever.
+            let (stream, _) = tcp_listener.accept().await.unwrap();
+            log::info!("accepting TLS connection");
+            let mut tls_stream = tls_acceptor.accept(stream).await.unwrap();
+            log::info!("TLS connection accepted");
+
+            // post-handshake protocol: read the client's "Version", reply
+            // with our own "Version" and a "PeerList" that claims our own
+            // (TLS-authenticated) certificate, then answer one "Ping" with a
+            // "Pong".
+            let client_version_frame = read_framed(&mut tls_stream).await.unwrap();
+            let client_version = message::version::Message::deserialize(&client_version_frame)
+                .expect("failed to deserialize client Version");
+            log::info!(
+                "server received client Version (network_id={})",
+                client_version.msg.network_id
+            );
+
+            let server_version = message::version::Message::default()
+                .network_id(NETWORK_ID)
+                .my_time(7_777_777)
+                .ip_addr(addr.ip())
+                .ip_port(u32::from(addr.port()))
+                .my_version("v1.2.3".to_string());
+            write_framed(&mut tls_stream, &server_version.serialize().unwrap())
+                .await
+                .unwrap();
+
+            let server_peerlist = message::peerlist::Message::default().claimed_ip_ports(vec![
+                message::peerlist::ClaimedIpPort {
+                    certificate: server_certificate_bytes,
+                    ip_addr: addr.ip(),
+                    ip_port: u32::from(addr.port()),
+                    time: 7_777_777,
+                    sig: vec![],
+                    tx_id: avalanche_types::ids::Id::empty(),
+                },
+            ]);
+            write_framed(&mut tls_stream, &server_peerlist.serialize().unwrap())
+                .await
+                .unwrap();
+
+            let ping_frame = read_framed(&mut tls_stream).await.unwrap();
+            let ping = message::ping::Message::deserialize(&ping_frame)
+                .expect("failed to deserialize client Ping");
+            log::info!("server received Ping (uptime={})", ping.msg.uptime);
+
+            let pong = message::pong::Message::default().uptime(100);
+            write_framed(&mut tls_stream, &pong.serialize().unwrap())
+                .await
+                .unwrap();
         });
 
         let client_key_path = random_manager::tmp_path(10, None)?;
@@ -106,7 +275,19 @@ mod test {
 
         log::info!("peer certificate:\n\n{}", stream.peer_certificate_pem);
 
-        join_handle.await?; // Hangs
+        let mut peer = Peer::new(stream);
+        let handshake_info = peer
+            .handshake(NETWORK_ID, "v1.2.3".to_string(), addr.ip(), addr.port())
+            .await?;
+        log::info!("handshake complete: {:?}", handshake_info);
+        assert_eq!(handshake_info.remote_network_id, NETWORK_ID);
+        assert_eq!(handshake_info.remote_peer_count, 1);
+        assert!(peer.ready);
+
+        let remote_uptime = peer.ping_pong(42).await?;
+        assert_eq!(remote_uptime, 100);
+
+        join_handle.await?;
 
         Ok(())
     }
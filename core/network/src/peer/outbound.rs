@@ -21,6 +21,15 @@ type Certificate = rustls::pki_types::CertificateDer<'static>;
 pub struct Connector {
     /// The client configuration of the local/source node for outbound TLS connections.
     pub client_config: Arc<ClientConfig>,
+
+    /// The local/source node's own staking certificate, as presented for TLS
+    /// client authentication and copied onto every [`Stream`] this connector
+    /// creates. The post-TLS handshake in `peer::Peer::handshake` does not
+    /// send it in any message.
+    pub my_certificate: Certificate,
+    /// The local/source node's Id, derived from `my_certificate` the same way
+    /// [`Stream::peer_node_id`] is derived from the remote certificate.
+    pub my_node_id: node::Id,
 }
 
 impl Connector {
@@ -31,6 +40,8 @@ impl Connector {
     {
         let (private_key, certificate) =
             cert_manager::x509::load_pem_key_cert_to_der(key_path.as_ref(), cert_path.as_ref())?;
+        let my_node_id = node::Id::from_cert_der_bytes(&certificate)?;
+        let my_certificate = certificate.clone();
 
         // NOTE: AvalancheGo/* uses TLS key pair for exchanging node IDs without hostname authentication.
         // Thus, ok to skip CA verification, to be consistent with Go tls.Config.InsecureSkipVerify.
@@ -49,6 +60,8 @@ impl Connector {
 
         Ok(Self {
             client_config: Arc::new(config),
+            my_certificate,
+            my_node_id,
         })
     }
 
@@ -60,10 +73,10 @@ impl Connector {
         let server_name = ServerName::IpAddress(addr.ip().into());
         let mut conn =
             rustls::ClientConnection::new(self.client_config.clone(), server_name).unwrap();
+        let mut tcp = TcpStream::connect(addr).unwrap();
 
         {
-            let mut sock = TcpStream::connect(addr).unwrap();
-            let mut tls = rustls::Stream::new(&mut conn, &mut sock);
+            let mut tls = rustls::Stream::new(&mut conn, &mut tcp);
 
             let binding = format!("GET / HTTP/1.1\r\nHost: {addr}\r\nConnection: close\r\nAccept-Encoding: identity\r\n\r\n");
 
@@ -118,6 +131,10 @@ impl Connector {
             peer_certificate_pem,
 
             conn,
+            tcp,
+
+            my_certificate: self.my_certificate.clone(),
+            my_node_id: self.my_node_id,
         })
     }
 }
@@ -195,9 +212,21 @@ pub struct Stream {
     /// ref. <https://docs.rs/rustls/latest/rustls/client/struct.ClientConnection.html>
     pub conn: ClientConnection,
 
+    /// The underlying TCP socket backing `conn`. Kept alongside `conn`
+    /// (rather than let it go out of scope once the TLS handshake completes,
+    /// as the initial dummy GET write used to) since every `write`/`read`
+    /// after the handshake needs it to actually move bytes on the wire.
+    pub tcp: TcpStream,
+
     pub peer_certificate: Certificate,
     pub peer_node_id: node::Id,
 
+    /// The local/source node's own certificate and Id, copied from the
+    /// `Connector` that created this `Stream`. See
+    /// [`Connector::my_certificate`].
+    pub my_certificate: Certificate,
+    pub my_node_id: node::Id,
+
     #[cfg(feature = "pem")]
     pub peer_certificate_pem: String,
 }
@@ -205,7 +234,8 @@ pub struct Stream {
 impl Stream {
     pub fn close(&mut self) -> io::Result<()> {
         self.conn.send_close_notify();
-        Ok(())
+        let mut tls = rustls::Stream::new(&mut self.conn, &mut self.tcp);
+        tls.flush()
     }
 
     /// Writes to the connection.
@@ -213,15 +243,49 @@ impl Stream {
     where
         S: AsRef<[u8]>,
     {
-        let mut wr = self.conn.writer();
-        wr.write(d.as_ref())
+        let mut tls = rustls::Stream::new(&mut self.conn, &mut self.tcp);
+        tls.write(d.as_ref())
     }
 
-    /// Reads from the connection.
+    /// Reads from the connection until the peer closes it. Only useful for a
+    /// one-shot exchange (e.g. the "peer_outbound_ping" example); a
+    /// long-lived connection that stays open across multiple messages should
+    /// use [`Stream::write_msg`]/[`Stream::read_msg`] instead.
     pub fn read(&mut self) -> io::Result<Vec<u8>> {
-        let mut rd = self.conn.reader();
+        let mut tls = rustls::Stream::new(&mut self.conn, &mut self.tcp);
         let mut d = Vec::new();
-        let _ = rd.read_to_end(&mut d)?;
+        let _ = tls.read_to_end(&mut d)?;
         Ok(d)
     }
+
+    /// Writes one AvalancheGo-framed P2P message: a 4-byte big-endian length
+    /// prefix followed by the message bytes.
+    /// ref. <https://github.com/ava-labs/avalanchego/blob/master/network/peer/msg_length.go>
+    pub fn write_msg<S>(&mut self, msg: S) -> io::Result<()>
+    where
+        S: AsRef<[u8]>,
+    {
+        let msg = msg.as_ref();
+        let len = u32::try_from(msg.len())
+            .map_err(|_| Error::new(ErrorKind::InvalidInput, "message too large to frame"))?;
+
+        let mut tls = rustls::Stream::new(&mut self.conn, &mut self.tcp);
+        tls.write_all(&len.to_be_bytes())?;
+        tls.write_all(msg)?;
+        tls.flush()
+    }
+
+    /// Reads one AvalancheGo-framed P2P message, blocking until the full
+    /// 4-byte length prefix and message body have arrived.
+    pub fn read_msg(&mut self) -> io::Result<Vec<u8>> {
+        let mut tls = rustls::Stream::new(&mut self.conn, &mut self.tcp);
+
+        let mut len_buf = [0u8; 4];
+        tls.read_exact(&mut len_buf)?;
+        let len = u32::from_be_bytes(len_buf) as usize;
+
+        let mut msg = vec![0u8; len];
+        tls.read_exact(&mut msg)?;
+        Ok(msg)
+    }
 }
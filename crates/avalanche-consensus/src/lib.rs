@@ -7,6 +7,8 @@
 //! See <https://docs.avax.network/learn/avalanche/avalanche-consensus>
 //! and the Avalanche whitepaper for more information.
 pub mod context;
+pub mod metrics;
+pub mod outstanding_polls;
 pub mod snowman;
 
 use avalanche_types::errors::{Error, Result};
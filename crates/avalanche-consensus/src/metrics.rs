@@ -0,0 +1,73 @@
+//! Prometheus instrumentation for the Snowball/Snowflake consensus instances.
+//!
+//! Metrics are lazily created and registered against the process-wide default
+//! registry the first time this module is referenced; modules increment them
+//! inline from their hot paths. [`gather`] renders the standard Prometheus text
+//! exposition format so operators can scrape consensus convergence behavior.
+use lazy_static::lazy_static;
+use prometheus::{
+    register_histogram, register_int_counter, register_int_gauge, Encoder, Histogram, IntCounter,
+    IntGauge, TextEncoder,
+};
+
+lazy_static! {
+    /// Number of polls that reached quorum and were recorded as successful.
+    pub static ref SUCCESSFUL_POLLS: prometheus::Result<IntCounter> = register_int_counter!(
+        "snowball_successful_polls",
+        "Count of successful (quorum-reaching) polls recorded by snowball instances"
+    );
+
+    /// Number of polls that failed to reach quorum.
+    pub static ref UNSUCCESSFUL_POLLS: prometheus::Result<IntCounter> = register_int_counter!(
+        "snowball_unsuccessful_polls",
+        "Count of unsuccessful polls recorded by snowball instances"
+    );
+
+    /// Current confidence (consecutive successful polls) of the most recently
+    /// updated instance.
+    pub static ref CONFIDENCE: prometheus::Result<IntGauge> = register_int_gauge!(
+        "snowball_confidence",
+        "Current confidence counter of the most recently updated snowball instance"
+    );
+
+    /// Distribution of confidence reached at the moment an instance finalized.
+    pub static ref POLLS_TO_FINALIZATION: prometheus::Result<Histogram> = register_histogram!(
+        "snowball_polls_to_finalization",
+        "Consecutive successful polls accumulated when an instance finalized",
+        vec![1.0, 2.0, 3.0, 5.0, 8.0, 13.0, 21.0]
+    );
+}
+
+/// Increments an `IntCounter`, ignoring the registration error if the metric
+/// could not be created.
+pub fn inc_counter(counter: &prometheus::Result<IntCounter>) {
+    if let Ok(c) = counter {
+        c.inc();
+    }
+}
+
+/// Sets an `IntGauge` to `value`, ignoring the registration error.
+pub fn set_gauge(gauge: &prometheus::Result<IntGauge>, value: i64) {
+    if let Ok(g) = gauge {
+        g.set(value);
+    }
+}
+
+/// Observes `value` into a `Histogram`, ignoring the registration error.
+pub fn observe(histogram: &prometheus::Result<Histogram>, value: f64) {
+    if let Ok(h) = histogram {
+        h.observe(value);
+    }
+}
+
+/// Renders the default registry in the Prometheus text exposition format.
+pub fn gather() -> String {
+    let metric_families = prometheus::gather();
+    let mut buf = Vec::new();
+    let encoder = TextEncoder::new();
+    if let Err(e) = encoder.encode(&metric_families, &mut buf) {
+        log::warn!("failed to encode prometheus metrics: {e}");
+        return String::new();
+    }
+    String::from_utf8(buf).unwrap_or_default()
+}
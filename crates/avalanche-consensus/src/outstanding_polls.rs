@@ -0,0 +1,156 @@
+//! Tracks in-flight consensus query request IDs with per-entry deadlines.
+//!
+//! A `pull_query`/`push_query` carries a `deadline`, but the snowball instances
+//! themselves have no notion of query timeouts -- a query that never reaches
+//! quorum is otherwise never recorded. [`OutstandingPolls`] is a
+//! `HashSetDelay`-style structure (as used in the lighthouse common crates)
+//! that registers each outstanding request with an expiry and yields request
+//! IDs whose deadline has passed. The consensus driver drains the expired IDs
+//! each tick and feeds them back as `record_unsuccessful_poll` calls, resetting
+//! confidence to 0:
+//!
+//! ```ignore
+//! while let Poll::Ready(Some(request_id)) = Pin::new(&mut outstanding).poll_expired(cx) {
+//!     if let Some(instance) = instances.get(&request_id) {
+//!         instance.record_unsuccessful_poll();
+//!     }
+//! }
+//! ```
+use std::{
+    collections::HashMap,
+    hash::Hash,
+    pin::Pin,
+    task::{Context, Poll},
+    time::Duration,
+};
+
+use futures::Stream;
+use tokio_util::time::{delay_queue, DelayQueue};
+
+/// Tracks outstanding query request IDs keyed on `K`, each expiring after a
+/// per-entry deadline. Backed by a [`DelayQueue`] for the timer wheel and a
+/// `HashMap` so entries can be removed or re-inserted by key.
+pub struct OutstandingPolls<K>
+where
+    K: Eq + Hash + Clone,
+{
+    /// Maps a request ID to its handle in the delay queue so it can be reset or
+    /// cancelled by key.
+    entries: HashMap<K, delay_queue::Key>,
+
+    /// Timer wheel ordering request IDs by deadline.
+    expirations: DelayQueue<K>,
+
+    /// Deadline applied by [`OutstandingPolls::insert`] when no explicit
+    /// deadline is supplied.
+    default_deadline: Duration,
+}
+
+impl<K> OutstandingPolls<K>
+where
+    K: Eq + Hash + Clone,
+{
+    /// Creates a tracker whose [`OutstandingPolls::insert`] entries expire after
+    /// `default_deadline`.
+    pub fn new(default_deadline: Duration) -> Self {
+        Self {
+            entries: HashMap::new(),
+            expirations: DelayQueue::new(),
+            default_deadline,
+        }
+    }
+
+    /// Registers `request_id` with the default deadline. Re-inserting an
+    /// existing request ID resets its deadline rather than duplicating it.
+    pub fn insert(&mut self, request_id: K) {
+        self.insert_at(request_id, self.default_deadline);
+    }
+
+    /// Registers `request_id` to expire after `deadline`. Re-inserting an
+    /// existing request ID resets its deadline rather than duplicating it.
+    pub fn insert_at(&mut self, request_id: K, deadline: Duration) {
+        if let Some(key) = self.entries.get(&request_id) {
+            // reset the deadline in place instead of adding a duplicate entry
+            self.expirations.reset(key, deadline);
+            return;
+        }
+        let key = self.expirations.insert(request_id.clone(), deadline);
+        self.entries.insert(request_id, key);
+    }
+
+    /// Cancels tracking of `request_id`, e.g. when a quorum response arrives.
+    /// Removing an already-expired or unknown entry is a no-op.
+    pub fn remove(&mut self, request_id: &K) {
+        if let Some(key) = self.entries.remove(request_id) {
+            self.expirations.remove(&key);
+        }
+    }
+
+    /// Returns the number of outstanding request IDs.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns `true` when no request IDs are outstanding.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Polls for the next expired request ID, yielding it once its deadline has
+    /// passed. Returns `Poll::Ready(None)` when no entries are outstanding.
+    pub fn poll_expired(&mut self, cx: &mut Context<'_>) -> Poll<Option<K>> {
+        match self.expirations.poll_expired(cx) {
+            Poll::Ready(Some(expired)) => {
+                let request_id = expired.into_inner();
+                self.entries.remove(&request_id);
+                Poll::Ready(Some(request_id))
+            }
+            Poll::Ready(None) => Poll::Ready(None),
+            Poll::Pending => Poll::Pending,
+        }
+    }
+}
+
+impl<K> Stream for OutstandingPolls<K>
+where
+    K: Eq + Hash + Clone + Unpin,
+{
+    type Item = K;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.poll_expired(cx)
+    }
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-consensus --lib -- outstanding_polls::test_outstanding_polls --exact --show-output
+#[tokio::test(start_paused = true)]
+async fn test_outstanding_polls() {
+    use futures::StreamExt;
+
+    let mut polls: OutstandingPolls<u32> = OutstandingPolls::new(Duration::from_secs(5));
+
+    // removing an unknown entry is a no-op
+    polls.remove(&42);
+    assert!(polls.is_empty());
+
+    polls.insert(1);
+    polls.insert(2);
+    assert_eq!(polls.len(), 2);
+
+    // re-inserting resets the deadline rather than duplicating
+    polls.insert(1);
+    assert_eq!(polls.len(), 2);
+
+    // a quorum response arrives for request 2 before it expires
+    polls.remove(&2);
+    assert_eq!(polls.len(), 1);
+
+    // request 1 expires once its deadline passes
+    tokio::time::advance(Duration::from_secs(6)).await;
+    assert_eq!(polls.next().await, Some(1));
+    assert!(polls.is_empty());
+
+    // removing an already-expired entry is a no-op
+    polls.remove(&1);
+    assert!(polls.is_empty());
+}
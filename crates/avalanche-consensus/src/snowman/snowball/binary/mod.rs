@@ -108,19 +108,30 @@ impl Snowflake {
             return;
         }
 
+        crate::metrics::inc_counter(&crate::metrics::SUCCESSFUL_POLLS);
+
         if self.preference() == choice {
             self.confidence.set(self.confidence.get() + 1);
         } else {
             // 1 because this poll itself is a successful poll
             self.confidence.set(1);
         }
+        crate::metrics::set_gauge(&crate::metrics::CONFIDENCE, self.confidence.get());
 
         self.finalized.set(self.confidence.get() >= self.beta());
+        if self.finalized() {
+            crate::metrics::observe(
+                &crate::metrics::POLLS_TO_FINALIZATION,
+                self.confidence.get() as f64,
+            );
+        }
         self.slush.record_successful_poll(choice);
     }
 
     pub fn record_unsuccessful_poll(&self) {
+        crate::metrics::inc_counter(&crate::metrics::UNSUCCESSFUL_POLLS);
         self.confidence.set(0);
+        crate::metrics::set_gauge(&crate::metrics::CONFIDENCE, 0);
     }
 }
 
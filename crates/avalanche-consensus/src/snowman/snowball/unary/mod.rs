@@ -48,16 +48,25 @@ impl Snowflake {
     }
 
     pub fn record_successful_poll(&self) {
+        crate::metrics::inc_counter(&crate::metrics::SUCCESSFUL_POLLS);
+
         let confidence = self.confidence.get() + 1;
         self.confidence.set(confidence);
+        crate::metrics::set_gauge(&crate::metrics::CONFIDENCE, confidence);
 
         if !self.finalized.get() {
-            self.finalized.set(confidence >= self.beta());
+            let finalized = confidence >= self.beta();
+            self.finalized.set(finalized);
+            if finalized {
+                crate::metrics::observe(&crate::metrics::POLLS_TO_FINALIZATION, confidence as f64);
+            }
         }
     }
 
     pub fn record_unsuccessful_poll(&self) {
+        crate::metrics::inc_counter(&crate::metrics::UNSUCCESSFUL_POLLS);
         self.confidence.set(0);
+        crate::metrics::set_gauge(&crate::metrics::CONFIDENCE, 0);
     }
 
     /// Extends to the binary snowflake instance with the `choice` as the preference.
@@ -0,0 +1,104 @@
+//! Compile-time Solidity ABI codegen.
+//!
+//! Every `abi/*.json` (standard ABI JSON) or `abi/*.sol` source is turned into
+//! strongly-typed Rust bindings via `ethers_contract::Abigen` and written to
+//! `$OUT_DIR/generated/<name>.rs`, which `src/evm/abi/generated/mod.rs`
+//! `include!`s at compile time -- writing into `OUT_DIR` rather than `src`
+//! keeps `cargo package`/`cargo publish` (which build from a read-only copy
+//! of the source tree) and vendored/read-only checkouts working. The
+//! generation only runs when the `evm_abigen` feature is enabled:
+//! `ethers_contract::Abigen` pulls in `solang-parser`/`syn`/`quote` as
+//! build-dependencies, which is too heavy to force on every consumer of the
+//! (lighter) `evm` runtime feature, so codegen is opt-in separately.
+//! `evm_abigen` must still imply `evm`, since the generated bindings only
+//! compile against the `evm`-gated `evm::abi` module.
+//!
+//! NOTE: this checkout ships no `Cargo.toml`, so the `evm_abigen` feature and
+//! its `ethers-contract` build-dependency cannot actually be wired up here --
+//! a real manifest would need
+//! `evm_abigen = ["evm"]` under `[features]` and `ethers-contract = { version = "...", optional = true }`
+//! under `[build-dependencies]`, gated the same way `evm` already gates the
+//! runtime `ethers-contract` dependency.
+//!
+//! ref. <https://github.com/gakonst/ethers-rs/tree/master/ethers-contract/ethers-contract-abigen>
+
+fn main() {
+    // Only the `evm_abigen` feature pulls in `ethers_contract`'s codegen, so
+    // skip it otherwise -- plain `evm` consumers keep hand-assembled/JSON-
+    // loaded `evm::abi::Contract` calls without paying for the generator.
+    if std::env::var_os("CARGO_FEATURE_EVM_ABIGEN").is_none() {
+        return;
+    }
+
+    let manifest_dir = std::env::var("CARGO_MANIFEST_DIR").expect("CARGO_MANIFEST_DIR not set");
+    let abi_dir = std::path::Path::new(&manifest_dir).join("abi");
+    let out_dir =
+        std::path::Path::new(&std::env::var("OUT_DIR").expect("OUT_DIR not set")).join("generated");
+
+    // Nothing to generate if the project ships no ABI sources.
+    if !abi_dir.is_dir() {
+        return;
+    }
+    std::fs::create_dir_all(&out_dir).expect("failed to create generated bindings dir");
+
+    println!("cargo:rerun-if-changed={}", abi_dir.display());
+
+    let mut generated = Vec::new();
+    for entry in std::fs::read_dir(&abi_dir).expect("failed to read abi dir") {
+        let path = entry.expect("failed to read abi entry").path();
+        let is_abi = matches!(
+            path.extension().and_then(|e| e.to_str()),
+            Some("json") | Some("sol")
+        );
+        if !is_abi {
+            continue;
+        }
+        println!("cargo:rerun-if-changed={}", path.display());
+
+        let name = path
+            .file_stem()
+            .and_then(|s| s.to_str())
+            .expect("abi file has no stem")
+            .to_string();
+
+        // Abigen derives a PascalCase contract type from the module name.
+        let type_name = to_pascal_case(&name);
+        let bindings = ethers_contract::Abigen::new(&type_name, path.to_string_lossy())
+            .expect("failed to construct Abigen")
+            .generate()
+            .expect("failed to generate bindings");
+        let dst = out_dir.join(format!("{name}.rs"));
+        bindings
+            .write_to_file(&dst)
+            .expect("failed to write generated bindings");
+        generated.push(name);
+    }
+
+    // Re-emit the module index `src/evm/abi/generated/mod.rs` includes from
+    // `OUT_DIR`. Each submodule is wrapped in an `include!` (rather than a
+    // plain `pub mod {name};`, which resolves relative to the *including*
+    // file) so it pulls the generated source in from its `OUT_DIR` path.
+    let mut mod_rs =
+        String::from("//! Generated contract bindings. Do not edit -- produced by `build.rs`.\n");
+    for name in &generated {
+        mod_rs.push_str(&format!(
+            "pub mod {name} {{ include!(concat!(env!(\"OUT_DIR\"), \"/generated/{name}.rs\")); }}\n"
+        ));
+    }
+    std::fs::write(out_dir.join("mod.rs"), mod_rs).expect("failed to write generated mod.rs");
+}
+
+/// Converts a snake_case / kebab-case ABI file stem into a PascalCase contract
+/// type name (`simple_registry` -> `SimpleRegistry`).
+fn to_pascal_case(s: &str) -> String {
+    s.split(|c| c == '_' || c == '-')
+        .filter(|part| !part.is_empty())
+        .map(|part| {
+            let mut chars = part.chars();
+            match chars.next() {
+                Some(first) => first.to_uppercase().chain(chars).collect::<String>(),
+                None => String::new(),
+            }
+        })
+        .collect()
+}
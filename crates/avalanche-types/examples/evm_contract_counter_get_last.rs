@@ -1,18 +1,21 @@
 #![allow(deprecated)]
 
-use std::{env::args, io, str::FromStr};
-
-use avalanche_types::{evm::abi, jsonrpc::client::evm as json_client_evm};
-use ethers::prelude::Eip1559TransactionRequest;
-use ethers_core::{
-    abi::{Function, Param, ParamType, StateMutability},
-    types::transaction::eip2718::TypedTransaction,
-    types::H160,
-};
-use ethers_providers::{Http, Middleware, Provider};
+use std::{env::args, io, str::FromStr, sync::Arc};
 
-/// cargo run --example evm_contract_counter_get_last --features="jsonrpc_client evm" -- [HTTP RPC ENDPOINT] [CONTRACT ADDRESS]
-/// cargo run --example evm_contract_counter_get_last --features="jsonrpc_client evm" -- http://127.0.0.1:9650/ext/bc/C/rpc 0x5DB9A7629912EBF95876228C24A848de0bfB43A9
+use avalanche_types::{
+    evm::abi::generated::counter::Counter, jsonrpc::client::evm as json_client_evm,
+};
+use ethers_core::types::H160;
+use ethers_providers::{Http, Provider};
+
+/// cargo run --example evm_contract_counter_get_last --features="jsonrpc_client evm_abigen" -- [HTTP RPC ENDPOINT] [CONTRACT ADDRESS]
+/// cargo run --example evm_contract_counter_get_last --features="jsonrpc_client evm_abigen" -- http://127.0.0.1:9650/ext/bc/C/rpc 0x5DB9A7629912EBF95876228C24A848de0bfB43A9
+///
+/// NOTE: `Counter` only exists when the `evm_abigen` feature generates it
+/// (see `build.rs`), so this example needs `required-features =
+/// ["evm_abigen"]` in `Cargo.toml` -- this checkout ships no manifest to add
+/// that to, so `cargo build --examples` without `evm_abigen` will fail on
+/// this file until one exists.
 #[tokio::main]
 async fn main() -> io::Result<()> {
     // ref. <https://github.com/env-logger-rs/env_logger/issues/47>
@@ -31,30 +34,11 @@ async fn main() -> io::Result<()> {
     let chain_id = json_client_evm::chain_id(&chain_rpc_url).await.unwrap();
     log::info!("running against {chain_rpc_url}, {chain_id} for contract {contract_addr}");
 
-    // parsed function of "getLast() public view returns (address)"
-    let func = Function {
-        name: "getLast".to_string(),
-        inputs: vec![],
-        outputs: vec![Param {
-            name: "address".to_string(),
-            kind: ParamType::Address,
-            internal_type: None,
-        }],
-        constant: None,
-        state_mutability: StateMutability::NonPayable,
-    };
-    let arg_tokens = vec![];
-    let calldata = abi::encode_calldata(func, &arg_tokens).unwrap();
-    log::info!("calldata: 0x{}", hex::encode(calldata.clone()));
-
-    let tx = Eip1559TransactionRequest::new()
-        .chain_id(chain_id.as_u64())
-        .to(ethers::prelude::H160::from(contract_addr.as_fixed_bytes()))
-        .data(calldata);
-    let tx: TypedTransaction = tx.into();
-
-    let output = chain_rpc_provider.call(&tx, None).await.unwrap();
-    log::info!("output: {:?}", output);
+    // typed binding generated from "abi/counter.json" at build time -- no manual
+    // Function/Param/ParamType assembly or calldata encoding needed.
+    let counter = Counter::new(contract_addr, Arc::new(chain_rpc_provider));
+    let last = counter.get_last().call().await.unwrap();
+    log::info!("getLast: {last:?}");
 
     Ok(())
 }
@@ -9,6 +9,7 @@ use std::{
 };
 
 use crate::{constants, coreth::genesis as coreth_genesis, key};
+use primitive_types::{H256, U256};
 use serde::{Deserialize, Serialize};
 
 /// Represents Avalanche network genesis configuration.
@@ -52,6 +53,14 @@ pub struct Genesis {
 
     #[serde(rename = "message", skip_serializing_if = "Option::is_none")]
     pub message: Option<String>,
+
+    /// Set only on the [`Self::mainnet`]/[`Self::fuji`] presets, which
+    /// legitimately carry a reserved `network_id` and ship without
+    /// allocations/stakers (the real sets are not vendored into this crate).
+    /// [`Self::validate`] exempts a preset from the checks that otherwise
+    /// reject those two things; never set this on a custom genesis.
+    #[serde(skip)]
+    is_well_known_preset: bool,
 }
 
 /// All of the P-chain assets owned by "initialStakedFunds" are evenly
@@ -117,6 +126,7 @@ impl Genesis {
             initial_stakers: None,
             c_chain_genesis: coreth_genesis::Genesis::default(),
             message: Some(String::new()),
+            is_well_known_preset: false,
         }
     }
 
@@ -194,6 +204,8 @@ impl Genesis {
     /// and overwrites the file.
     pub fn sync(&self, file_path: &str) -> io::Result<()> {
         log::info!("syncing genesis to '{}'", file_path);
+        self.validate()?;
+
         let path = Path::new(file_path);
         if let Some(parent_dir) = path.parent() {
             log::info!("creating parent dir '{}'", parent_dir.display());
@@ -266,9 +278,316 @@ impl Genesis {
             c_chain_genesis,
 
             message: genesis_file.message,
+            is_well_known_preset: false,
         };
         Ok(genesis)
     }
+
+    /// Validates the documented AvalancheGo genesis invariants so that a
+    /// misconfigured genesis surfaces here instead of at node startup.
+    ///
+    /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/genesis#Config>
+    pub fn validate(&self) -> io::Result<()> {
+        if !self.is_well_known_preset {
+            if let Some(name) = constants::NETWORK_ID_TO_NETWORK_NAME.get(&self.network_id) {
+                if matches!(*name, "mainnet" | "fuji" | "local") {
+                    return Err(Error::new(
+                        ErrorKind::InvalidInput,
+                        format!(
+                            "network_id {} is reserved for the '{}' network, custom genesis configs must use a different network_id",
+                            self.network_id, name
+                        ),
+                    ));
+                }
+            }
+        }
+
+        let initial_staked_funds = self
+            .initial_staked_funds
+            .as_ref()
+            .map(|v| v.as_slice())
+            .unwrap_or(&[]);
+        if initial_staked_funds.is_empty() && !self.is_well_known_preset {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "initial_staked_funds must be non-empty",
+            ));
+        }
+
+        let allocated_avax_addrs: std::collections::HashSet<&str> = self
+            .allocations
+            .as_ref()
+            .map(|allocations| {
+                allocations
+                    .iter()
+                    .filter_map(|a| a.avax_addr.as_deref())
+                    .collect()
+            })
+            .unwrap_or_default();
+        for avax_addr in initial_staked_funds {
+            if !allocated_avax_addrs.contains(avax_addr.as_str()) {
+                return Err(Error::new(
+                    ErrorKind::InvalidInput,
+                    format!(
+                        "initial_staked_funds address '{avax_addr}' is not present in allocations"
+                    ),
+                ));
+            }
+        }
+
+        let initial_stakers = self
+            .initial_stakers
+            .as_ref()
+            .map(|v| v.as_slice())
+            .unwrap_or(&[]);
+        if initial_stakers.is_empty() && !self.is_well_known_preset {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "initial_stakers must be non-empty for a started network",
+            ));
+        }
+        for staker in initial_stakers {
+            if let Some(delegation_fee) = staker.delegation_fee {
+                if delegation_fee > 1_000_000 {
+                    return Err(Error::new(
+                        ErrorKind::InvalidInput,
+                        format!("delegation_fee {delegation_fee} exceeds the maximum of 1,000,000"),
+                    ));
+                }
+            }
+        }
+
+        let expected_hrp = constants::NETWORK_ID_TO_HRP
+            .get(&self.network_id)
+            .copied()
+            .unwrap_or(constants::FALLBACK_HRP);
+        if let Some(allocations) = &self.allocations {
+            for alloc in allocations {
+                if let Some(avax_addr) = &alloc.avax_addr {
+                    if !avax_addr.starts_with("X-") {
+                        return Err(Error::new(
+                            ErrorKind::InvalidInput,
+                            format!("avax_addr '{avax_addr}' must start with 'X-'"),
+                        ));
+                    }
+                    let (hrp, _) =
+                        key::secp256k1::address::avax_address_to_short_bytes("X", avax_addr)
+                            .map_err(|e| {
+                                Error::new(
+                                    ErrorKind::InvalidInput,
+                                    format!("avax_addr '{avax_addr}' is not a valid address: {e}"),
+                                )
+                            })?;
+                    if hrp != expected_hrp {
+                        return Err(Error::new(
+                            ErrorKind::InvalidInput,
+                            format!(
+                                "avax_addr '{avax_addr}' has HRP '{hrp}', expected '{expected_hrp}' for network_id {}",
+                                self.network_id
+                            ),
+                        ));
+                    }
+                }
+
+                if let (Some(unlock_schedule), Some(start_time)) =
+                    (&alloc.unlock_schedule, self.start_time)
+                {
+                    for locked in unlock_schedule {
+                        if let Some(locktime) = locked.locktime {
+                            if locktime < start_time {
+                                return Err(Error::new(
+                                    ErrorKind::InvalidInput,
+                                    format!(
+                                        "locktime {locktime} is before start_time {start_time}"
+                                    ),
+                                ));
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Inserts or updates a pre-deployed contract (bytecode, storage slots,
+    /// and balance) at `eth_addr` in the C-chain genesis allocation, keyed by
+    /// the lowercased address without the "0x" prefix.
+    ///
+    /// ref. <https://pkg.go.dev/github.com/ava-labs/coreth/core#GenesisAccount>
+    pub fn with_c_chain_contract(
+        &mut self,
+        eth_addr: &str,
+        code_hex: &str,
+        storage: BTreeMap<H256, H256>,
+        balance: U256,
+    ) -> io::Result<()> {
+        let code_hex_trimmed = code_hex.trim_start_matches("0x");
+        hex::decode(code_hex_trimmed).map_err(|e| {
+            Error::new(
+                ErrorKind::InvalidInput,
+                format!("invalid code_hex '{code_hex}': {e}"),
+            )
+        })?;
+
+        let key = eth_addr.trim_start_matches("0x").to_lowercase();
+        let storage = if storage.is_empty() {
+            None
+        } else {
+            Some(
+                storage
+                    .into_iter()
+                    .map(|(k, v)| (format!("0x{k:x}"), format!("0x{v:x}")))
+                    .collect(),
+            )
+        };
+
+        let alloc = self.c_chain_genesis.alloc.get_or_insert_with(BTreeMap::new);
+        let account = alloc
+            .entry(key)
+            .or_insert_with(coreth_genesis::AllocAccount::default);
+        account.code = Some(format!("0x{code_hex_trimmed}"));
+        account.storage = storage;
+        account.balance = balance;
+
+        Ok(())
+    }
+
+    /// Returns a deterministic SHA-256 fingerprint, as a lowercase hex
+    /// digest, over the same bytes [`Self::sync`] would write.
+    ///
+    /// Because `alloc` is a `BTreeMap` and the struct field order is fixed,
+    /// serialization is already deterministic. The one caveat is that
+    /// `start_time` defaults to `SystemTime::now()`, so `fingerprint` only
+    /// matches across machines when `start_time` is pinned explicitly.
+    pub fn fingerprint(&self) -> io::Result<String> {
+        let c_chain_genesis = self.c_chain_genesis.encode_json()?;
+        let genesis_file = GenesisFile {
+            network_id: self.network_id,
+            allocations: self.allocations.clone(),
+            start_time: self.start_time,
+            initial_stake_duration: self.initial_stake_duration,
+            initial_stake_duration_offset: self.initial_stake_duration_offset,
+            initial_staked_funds: self.initial_staked_funds.clone(),
+            initial_stakers: self.initial_stakers.clone(),
+
+            // the avalanchego can only read string-format c-chain genesis
+            c_chain_genesis,
+
+            message: self.message.clone(),
+        };
+
+        let d = serde_json::to_vec(&genesis_file)
+            .map_err(|e| Error::new(ErrorKind::Other, format!("failed to serialize JSON {}", e)))?;
+
+        Ok(hex::encode(crate::hash::sha256(&d)))
+    }
+
+    /// Returns the Avalanche mainnet (network ID 1) preset, embedded from
+    /// `artifacts/mainnet.genesis.json`.
+    ///
+    /// NOTE: the embedded asset only pins the network ID and C-chain ID;
+    /// it intentionally ships with no allocations or stakers, since the
+    /// real, multi-megabyte mainnet allocation list is not vendored into
+    /// this crate. Operators needing the exact canonical genesis should
+    /// still pull it from AvalancheGo's `genesis/genesis_mainnet.json`.
+    /// [`Self::validate`] (and so [`Self::sync`]) exempts this preset from
+    /// the reserved-`network_id` and non-empty-stakers/funds checks it would
+    /// otherwise fail, so `Genesis::mainnet()?.sync(path)?` succeeds.
+    pub fn mainnet() -> io::Result<Genesis> {
+        Self::from_embedded_artifact("artifacts/mainnet.genesis.json")
+    }
+
+    /// Returns the Fuji testnet (network ID 5) preset, embedded from
+    /// `artifacts/fuji.genesis.json`. See [`Self::mainnet`] for the same
+    /// caveat about allocations/stakers not being vendored and the same
+    /// [`Self::validate`] exemption.
+    pub fn fuji() -> io::Result<Genesis> {
+        Self::from_embedded_artifact("artifacts/fuji.genesis.json")
+    }
+
+    /// Returns the [`Self::mainnet`] or [`Self::fuji`] preset for the
+    /// well-known network IDs, or [`Self::default`] for any other
+    /// (custom) `network_id`.
+    pub fn from_network_id(network_id: u32) -> io::Result<Genesis> {
+        match network_id {
+            1 => Self::mainnet(),
+            5 => Self::fuji(),
+            _ => Ok(Self::default()),
+        }
+    }
+
+    /// Loads a [`GenesisFile`] embedded under `artifacts/` at build time.
+    fn from_embedded_artifact(asset_path: &str) -> io::Result<Genesis> {
+        use rust_embed::RustEmbed;
+        #[derive(RustEmbed)]
+        #[folder = "artifacts/"]
+        #[prefix = "artifacts/"]
+        struct Asset;
+
+        let asset = Asset::get(asset_path).ok_or_else(|| {
+            Error::new(
+                ErrorKind::NotFound,
+                format!("embedded asset '{asset_path}' not found"),
+            )
+        })?;
+        let contents = std::str::from_utf8(asset.data.as_ref()).map_err(|e| {
+            Error::new(
+                ErrorKind::InvalidData,
+                format!("invalid utf-8 in '{asset_path}': {e}"),
+            )
+        })?;
+
+        let genesis_file: GenesisFile = serde_json::from_str(contents)
+            .map_err(|e| Error::new(ErrorKind::InvalidInput, format!("invalid JSON: {e}")))?;
+
+        let c_chain_genesis: coreth_genesis::Genesis =
+            serde_json::from_str(&genesis_file.c_chain_genesis)
+                .map_err(|e| Error::new(ErrorKind::InvalidInput, format!("invalid JSON: {e}")))?;
+
+        Ok(Genesis {
+            network_id: genesis_file.network_id,
+            allocations: genesis_file.allocations,
+            start_time: genesis_file.start_time,
+            initial_stake_duration: genesis_file.initial_stake_duration,
+            initial_stake_duration_offset: genesis_file.initial_stake_duration_offset,
+            initial_staked_funds: genesis_file.initial_staked_funds,
+            initial_stakers: genesis_file.initial_stakers,
+            c_chain_genesis,
+            message: genesis_file.message,
+            is_well_known_preset: true,
+        })
+    }
+
+    /// Applies `cfg` to the C-chain genesis: sets `base_fee`/`gas_limit` and
+    /// records the full [`coreth_genesis::FeeConfig`] under the chain
+    /// config's `feeConfig` block.
+    pub fn with_fee_config(&mut self, cfg: coreth_genesis::FeeConfig) -> io::Result<()> {
+        if cfg.target_base_fee == 0 && cfg.min_base_fee == 0 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "fee config base fee must be non-zero",
+            ));
+        }
+        if cfg.gas_limit == 0 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "fee config gas_limit must be non-zero",
+            ));
+        }
+
+        self.c_chain_genesis.base_fee = Some(format!("0x{:x}", cfg.target_base_fee));
+        self.c_chain_genesis.gas_limit = U256::from(cfg.gas_limit);
+
+        let chain_config = self
+            .c_chain_genesis
+            .config
+            .get_or_insert_with(coreth_genesis::ChainConfig::default);
+        chain_config.fee_config = Some(cfg);
+
+        Ok(())
+    }
 }
 
 /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/genesis#Allocation>
@@ -563,6 +882,7 @@ fn test_genesis() {
         },
 
         message: Some(String::from("{{ fun_quote }}")),
+        is_well_known_preset: false,
     };
     assert_eq!(original_genesis, genesis);
 
@@ -575,3 +895,207 @@ fn test_genesis() {
     let d = fs::read_to_string(&p).unwrap();
     log::info!("{}", d);
 }
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- avalanchego::genesis::test_genesis_validate --exact --show-output
+#[test]
+fn test_genesis_validate() {
+    let _ = env_logger::builder().is_test(true).try_init();
+
+    let avax_addr = String::from("X-custom1g65uqn6t77p656w64023nh8nd9updzmxwd59gh");
+    let mut genesis = Genesis {
+        network_id: 1337,
+        allocations: Some(vec![Allocation {
+            avax_addr: Some(avax_addr.clone()),
+            eth_addr: None,
+            initial_amount: Some(1),
+            unlock_schedule: Some(vec![LockedAmount {
+                amount: Some(1),
+                locktime: Some(100),
+            }]),
+        }]),
+        start_time: Some(50),
+        initial_stake_duration: Some(DEFAULT_INITIAL_STAKE_DURATION),
+        initial_stake_duration_offset: Some(DEFAULT_INITIAL_STAKE_DURATION_OFFSET),
+        initial_staked_funds: Some(vec![avax_addr.clone()]),
+        initial_stakers: Some(vec![Staker {
+            node_id: Some(String::from("NodeID-7Xhw2mDxuDS44j42TCB6U5579esbSt3Lg")),
+            reward_address: Some(avax_addr.clone()),
+            delegation_fee: Some(1_000_000),
+        }]),
+        c_chain_genesis: coreth_genesis::Genesis::default(),
+        message: None,
+        is_well_known_preset: false,
+    };
+    assert!(genesis.validate().is_ok());
+
+    // reserved network_id
+    genesis.network_id = 1;
+    assert!(genesis.validate().is_err());
+    genesis.network_id = 1337;
+
+    // initial_staked_funds not in allocations
+    genesis.initial_staked_funds = Some(vec![String::from(
+        "X-custom18jma8ppw3nhx5r4ap8clazz0dps7rv5u9xde7p",
+    )]);
+    assert!(genesis.validate().is_err());
+    genesis.initial_staked_funds = Some(vec![avax_addr.clone()]);
+
+    // delegation_fee over the on-chain max
+    genesis.initial_stakers.as_mut().unwrap()[0].delegation_fee = Some(1_000_001);
+    assert!(genesis.validate().is_err());
+    genesis.initial_stakers.as_mut().unwrap()[0].delegation_fee = Some(1_000_000);
+
+    // avax_addr not starting with "X-"
+    genesis.allocations.as_mut().unwrap()[0].avax_addr = Some(String::from(
+        "P-custom1g65uqn6t77p656w64023nh8nd9updzmxwd59gh",
+    ));
+    assert!(genesis.validate().is_err());
+    genesis.allocations.as_mut().unwrap()[0].avax_addr = Some(avax_addr.clone());
+
+    // locktime before start_time
+    genesis.allocations.as_mut().unwrap()[0]
+        .unlock_schedule
+        .as_mut()
+        .unwrap()[0]
+        .locktime = Some(10);
+    assert!(genesis.validate().is_err());
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- avalanchego::genesis::test_with_c_chain_contract --exact --show-output
+#[test]
+fn test_with_c_chain_contract() {
+    let _ = env_logger::builder().is_test(true).try_init();
+
+    let mut genesis = Genesis::default();
+
+    let mut storage = BTreeMap::new();
+    storage.insert(H256::from_low_u64_be(1), H256::from_low_u64_be(42));
+
+    genesis
+        .with_c_chain_contract(
+            "0x8Db97C7cEcE249C2b98bDC0226Cc4C2A57BF52FC",
+            "0x6080604052",
+            storage,
+            U256::from(100),
+        )
+        .unwrap();
+
+    let account = genesis
+        .c_chain_genesis
+        .alloc
+        .as_ref()
+        .unwrap()
+        .get("8db97c7cece249c2b98bdc0226cc4c2a57bf52fc")
+        .unwrap();
+    assert_eq!(account.code, Some(String::from("0x6080604052")));
+    assert_eq!(account.balance, U256::from(100));
+    assert_eq!(
+        account
+            .storage
+            .as_ref()
+            .unwrap()
+            .get(&format!("0x{:x}", H256::from_low_u64_be(1))),
+        Some(&format!("0x{:x}", H256::from_low_u64_be(42)))
+    );
+
+    // invalid hex is rejected
+    assert!(genesis
+        .with_c_chain_contract(
+            "0x8Db97C7cEcE249C2b98bDC0226Cc4C2A57BF52FC",
+            "zz",
+            BTreeMap::new(),
+            U256::zero()
+        )
+        .is_err());
+
+    // the nested C-chain genesis round-trips through its string encoding,
+    // the same way it does inside `Genesis::sync`/`Genesis::load`.
+    let encoded = genesis.c_chain_genesis.encode_json().unwrap();
+    let decoded: coreth_genesis::Genesis = serde_json::from_str(&encoded).unwrap();
+    assert_eq!(decoded, genesis.c_chain_genesis);
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- avalanchego::genesis::test_fingerprint --exact --show-output
+#[test]
+fn test_fingerprint() {
+    let _ = env_logger::builder().is_test(true).try_init();
+
+    let mut genesis = Genesis::default();
+    genesis.start_time = Some(1630987200);
+
+    let fingerprint1 = genesis.fingerprint().unwrap();
+    let fingerprint2 = genesis.fingerprint().unwrap();
+    assert_eq!(fingerprint1, fingerprint2);
+    assert_eq!(fingerprint1.len(), 64);
+
+    // any field change flips the fingerprint
+    genesis.message = Some(String::from("different"));
+    let fingerprint3 = genesis.fingerprint().unwrap();
+    assert_ne!(fingerprint1, fingerprint3);
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- avalanchego::genesis::test_network_presets --exact --show-output
+#[test]
+fn test_network_presets() {
+    let _ = env_logger::builder().is_test(true).try_init();
+
+    let mainnet = Genesis::mainnet().unwrap();
+    assert_eq!(mainnet.network_id, 1);
+    assert_eq!(
+        mainnet.c_chain_genesis.config.as_ref().unwrap().chain_id,
+        Some(43114)
+    );
+
+    let fuji = Genesis::fuji().unwrap();
+    assert_eq!(fuji.network_id, 5);
+    assert_eq!(
+        fuji.c_chain_genesis.config.as_ref().unwrap().chain_id,
+        Some(43113)
+    );
+
+    assert_eq!(Genesis::from_network_id(1).unwrap(), mainnet);
+    assert_eq!(Genesis::from_network_id(5).unwrap(), fuji);
+    assert_eq!(
+        Genesis::from_network_id(12345).unwrap().network_id,
+        Genesis::default().network_id
+    );
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- avalanchego::genesis::test_with_fee_config --exact --show-output
+#[test]
+fn test_with_fee_config() {
+    let _ = env_logger::builder().is_test(true).try_init();
+
+    let mut genesis = Genesis::default();
+    let cfg = coreth_genesis::FeeConfig {
+        gas_limit: 15_000_000,
+        target_base_fee: 25_000_000_000,
+        min_base_fee: 25_000_000_000,
+        base_fee_change_denominator: 36,
+        block_gas_cost_step: 200_000,
+    };
+    genesis.with_fee_config(cfg).unwrap();
+
+    assert_eq!(
+        genesis.c_chain_genesis.base_fee,
+        Some(String::from("0x5d21dba00"))
+    );
+    assert_eq!(genesis.c_chain_genesis.gas_limit, U256::from(15_000_000));
+    assert_eq!(
+        genesis.c_chain_genesis.config.as_ref().unwrap().fee_config,
+        Some(cfg)
+    );
+
+    let encoded = genesis.c_chain_genesis.encode_json().unwrap();
+    let decoded: coreth_genesis::Genesis = serde_json::from_str(&encoded).unwrap();
+    assert_eq!(decoded, genesis.c_chain_genesis);
+
+    let mut zero_gas_limit_cfg = cfg;
+    zero_gas_limit_cfg.gas_limit = 0;
+    assert!(genesis.with_fee_config(zero_gas_limit_cfg).is_err());
+
+    let mut zero_base_fee_cfg = cfg;
+    zero_base_fee_cfg.target_base_fee = 0;
+    zero_base_fee_cfg.min_base_fee = 0;
+    assert!(genesis.with_fee_config(zero_base_fee_cfg).is_err());
+}
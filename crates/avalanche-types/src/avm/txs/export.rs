@@ -1,9 +1,10 @@
 //! Base export transaction type.
 use crate::{
     avm::txs::fx,
-    codec,
+    codec, constants,
     errors::{Error, Result},
-    hash, ids, key, platformvm, txs,
+    formatting, hash, ids, key, packer, platformvm,
+    txs::{self, transferable, Signable},
 };
 use serde::{Deserialize, Serialize};
 
@@ -52,24 +53,136 @@ impl Tx {
         }
     }
 
-    /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/avm#Tx.SignSECP256K1Fx>
-    ///
-    /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/utils/crypto#PrivateKeyED25519.SignHash>
-    pub async fn sign<T: key::secp256k1::SignOnly>(&mut self, signers: Vec<Vec<T>>) -> Result<()> {
-        // marshal "unsigned tx" with the codec version
-        let type_id = Self::type_id();
-        let packer = self.base_tx.pack(codec::VERSION, type_id)?;
-
-        // "avalanchego" marshals the whole struct again for signed bytes
-        // even when the underlying "unsigned_tx" is already once marshaled
-        // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/avm#Tx.SignSECP256K1Fx
-        //
-        // reuse the underlying packer to avoid marshaling the unsigned tx twice
-        // just marshal the next fields in the struct and pack them all together
-        // in the existing packer
-        let b = packer.take_bytes();
-        packer.set_bytes(&b);
+    /// Packs the unsigned portion of the tx -- the base tx and the export
+    /// fields, everything `sign` hashes up to but not including the credentials.
+    /// Shared by [`Tx::summary`] so the signing hash can be computed without
+    /// running the full sign routine.
+    pub fn pack_unsigned(&self) -> Result<Vec<u8>> {
+        let packer = self.base_tx.pack(codec::VERSION, Self::type_id())?;
+        let unsigned_tx_bytes = packer.take_bytes();
+        packer.set_bytes(&unsigned_tx_bytes);
+        <Self as Signable>::pack_unsigned_fields(self, &packer)?;
+        Ok(packer.take_bytes().to_vec())
+    }
+
+    /// Extracts a structured, human-readable view of the transaction without
+    /// signing it, for "clear-signing" flows where a hardware or otherwise
+    /// constrained signer must show the operator exactly what is being approved
+    /// -- including the `signing_hash` the device will actually sign -- before a
+    /// signature is produced. Owner addresses are rendered as bech32 with the
+    /// X-chain alias and the network's HRP.
+    pub fn summary(&self) -> Result<TxSummary> {
+        let hrp = constants::NETWORK_ID_TO_HRP
+            .get(&self.base_tx.network_id)
+            .copied()
+            .unwrap_or(constants::FALLBACK_HRP);
+
+        let fmt_owners = |owners: &key::secp256k1::txs::OutputOwners| -> Result<Vec<String>> {
+            owners
+                .addresses
+                .iter()
+                .map(|addr| {
+                    formatting::address("X", hrp, addr.as_ref()).map_err(|e| Error::Other {
+                        message: format!("failed formatting::address '{e}'"),
+                        retryable: false,
+                    })
+                })
+                .collect()
+        };
+
+        let mut outputs = Vec::new();
+        if let Some(transferable_outputs) = &self.destination_chain_transferable_outputs {
+            for o in transferable_outputs {
+                let (amount, locktime, owners) = match &o.out {
+                    transferable::TransferableOut::TransferOutput(out) => {
+                        (out.amount, out.output_owners.locktime, &out.output_owners)
+                    }
+                    transferable::TransferableOut::StakeableLockOut(out) => (
+                        out.transfer_output.amount,
+                        out.locktime,
+                        &out.transfer_output.output_owners,
+                    ),
+                };
+                outputs.push(OutputSummary {
+                    asset_id: o.asset_id,
+                    amount,
+                    locktime,
+                    threshold: owners.threshold,
+                    addresses: fmt_owners(owners)?,
+                });
+            }
+        }
+
+        let mut inputs = Vec::new();
+        if let Some(transferable_inputs) = &self.base_tx.transferable_inputs {
+            for i in transferable_inputs {
+                let amount = match (&i.transfer_input, &i.stakeable_lock_in) {
+                    (Some(input), _) => input.amount,
+                    (_, Some(lock_in)) => lock_in.transfer_input.amount,
+                    _ => 0,
+                };
+                inputs.push(InputSummary {
+                    asset_id: i.asset_id,
+                    utxo_id: i.utxo_id.clone(),
+                    amount,
+                });
+            }
+        }
 
+        Ok(TxSummary {
+            destination_chain_id: self.destination_chain_id,
+            outputs,
+            inputs,
+            memo: self.base_tx.memo.clone().unwrap_or_default(),
+            signing_hash: hash::sha256(&self.pack_unsigned()?).to_vec(),
+        })
+    }
+}
+
+/// A signing-time view of an export [`Tx`] for on-device / clear-signing
+/// confirmation, produced by [`Tx::summary`] without signing.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TxSummary {
+    pub destination_chain_id: ids::Id,
+    pub outputs: Vec<OutputSummary>,
+    pub inputs: Vec<InputSummary>,
+    pub memo: Vec<u8>,
+    /// The sha256 of the unsigned tx bytes -- the 32 bytes `sign` hashes.
+    pub signing_hash: Vec<u8>,
+}
+
+/// One transferable output as rendered for confirmation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OutputSummary {
+    pub asset_id: ids::Id,
+    pub amount: u64,
+    pub locktime: u64,
+    pub threshold: u32,
+    pub addresses: Vec<String>,
+}
+
+/// One source input as rendered for confirmation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InputSummary {
+    pub asset_id: ids::Id,
+    pub utxo_id: txs::utxo::Id,
+    pub amount: u64,
+}
+
+impl txs::Signable for Tx {
+    fn type_id() -> u32 {
+        Self::type_id()
+    }
+
+    fn base_tx(&self) -> &txs::Tx {
+        &self.base_tx
+    }
+
+    fn base_tx_mut(&mut self) -> &mut txs::Tx {
+        &mut self.base_tx
+    }
+
+    fn pack_unsigned_fields(&self, packer: &packer::Packer) -> Result<()> {
         // pack the second field in the struct
         packer.pack_bytes(self.destination_chain_id.as_ref())?;
 
@@ -187,67 +300,53 @@ impl Tx {
         } else {
             packer.pack_u32(0_u32)?;
         }
+        Ok(())
+    }
 
-        // take bytes just for hashing computation
-        let tx_bytes_with_no_signature = packer.take_bytes();
-        packer.set_bytes(&tx_bytes_with_no_signature);
-
-        // compute sha256 for marshaled "unsigned tx" bytes
-        // IMPORTANT: take the hash only for the type "avm.ExportTx" unsigned tx
-        // not other fields -- only hash "avm.ExportTx.*" but not "avm.Tx.Creds"
-        // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/avm#ExportTx
-        let tx_bytes_hash = hash::sha256(&tx_bytes_with_no_signature);
-
-        // number of of credentials
-        let fx_creds_len = signers.len() as u32;
-        // pack the fourth field in the struct
-        packer.pack_u32(fx_creds_len)?;
-
-        // sign the hash with the signers (in case of multi-sig)
-        // and combine all signatures into a secp256k1fx credential
-        self.fx_creds = Vec::new();
-        for keys in signers.iter() {
-            let mut sigs: Vec<Vec<u8>> = Vec::new();
-            for k in keys.iter() {
-                let sig = k.sign_digest(&tx_bytes_hash).await?;
-                sigs.push(Vec::from(sig));
-            }
-
-            let cred = key::secp256k1::txs::Credential { signatures: sigs };
-
-            let fx_cred = fx::Credential {
+    fn set_credentials(&mut self, creds: Vec<key::secp256k1::txs::Credential>) {
+        self.fx_creds = creds
+            .into_iter()
+            .map(|cred| fx::Credential {
                 cred,
                 ..Default::default()
-            };
+            })
+            .collect();
+    }
+}
 
-            // add a new credential to "Tx"
-            self.fx_creds.push(fx_cred);
-        }
-        if fx_creds_len > 0 {
-            // pack each "fx_cred" which is "secp256k1fx.Credential"
-            // marshal type ID for "secp256k1fx.Credential"
-            let cred_type_id = key::secp256k1::txs::Credential::type_id();
-            for fx_cred in self.fx_creds.iter() {
-                packer.pack_u32(cred_type_id)?;
-                packer.pack_u32(fx_cred.cred.signatures.len() as u32)?;
-                for sig in fx_cred.cred.signatures.iter() {
-                    packer.pack_bytes(sig)?;
-                }
-            }
+impl txs::Decodable for Tx {
+    fn type_id() -> u32 {
+        Self::type_id()
+    }
+
+    fn base_tx_mut(&mut self) -> &mut txs::Tx {
+        &mut self.base_tx
+    }
+
+    fn unpack_unsigned_fields(packer: &packer::Packer, base_tx: txs::Tx) -> Result<Self> {
+        // inverse of the second field in "pack_unsigned_fields"
+        let destination_chain_id = ids::Id::from_slice(&packer.unpack_bytes(ids::LEN)?);
+
+        // inverse of the third field; each transferable output decodes itself
+        // through the per-version codec registry (type IDs 7 and 22), matching
+        // the switch in the pack path
+        let outs_len = packer.unpack_u32()? as usize;
+        let mut destination_chain_transferable_outputs = Vec::with_capacity(outs_len);
+        for _ in 0..outs_len {
+            destination_chain_transferable_outputs.push(packer.unpack()?);
         }
-        let tx_bytes_with_signatures = packer.take_bytes();
-        let tx_id = hash::sha256(&tx_bytes_with_signatures);
-
-        // update "BaseTx.Metadata" with id/unsigned bytes/bytes
-        // ref. "avalanchego/vms/avm.Tx.SignSECP256K1Fx"
-        // ref. "avalanchego/vms/components/avax.BaseTx.Metadata.Initialize"
-        self.base_tx.metadata = Some(txs::Metadata {
-            id: ids::Id::from_slice(&tx_id),
-            tx_bytes_with_no_signature: tx_bytes_with_no_signature.to_vec(),
-            tx_bytes_with_signatures: tx_bytes_with_signatures.to_vec(),
-        });
 
-        Ok(())
+        Ok(Self {
+            base_tx,
+            destination_chain_id,
+            destination_chain_transferable_outputs: (outs_len > 0)
+                .then_some(destination_chain_transferable_outputs),
+            fx_creds: Vec::new(),
+        })
+    }
+
+    fn set_credentials(&mut self, creds: Vec<key::secp256k1::txs::Credential>) {
+        <Self as txs::Signable>::set_credentials(self, creds);
     }
 }
 
@@ -453,3 +552,133 @@ fn test_export_tx_serialization_with_two_signers() {
         &tx_bytes_with_signatures
     ));
 }
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- avm::txs::export::test_export_tx_unpack_round_trip --exact --show-output
+#[test]
+fn test_export_tx_unpack_round_trip() {
+    use crate::txs::Decodable;
+
+    macro_rules! ab {
+        ($e:expr) => {
+            tokio_test::block_on($e)
+        };
+    }
+
+    let mut tx = Tx {
+        base_tx: txs::Tx {
+            network_id: 2,
+            blockchain_id: ids::Id::from_slice(&[0x11; ids::LEN]),
+            transferable_inputs: Some(vec![txs::transferable::Input {
+                utxo_id: txs::utxo::Id {
+                    tx_id: ids::Id::from_slice(&[0x22; ids::LEN]),
+                    ..txs::utxo::Id::default()
+                },
+                asset_id: ids::Id::from_slice(&[0x33; ids::LEN]),
+                transfer_input: Some(key::secp256k1::txs::transfer::Input {
+                    amount: 1000,
+                    sig_indices: vec![0],
+                }),
+                ..txs::transferable::Input::default()
+            }]),
+            memo: Some(vec![0x00, 0x01, 0x02, 0x03]),
+            ..txs::Tx::default()
+        },
+        destination_chain_id: ids::Id::from_slice(&[0x44; ids::LEN]),
+        ..Tx::default()
+    };
+
+    let test_key = key::secp256k1::private_key::Key::from_cb58(
+        "PrivateKey-24jUJ9vZexUM6expyMcT48LBx27k1m7xpraoV62oSQAHdziao5",
+    )
+    .expect("failed to load private key");
+    let signers: Vec<Vec<key::secp256k1::private_key::Key>> =
+        vec![vec![test_key.clone(), test_key]];
+    ab!(tx.sign(signers)).expect("failed to sign");
+
+    let signed = tx.base_tx.metadata.clone().unwrap().tx_bytes_with_signatures;
+    let decoded = Tx::from_signed_bytes(&signed).expect("failed to decode");
+
+    // fully reconstructed, metadata included
+    assert_eq!(decoded, tx);
+    assert_eq!(decoded.fx_creds.len(), 1);
+
+    // decode -> encode is byte-identical
+    let re_signed = decoded
+        .base_tx
+        .metadata
+        .clone()
+        .unwrap()
+        .tx_bytes_with_signatures;
+    assert!(cmp_manager::eq_vectors(&signed, &re_signed));
+
+    // a wrong type ID in the header is rejected
+    let mut bad_type = signed.clone();
+    bad_type[5] = bad_type[5].wrapping_add(1);
+    assert!(Tx::from_signed_bytes(&bad_type).is_err());
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- avm::txs::export::test_export_tx_summary --exact --show-output
+#[test]
+fn test_export_tx_summary() {
+    macro_rules! ab {
+        ($e:expr) => {
+            tokio_test::block_on($e)
+        };
+    }
+
+    let asset_id = ids::Id::from_slice(&[0x11; ids::LEN]);
+    let owner = ids::short::Id::from_slice(&[0x22; 20]);
+
+    let mut tx = Tx {
+        base_tx: txs::Tx {
+            network_id: 1,
+            transferable_inputs: Some(vec![txs::transferable::Input {
+                utxo_id: txs::utxo::Id::default(),
+                asset_id,
+                transfer_input: Some(key::secp256k1::txs::transfer::Input {
+                    amount: 1000,
+                    sig_indices: vec![0],
+                }),
+                ..txs::transferable::Input::default()
+            }]),
+            memo: Some(vec![0x09, 0x08]),
+            ..txs::Tx::default()
+        },
+        destination_chain_id: ids::Id::from_slice(&[0x33; ids::LEN]),
+        destination_chain_transferable_outputs: Some(vec![txs::transferable::Output {
+            asset_id,
+            out: txs::transferable::TransferableOut::TransferOutput(
+                key::secp256k1::txs::transfer::Output {
+                    amount: 900,
+                    output_owners: key::secp256k1::txs::OutputOwners {
+                        locktime: 0,
+                        threshold: 1,
+                        addresses: vec![owner],
+                    },
+                },
+            ),
+            ..txs::transferable::Output::default()
+        }]),
+        ..Tx::default()
+    };
+
+    // the summary must be derivable without signing
+    let summary = tx.summary().expect("failed to build summary");
+    assert_eq!(summary.destination_chain_id, tx.destination_chain_id);
+    assert_eq!(summary.memo, vec![0x09, 0x08]);
+    assert_eq!(summary.inputs.len(), 1);
+    assert_eq!(summary.inputs[0].amount, 1000);
+    assert_eq!(summary.outputs.len(), 1);
+    assert_eq!(summary.outputs[0].amount, 900);
+    assert_eq!(summary.outputs[0].threshold, 1);
+    assert_eq!(summary.outputs[0].addresses.len(), 1);
+
+    // the advertised signing hash must be exactly the bytes `sign` hashes
+    let signers: Vec<Vec<key::secp256k1::private_key::Key>> = Vec::new();
+    ab!(tx.sign(signers)).expect("failed to sign");
+    let metadata = tx.base_tx.metadata.clone().unwrap();
+    assert_eq!(
+        summary.signing_hash,
+        hash::sha256(&metadata.tx_bytes_with_no_signature).to_vec()
+    );
+}
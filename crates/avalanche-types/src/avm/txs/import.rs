@@ -3,7 +3,8 @@ use crate::{
     avm::txs::fx,
     codec,
     errors::{Error, Result},
-    hash, ids, key, platformvm, txs,
+    ids, key, packer, platformvm,
+    txs::{self, Signable},
 };
 use serde::{Deserialize, Serialize};
 
@@ -64,24 +65,22 @@ impl Tx {
     pub fn type_id() -> u32 {
         *(codec::X_TYPES.get(&Self::type_name()).unwrap()) as u32
     }
+}
 
-    /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/avm#Tx.SignSECP256K1Fx>
-    /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/utils/crypto#PrivateKeyED25519.SignHash>
-    pub async fn sign<T: key::secp256k1::SignOnly>(&mut self, signers: Vec<Vec<T>>) -> Result<()> {
-        // marshal "unsigned tx" with the codec version
-        let type_id = Self::type_id();
-        let packer = self.base_tx.pack(codec::VERSION, type_id)?;
+impl txs::Signable for Tx {
+    fn type_id() -> u32 {
+        Self::type_id()
+    }
 
-        // "avalanchego" marshals the whole struct again for signed bytes
-        // even when the underlying "unsigned_tx" is already once marshaled
-        // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/avm#Tx.SignSECP256K1Fx
-        //
-        // reuse the underlying packer to avoid marshaling the unsigned tx twice
-        // just marshal the next fields in the struct and pack them all together
-        // in the existing packer
-        let b = packer.take_bytes();
-        packer.set_bytes(&b);
+    fn base_tx(&self) -> &txs::Tx {
+        &self.base_tx
+    }
+
+    fn base_tx_mut(&mut self) -> &mut txs::Tx {
+        &mut self.base_tx
+    }
 
+    fn pack_unsigned_fields(&self, packer: &packer::Packer) -> Result<()> {
         // pack the second field in the struct
         packer.pack_bytes(self.source_chain_id.as_ref())?;
 
@@ -192,67 +191,18 @@ impl Tx {
         } else {
             packer.pack_u32(0_u32)?;
         }
-
-        // take bytes just for hashing computation
-        let tx_bytes_with_no_signature = packer.take_bytes();
-        packer.set_bytes(&tx_bytes_with_no_signature);
-
-        // compute sha256 for marshaled "unsigned tx" bytes
-        // IMPORTANT: take the hash only for the type "avm.ImportTx" unsigned tx
-        // not other fields -- only hash "avm.ImportTx.*" but not "avm.Tx.Creds"
-        // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/avm#ImportTx
-        let tx_bytes_hash = hash::sha256(&tx_bytes_with_no_signature);
-
-        // number of of credentials
-        let fx_creds_len = signers.len() as u32;
-        // pack the fourth field in the struct
-        packer.pack_u32(fx_creds_len)?;
-
-        // sign the hash with the signers (in case of multi-sig)
-        // and combine all signatures into a secp256k1fx credential
-        self.fx_creds = Vec::new();
-        for keys in signers.iter() {
-            let mut sigs: Vec<Vec<u8>> = Vec::new();
-            for k in keys.iter() {
-                let sig = k.sign_digest(&tx_bytes_hash).await?;
-                sigs.push(Vec::from(sig));
-            }
-
-            let mut cred = key::secp256k1::txs::Credential::default();
-            cred.signatures = sigs;
-
-            let mut fx_cred = fx::Credential::default();
-            fx_cred.cred = cred;
-
-            // add a new credential to "Tx"
-            self.fx_creds.push(fx_cred);
-        }
-        if fx_creds_len > 0 {
-            // pack each "fx_cred" which is "secp256k1fx.Credential"
-            // marshal type ID for "secp256k1fx.Credential"
-            let cred_type_id = key::secp256k1::txs::Credential::type_id();
-            for fx_cred in self.fx_creds.iter() {
-                packer.pack_u32(cred_type_id)?;
-                packer.pack_u32(fx_cred.cred.signatures.len() as u32)?;
-                for sig in fx_cred.cred.signatures.iter() {
-                    packer.pack_bytes(sig)?;
-                }
-            }
-        }
-        let tx_bytes_with_signatures = packer.take_bytes();
-        let tx_id = hash::sha256(&tx_bytes_with_signatures);
-
-        // update "BaseTx.Metadata" with id/unsigned bytes/bytes
-        // ref. "avalanchego/vms/avm.Tx.SignSECP256K1Fx"
-        // ref. "avalanchego/vms/components/avax.BaseTx.Metadata.Initialize"
-        self.base_tx.metadata = Some(txs::Metadata {
-            id: ids::Id::from_slice(&tx_id),
-            tx_bytes_with_no_signature: tx_bytes_with_no_signature.to_vec(),
-            tx_bytes_with_signatures: tx_bytes_with_signatures.to_vec(),
-        });
-
         Ok(())
     }
+
+    fn set_credentials(&mut self, creds: Vec<key::secp256k1::txs::Credential>) {
+        self.fx_creds = creds
+            .into_iter()
+            .map(|cred| fx::Credential {
+                cred,
+                ..Default::default()
+            })
+            .collect();
+    }
 }
 
 /// RUST_LOG=debug cargo test --package avalanche-types --lib -- avm::txs::import::test_import_tx_serialization_with_two_signers --exact --show-output
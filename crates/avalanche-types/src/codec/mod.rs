@@ -1,4 +1,5 @@
 //! Implementation of the avalanchego codec.
+pub mod registry;
 pub mod serde;
 
 use std::collections::HashMap;
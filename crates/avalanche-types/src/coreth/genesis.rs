@@ -177,6 +177,13 @@ pub struct ChainConfig {
     pub banff_block_timestamp: Option<u64>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub cortina_block_timestamp: Option<u64>,
+
+    /// Tunable EIP-1559 fee-market parameters, only set once a network
+    /// opts out of the activation-block defaults.
+    ///
+    /// ref. <https://github.com/ava-labs/subnet-evm/blob/master/params/config.go>
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fee_config: Option<FeeConfig>,
 }
 
 impl Default for ChainConfig {
@@ -216,10 +223,30 @@ impl Default for ChainConfig {
             apricot_phase_post6_block_timestamp: Some(0),
             banff_block_timestamp: Some(0),
             cortina_block_timestamp: Some(0),
+            fee_config: None,
         }
     }
 }
 
+/// Tunable EIP-1559 fee-market parameters for the C-chain.
+///
+/// ref. <https://github.com/ava-labs/subnet-evm/blob/master/params/config.go>
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone, Copy)]
+#[serde(rename_all = "camelCase")]
+pub struct FeeConfig {
+    /// Gas limit per block.
+    pub gas_limit: u64,
+    /// Base fee the market converges to when blocks are exactly at the
+    /// target gas usage.
+    pub target_base_fee: u64,
+    /// Floor below which the base fee cannot drop.
+    pub min_base_fee: u64,
+    /// Denominator controlling how fast the base fee can change per block.
+    pub base_fee_change_denominator: u64,
+    /// Gas cost charged per second a block's timestamp lags real time.
+    pub block_gas_cost_step: u64,
+}
+
 /// ref. <https://pkg.go.dev/github.com/ava-labs/coreth/core#GenesisAlloc>
 /// ref. <https://pkg.go.dev/github.com/ava-labs/coreth/core#GenesisAccount>
 #[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
@@ -0,0 +1,6 @@
+//! Generated contract bindings, `include!`d from `$OUT_DIR` (see `build.rs`);
+//! only compiled in when `evm_abigen` is enabled, since that's the only
+//! feature that actually runs the codegen that produces them.
+#![cfg(feature = "evm_abigen")]
+
+include!(concat!(env!("OUT_DIR"), "/generated/mod.rs"));
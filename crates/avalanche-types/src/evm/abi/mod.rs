@@ -1,8 +1,86 @@
 //! The EVM ABI.
 #![allow(deprecated)]
+
+/// Typed contract bindings generated at compile time from the ABI sources in
+/// the crate's `abi/` directory (see `build.rs`). Each `abi/<name>.json`
+/// yields a `generated::<name>` module with a strongly-typed contract handle,
+/// so callers use e.g. `Counter::new(addr, client).get_last().call().await`
+/// instead of hand-assembling [`ethers_core::abi::Function`] values. Only
+/// compiled in when `evm_abigen` generates it; plain `evm` builds don't get
+/// the `ethers_contract` codegen dependency.
+#[cfg(feature = "evm_abigen")]
+#[cfg_attr(docsrs, doc(cfg(feature = "evm_abigen")))]
+pub mod generated;
+
 use std::io::{self, Error, ErrorKind};
 
-use ethers_core::abi::{Function, Token};
+use std::collections::HashMap;
+
+use ethers_core::abi::{Abi, Function, Token};
+
+/// A parsed Solidity contract ABI, loaded from standard ABI JSON (an array of
+/// `{type, name, inputs, outputs, stateMutability}` entries), that exposes
+/// selector-keyed call encoding and output decoding.
+///
+/// This spares callers from hand-building `ethers_core::abi::Function` values
+/// and calling [`encode_calldata`] directly: `contract.encode("transferFrom",
+/// &tokens)` parses the ABI once and dispatches by function name.
+#[derive(Clone, Debug)]
+pub struct Contract {
+    /// The parsed ABI, keeping the full function/event/error descriptors.
+    pub abi: Abi,
+    /// Functions keyed by their 4-byte selector, for reverse lookups from raw
+    /// calldata.
+    selectors: HashMap<[u8; 4], Function>,
+}
+
+impl Contract {
+    /// Loads a contract from standard Solidity ABI JSON.
+    pub fn load_from_json(s: &str) -> io::Result<Self> {
+        let abi: Abi = serde_json::from_str(s).map_err(|e| {
+            Error::new(
+                ErrorKind::InvalidInput,
+                format!("failed to parse ABI {}", e),
+            )
+        })?;
+        let mut selectors = HashMap::new();
+        for func in abi.functions() {
+            selectors.insert(func.short_signature(), func.clone());
+        }
+        Ok(Self { abi, selectors })
+    }
+
+    /// Looks up a function by name, returning an error if it is absent. For an
+    /// overloaded name the first overload in the ABI is returned.
+    pub fn function(&self, name: &str) -> io::Result<&Function> {
+        self.abi.function(name).map_err(|e| {
+            Error::new(
+                ErrorKind::NotFound,
+                format!("no function '{}' in ABI: {}", name, e),
+            )
+        })
+    }
+
+    /// Looks up a function by its 4-byte selector (e.g. the first four bytes of
+    /// the calldata).
+    pub fn function_by_selector(&self, selector: &[u8; 4]) -> Option<&Function> {
+        self.selectors.get(selector)
+    }
+
+    /// Encodes a call to the named function, returning the selector-prefixed
+    /// calldata.
+    pub fn encode(&self, fn_name: &str, arg_tokens: &[Token]) -> io::Result<Vec<u8>> {
+        let func = self.function(fn_name)?;
+        encode_calldata(func.clone(), arg_tokens)
+    }
+
+    /// Decodes the ABI-encoded return data of the named function into tokens.
+    pub fn decode_output(&self, fn_name: &str, data: &[u8]) -> io::Result<Vec<Token>> {
+        let func = self.function(fn_name)?;
+        func.decode_output(data)
+            .map_err(|e| Error::new(ErrorKind::Other, format!("failed to decode_output {}", e)))
+    }
+}
 
 /// ref. <https://github.com/foundry-rs/foundry/blob/master/common/src/abi.rs> "encode_args"
 pub fn encode_calldata(func: Function, arg_tokens: &[Token]) -> io::Result<Vec<u8>> {
@@ -232,3 +310,50 @@ fn test_encode_calldata_forward_request() {
     let calldata = encode_calldata(func, &arg_tokens).unwrap();
     log::info!("calldata: 0x{}", hex::encode(calldata));
 }
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib --features="evm" -- evm::abi::test_contract_load_from_json --exact --show-output
+#[test]
+fn test_contract_load_from_json() {
+    use ethers_core::{abi::Token, types::U256};
+
+    let _ = env_logger::builder()
+        .filter_level(log::LevelFilter::Debug)
+        .is_test(true)
+        .try_init();
+
+    // minimal ERC-20 "transfer(address,uint256)" ABI
+    let json = r#"[
+        {
+            "type": "function",
+            "name": "transfer",
+            "inputs": [
+                {"name": "to", "type": "address"},
+                {"name": "amount", "type": "uint256"}
+            ],
+            "outputs": [{"name": "", "type": "bool"}],
+            "stateMutability": "nonpayable"
+        }
+    ]"#;
+
+    let contract = Contract::load_from_json(json).unwrap();
+    let arg_tokens = vec![
+        Token::Address(ethers_core::types::H160::random()),
+        Token::Uint(U256::from(1_000_000u64)),
+    ];
+    let calldata = contract.encode("transfer", &arg_tokens).unwrap();
+    log::info!("calldata: 0x{}", hex::encode(&calldata));
+
+    // selector-keyed reverse lookup resolves back to the same function
+    let mut selector = [0u8; 4];
+    selector.copy_from_slice(&calldata[..4]);
+    assert_eq!(
+        contract.function_by_selector(&selector).unwrap().name,
+        "transfer"
+    );
+
+    // decoding a single `true` return token round-trips (ABI bool is a 32-byte word)
+    let mut encoded_true = [0u8; 32];
+    encoded_true[31] = 1;
+    let out = contract.decode_output("transfer", &encoded_true).unwrap();
+    assert_eq!(out, vec![Token::Bool(true)]);
+}
@@ -0,0 +1,109 @@
+//! Deterministic CREATE2 contract deployment.
+//!
+//! Avalanche subnets that coordinate across chains often need a contract
+//! (router, key registry, forwarder, ...) to land at the same address on every
+//! chain. CREATE2 makes the deployed address a pure function of the deployer,
+//! a caller-chosen salt, and the init code, so the same `(init_code, salt)`
+//! yields an identical, independently verifiable address everywhere.
+//! ref. <https://eips.ethereum.org/EIPS/eip-1014>
+use std::io::{self, Error, ErrorKind};
+
+use ethers_core::{
+    types::{Bytes, TransactionRequest, H160},
+    utils::keccak256,
+};
+use ethers_providers::Middleware;
+
+/// Computes the deterministic CREATE2 address
+/// `keccak256(0xff ++ deployer ++ salt ++ keccak256(init_code))[12..]`.
+///
+/// This is pure -- it performs no network access -- so callers can verify the
+/// target address across chains before sending any transaction.
+pub fn create2_address(deployer: H160, salt: [u8; 32], init_code: &[u8]) -> H160 {
+    let mut buf = Vec::with_capacity(1 + 20 + 32 + 32);
+    buf.push(0xff);
+    buf.extend_from_slice(deployer.as_bytes());
+    buf.extend_from_slice(&salt);
+    buf.extend_from_slice(&keccak256(init_code));
+    let hash = keccak256(&buf);
+    H160::from_slice(&hash[12..])
+}
+
+/// Deploys `init_code` through the CREATE2 `factory` at the deterministic
+/// address, unless a contract already lives there.
+///
+/// The factory is expected to forward `salt ++ init_code` to CREATE2 (the
+/// convention used by the canonical deterministic deployers). Returns the
+/// predicted address; if it already has code the deploy is skipped, and if the
+/// post-deploy code is still empty the deployment is treated as failed.
+pub async fn deploy_if_absent<M: Middleware>(
+    client: &M,
+    factory: H160,
+    salt: [u8; 32],
+    init_code: Vec<u8>,
+) -> io::Result<H160> {
+    let predicted = create2_address(factory, salt, &init_code);
+
+    let existing = client
+        .get_code(predicted, None)
+        .await
+        .map_err(|e| Error::new(ErrorKind::Other, format!("failed get_code '{e}'")))?;
+    if !existing.0.is_empty() {
+        log::info!("contract already deployed at 0x{predicted:x}; skipping");
+        return Ok(predicted);
+    }
+
+    // the factory calldata is the salt followed by the init code
+    let mut calldata = Vec::with_capacity(32 + init_code.len());
+    calldata.extend_from_slice(&salt);
+    calldata.extend_from_slice(&init_code);
+
+    let tx = TransactionRequest::new()
+        .to(factory)
+        .data(Bytes::from(calldata));
+    let pending = client
+        .send_transaction(tx, None)
+        .await
+        .map_err(|e| Error::new(ErrorKind::Other, format!("failed send_transaction '{e}'")))?;
+    pending
+        .await
+        .map_err(|e| Error::new(ErrorKind::Other, format!("failed awaiting receipt '{e}'")))?;
+
+    let deployed = client
+        .get_code(predicted, None)
+        .await
+        .map_err(|e| Error::new(ErrorKind::Other, format!("failed get_code '{e}'")))?;
+    if deployed.0.is_empty() {
+        return Err(Error::new(
+            ErrorKind::Other,
+            format!("CREATE2 deployment produced no code at 0x{predicted:x}"),
+        ));
+    }
+
+    log::info!("deployed contract at 0x{predicted:x}");
+    Ok(predicted)
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib --features="evm" -- evm::deploy::test_create2_address --exact --show-output
+#[test]
+fn test_create2_address() {
+    use std::str::FromStr;
+
+    // ref. EIP-1014 "Example 0": deployer 0x0..0, zero salt, init code 0x00
+    let deployer = H160::from_str("0x0000000000000000000000000000000000000000").unwrap();
+    let salt = [0u8; 32];
+    let addr = create2_address(deployer, salt, &[0x00]);
+    assert_eq!(
+        addr,
+        H160::from_str("0x4D1A2e2bB4F88F0250f26Ffff098B0b30B26Bf38").unwrap()
+    );
+
+    // the same salt + init code is deterministic
+    let addr2 = create2_address(deployer, salt, &[0x00]);
+    assert_eq!(addr, addr2);
+
+    // a different salt yields a different address
+    let mut salt2 = [0u8; 32];
+    salt2[31] = 1;
+    assert_ne!(create2_address(deployer, salt2, &[0x00]), addr);
+}
@@ -8,8 +8,9 @@ use crate::evm::abi as evm_abi;
 use ethers_core::{
     abi::{Function, Param, ParamType, StateMutability, Token},
     types::{
-        transaction::eip712::{
-            EIP712Domain, Eip712, Eip712DomainType, Eip712Error, TypedData, Types,
+        transaction::{
+            eip2930::AccessList,
+            eip712::{EIP712Domain, Eip712, Eip712DomainType, Eip712Error, TypedData, Types},
         },
         H160, H256, U256,
     },
@@ -91,6 +92,12 @@ pub struct Tx {
     /// Must match with the one used in "registerRequestType".
     /// ref. <https://github.com/opengsn/gsn/blob/master/packages/contracts/src/forwarder/Forwarder.sol> "registerRequestType"
     pub type_suffix_data: String,
+
+    /// EIP-2930 access list pre-computed (via `eth_createAccessList`) for the
+    /// typed transaction that wraps this forward request's "execute" call, to
+    /// lower gas on calls that touch many storage slots.
+    /// ref. <https://eips.ethereum.org/EIPS/eip-2930>
+    pub access_list: Option<AccessList>,
 }
 
 impl Tx {
@@ -111,6 +118,8 @@ impl Tx {
 
             type_name: String::new(),
             type_suffix_data: String::new(),
+
+            access_list: None,
         }
     }
 
@@ -193,6 +202,12 @@ impl Tx {
         self
     }
 
+    #[must_use]
+    pub fn access_list(mut self, access_list: impl Into<AccessList>) -> Self {
+        self.access_list = Some(access_list.into());
+        self
+    }
+
     /// ref. <https://github.com/opengsn/gsn/blob/master/packages/contracts/src/forwarder/Forwarder.sol> "registerDomainSeparator"
     fn eip712_domain(&self) -> EIP712Domain {
         EIP712Domain {
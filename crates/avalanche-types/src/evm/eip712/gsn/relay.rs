@@ -1,5 +1,7 @@
 #![allow(deprecated)]
 
+pub mod server;
+
 use std::{
     convert::TryFrom,
     io::{self, Error, ErrorKind},
@@ -21,6 +23,29 @@ use serde_with::serde_as;
 use tokio::time::{sleep, Duration, Instant};
 use zerocopy::AsBytes;
 
+use crate::wallet::evm::middleware::ForwarderNonceMiddleware;
+
+/// Quorum threshold for [`super::Tx::sign_to_request_with_estimated_gas_quorum`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GasQuorumPolicy {
+    /// A strict majority (more than half) of the queried endpoints must agree.
+    Majority,
+    /// Every queried endpoint must agree.
+    All,
+    /// At least `n` endpoints must agree, regardless of how many were queried.
+    AtLeast(usize),
+}
+
+impl GasQuorumPolicy {
+    fn is_satisfied(self, agreeing: usize, total: usize) -> bool {
+        match self {
+            GasQuorumPolicy::Majority => agreeing * 2 > total,
+            GasQuorumPolicy::All => agreeing == total,
+            GasQuorumPolicy::AtLeast(n) => agreeing >= n,
+        }
+    }
+}
+
 impl super::Tx {
     pub async fn sign(
         &self,
@@ -127,6 +152,218 @@ impl super::Tx {
         }
         return Err(Error::new(ErrorKind::Other, "failed estimate_gas in time"));
     }
+
+    /// "sign_to_request_with_estimated_gas" but first calls
+    /// "eth_createAccessList" to pre-compute an EIP-2930 access list for the
+    /// typed transaction, storing it on "self.access_list" so it's included
+    /// both in the gas estimation and the forward request's calldata path,
+    /// and uses the "gasUsed" returned alongside the access list as the
+    /// estimated gas rather than a second "eth_estimateGas" round-trip.
+    /// Falls back to the access-list-free "sign_to_request_with_estimated_gas"
+    /// path if the node doesn't support "eth_createAccessList" (or the call
+    /// otherwise fails).
+    /// ref. <https://eips.ethereum.org/EIPS/eip-2930>
+    pub async fn sign_to_request_with_estimated_gas_and_access_list(
+        &mut self,
+        eth_signer: impl ethers_signers::Signer + Clone,
+        chain_rpc_provider: Arc<Provider<RetryClient<Http>>>,
+    ) -> io::Result<Request> {
+        let eip1559_tx = Eip1559TransactionRequest::new()
+            .chain_id(self.domain_chain_id.as_u64())
+            .from(self.from)
+            .to(self.to)
+            .gas(self.gas)
+            .data(self.data.clone());
+        let typed_tx: eip2718::TypedTransaction = eip1559_tx.into();
+
+        match chain_rpc_provider.create_access_list(&typed_tx, None).await {
+            Ok(access_list_with_gas_used) => {
+                log::info!(
+                    "eth_createAccessList returned {} entries, gas used {}",
+                    access_list_with_gas_used.access_list.0.len(),
+                    access_list_with_gas_used.gas_used
+                );
+                self.access_list = Some(access_list_with_gas_used.access_list);
+                self.gas = access_list_with_gas_used.gas_used;
+                Request::sign_to_request(self, eth_signer).await
+            }
+            Err(e) => {
+                log::warn!(
+                    "failed eth_createAccessList '{}' -- falling back to access-list-free gas estimation",
+                    e
+                );
+                self.sign_to_request_with_estimated_gas(eth_signer, chain_rpc_provider)
+                    .await
+            }
+        }
+    }
+
+    /// "sign_to_request" but with "self.nonce" resolved via a
+    /// [`ForwarderNonceMiddleware`], so a stale caller-supplied nonce can no
+    /// longer silently produce an un-relayable request. The on-chain
+    /// forwarder nonce overrides any locally cached value that's behind it;
+    /// a cached value that's ahead (from a batch of requests signed
+    /// back-to-back that haven't been mined yet) is kept, so callers can
+    /// batch several relayed transactions without a round-trip each time.
+    /// If a signed request built this way is dropped instead of submitted,
+    /// call [`ForwarderNonceMiddleware::reset`] so the next call doesn't
+    /// skip ahead of the on-chain nonce.
+    pub async fn sign_to_request_with_managed_nonce(
+        &mut self,
+        eth_signer: impl ethers_signers::Signer + Clone,
+        nonce_manager: &ForwarderNonceMiddleware,
+    ) -> io::Result<Request> {
+        self.nonce = nonce_manager.next_nonce(self.from).await.map_err(|e| {
+            Error::new(
+                ErrorKind::Other,
+                format!("failed to resolve forwarder nonce '{}'", e),
+            )
+        })?;
+        Request::sign_to_request(self, eth_signer).await
+    }
+
+    /// "sign_to_request_with_estimated_gas" but against several RPC endpoints
+    /// at once, following ethers' `QuorumProvider` pattern (see
+    /// [`crate::jsonrpc::client::provider::quorum`] for the read-quorum
+    /// equivalent used elsewhere in this crate): issues `eth_estimateGas`
+    /// concurrently to every endpoint in `providers`, and proceeds once
+    /// `quorum` is satisfied by the endpoints whose estimate falls within
+    /// `tolerance_percent` of the highest one returned. Divergent endpoints
+    /// outside the tolerance window don't count toward quorum, and the
+    /// conservative (highest) estimate among the agreeing endpoints is used,
+    /// to avoid `gas required exceeds allowance`. Every per-endpoint error is
+    /// folded into the returned error message if quorum isn't met, so callers
+    /// can see which providers failed.
+    pub async fn sign_to_request_with_estimated_gas_quorum(
+        &mut self,
+        eth_signer: impl ethers_signers::Signer + Clone,
+        providers: &[Arc<Provider<RetryClient<Http>>>],
+        quorum: GasQuorumPolicy,
+        tolerance_percent: u64,
+    ) -> io::Result<Request> {
+        let eip1559_tx = Eip1559TransactionRequest::new()
+            .chain_id(self.domain_chain_id.as_u64())
+            .from(self.from)
+            .to(self.to)
+            .gas(self.gas)
+            .data(self.data.clone());
+        let typed_tx: eip2718::TypedTransaction = eip1559_tx.into();
+
+        let results = futures::future::join_all(providers.iter().enumerate().map(|(i, p)| {
+            let typed_tx = typed_tx.clone();
+            async move {
+                p.estimate_gas(&typed_tx, None)
+                    .await
+                    .map_err(|e| format!("endpoint {i}: {e}"))
+            }
+        }))
+        .await;
+
+        let mut estimates = Vec::new();
+        let mut endpoint_errors = Vec::new();
+        for r in results {
+            match r {
+                Ok(g) => estimates.push(g),
+                Err(e) => endpoint_errors.push(e),
+            }
+        }
+
+        let highest = *estimates.iter().max().ok_or_else(|| {
+            Error::new(
+                ErrorKind::Other,
+                format!(
+                    "no endpoint returned a gas estimate out of {} queried ({})",
+                    providers.len(),
+                    endpoint_errors.join("; ")
+                ),
+            )
+        })?;
+        let tolerance = highest * U256::from(tolerance_percent) / U256::from(100u64);
+        let agreeing = estimates
+            .iter()
+            .filter(|g| highest.saturating_sub(**g) <= tolerance)
+            .count();
+
+        if !quorum.is_satisfied(agreeing, providers.len()) {
+            return Err(Error::new(
+                ErrorKind::Other,
+                format!(
+                    "gas estimate quorum {quorum:?} not met: only {agreeing}/{} endpoint(s) agreed within {tolerance_percent}% ({})",
+                    providers.len(),
+                    endpoint_errors.join("; ")
+                ),
+            ));
+        }
+        if !endpoint_errors.is_empty() {
+            log::warn!(
+                "gas estimate quorum met ({agreeing}/{} agreed) despite endpoint failures: {}",
+                providers.len(),
+                endpoint_errors.join("; ")
+            );
+        }
+        log::info!("estimated gas {highest} via quorum {agreeing}/{} -- now signing again with updated gas", providers.len());
+
+        self.gas = highest;
+        Request::sign_to_request(&self, eth_signer).await
+    }
+
+    /// "sign_to_request" but with EIP-1559 fees dynamically estimated via
+    /// "eth_feeHistory", attached to the relay metadata so relayers don't
+    /// over- or under-pay, instead of a fixed-gas retry loop.
+    ///
+    /// Queries `eth_feeHistory(blockCount=block_window, newestBlock="latest",
+    /// rewardPercentiles=[reward_percentile])`, averages the returned
+    /// `reward` column across the window for the priority fee (falling back
+    /// to `default_priority_fee_per_gas` if the node returns no reward data),
+    /// and sets `maxFeePerGas = baseFeePerGas * 2 + priorityFee` and
+    /// `maxPriorityFeePerGas = priorityFee`, both clamped to
+    /// `max_fee_per_gas_ceiling` to avoid runaway fees during a spike.
+    pub async fn sign_to_request_with_eip1559_fees(
+        &self,
+        eth_signer: impl ethers_signers::Signer + Clone,
+        chain_rpc_provider: Arc<Provider<RetryClient<Http>>>,
+        block_window: u64,
+        reward_percentile: f64,
+        default_priority_fee_per_gas: U256,
+        max_fee_per_gas_ceiling: U256,
+    ) -> io::Result<Request> {
+        let history = chain_rpc_provider
+            .fee_history(
+                U256::from(block_window),
+                ethers_core::types::BlockNumber::Latest,
+                &[reward_percentile],
+            )
+            .await
+            .map_err(|e| Error::new(ErrorKind::Other, format!("failed eth_feeHistory '{}'", e)))?;
+
+        let base_fee_per_gas = history.base_fee_per_gas.last().copied().unwrap_or_default();
+
+        let rewards: Vec<U256> = history
+            .reward
+            .iter()
+            .filter_map(|block_rewards| block_rewards.first().copied())
+            .collect();
+        let priority_fee_per_gas = if rewards.is_empty() {
+            log::warn!(
+                "eth_feeHistory returned no reward data, falling back to the configured default priority fee"
+            );
+            default_priority_fee_per_gas
+        } else {
+            rewards.iter().fold(U256::zero(), |acc, r| acc + r) / U256::from(rewards.len())
+        };
+
+        let max_fee_per_gas = (base_fee_per_gas * U256::from(2u64) + priority_fee_per_gas)
+            .min(max_fee_per_gas_ceiling);
+        let max_priority_fee_per_gas = priority_fee_per_gas.min(max_fee_per_gas_ceiling);
+        log::info!(
+            "estimated eip-1559 fees via eth_feeHistory: max_fee_per_gas {max_fee_per_gas}, max_priority_fee_per_gas {max_priority_fee_per_gas}"
+        );
+
+        let mut req = Request::sign_to_request(self, eth_signer).await?;
+        req.metadata.max_fee_per_gas = Some(max_fee_per_gas);
+        req.metadata.max_priority_fee_per_gas = Some(max_priority_fee_per_gas);
+        Ok(req)
+    }
 }
 
 /// Used for gas relayer server, compatible with the OpenGSN request.
@@ -150,6 +387,15 @@ pub struct Request {
 pub struct Metadata {
     #[serde_as(as = "serde_with::hex::Hex")]
     pub signature: Vec<u8>,
+
+    /// EIP-1559 priority fee suggested for the relayer to use when relaying
+    /// this request, in wei. Only set by `Tx::sign_to_request_with_eip1559_fees`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_priority_fee_per_gas: Option<U256>,
+    /// EIP-1559 max fee suggested for the relayer to use when relaying this
+    /// request, in wei. Only set by `Tx::sign_to_request_with_eip1559_fees`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_fee_per_gas: Option<U256>,
 }
 
 impl Request {
@@ -225,6 +471,8 @@ impl Request {
             forward_request: tx.typed_data(),
             metadata: Metadata {
                 signature: sig.to_vec(),
+                max_priority_fee_per_gas: None,
+                max_fee_per_gas: None,
             },
         })
     }
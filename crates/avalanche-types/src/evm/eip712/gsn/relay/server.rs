@@ -0,0 +1,170 @@
+//! A minimal GSN relay server: decodes an OpenGSN `eth_sendRawTransaction`
+//! body, verifies it, and forwards the wrapped "execute" call on-chain.
+//!
+//! This is the server-side counterpart to [`super::Request::from_send_raw_transaction`]
+//! and [`super::Request::recover_signature`] -- together they let this crate
+//! act as both sides of the GSN protocol instead of only the client side.
+
+use std::{
+    fmt,
+    sync::Arc,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use super::Request;
+use crate::wallet::evm::{self, middleware::ForwarderNonceMiddleware};
+use ethers::prelude::{Eip1559TransactionRequest, SignerMiddleware};
+use ethers_core::types::{transaction::eip2718::TypedTransaction, H256, U256};
+use ethers_providers::{Http, Middleware, Provider, RetryClient};
+
+/// Why a relay request was rejected before being forwarded on-chain.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RejectionReason {
+    /// Failed to decode the `eth_sendRawTransaction` body into a [`Request`].
+    Decode(String),
+    /// The recovered signer doesn't match `forward_request.message.from`.
+    InvalidSignature,
+    /// `validUntilTime` has already passed.
+    Expired,
+    /// The forwarder's on-chain nonce doesn't match the request's nonce.
+    NonceMismatch { expected: U256, got: U256 },
+    /// Verification or broadcast failed for another reason, e.g. a failed
+    /// RPC call or a reverted "execute" transaction.
+    Internal(String),
+}
+
+impl fmt::Display for RejectionReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RejectionReason::Decode(m) => write!(f, "failed to decode relay request: {m}"),
+            RejectionReason::InvalidSignature => write!(
+                f,
+                "recovered signer does not match forward_request.message.from"
+            ),
+            RejectionReason::Expired => write!(f, "forward request validUntilTime has passed"),
+            RejectionReason::NonceMismatch { expected, got } => write!(
+                f,
+                "forwarder nonce mismatch: on-chain nonce is {expected}, request has {got}"
+            ),
+            RejectionReason::Internal(m) => write!(f, "{m}"),
+        }
+    }
+}
+
+impl std::error::Error for RejectionReason {}
+
+/// Verifies decoded GSN relay requests and forwards them on-chain with a
+/// configured relayer wallet.
+pub struct Server<S: ethers_signers::Signer + Clone> {
+    pub type_name: String,
+    pub type_suffix_data: String,
+    pub relayer_signer: S,
+    pub chain_rpc_provider: Arc<Provider<RetryClient<Http>>>,
+}
+
+impl<S> Server<S>
+where
+    S: ethers_signers::Signer + Clone + 'static,
+    S::Error: 'static,
+{
+    #[must_use]
+    pub fn new(
+        type_name: impl Into<String>,
+        type_suffix_data: impl Into<String>,
+        relayer_signer: S,
+        chain_rpc_provider: Arc<Provider<RetryClient<Http>>>,
+    ) -> Self {
+        Self {
+            type_name: type_name.into(),
+            type_suffix_data: type_suffix_data.into(),
+            relayer_signer,
+            chain_rpc_provider,
+        }
+    }
+
+    /// Decodes an `eth_sendRawTransaction` JSON-RPC body carrying a signed GSN
+    /// [`Request`], verifies it, and relays it on-chain.
+    /// Returns the relayed transaction hash or a structured rejection reason.
+    pub async fn handle_send_raw_transaction(
+        &self,
+        body: serde_json::Value,
+    ) -> Result<H256, RejectionReason> {
+        let req = Request::from_send_raw_transaction(body)
+            .map_err(|e| RejectionReason::Decode(e.to_string()))?;
+        self.handle_request(&req).await
+    }
+
+    /// Verifies an already-decoded GSN [`Request`] and relays it on-chain.
+    ///
+    /// Confirms the recovered signer matches `forward_request.message.from`,
+    /// checks `validUntilTime` against the current clock, verifies the
+    /// on-chain forwarder nonce matches, and then submits the wrapped
+    /// `execute` call as a real EIP-1559 transaction from `relayer_signer`.
+    pub async fn handle_request(&self, req: &Request) -> Result<H256, RejectionReason> {
+        let (sig, signer_addr) = req
+            .recover_signature(&self.type_name, &self.type_suffix_data)
+            .map_err(|e| RejectionReason::Internal(e.to_string()))?;
+        let tx = req
+            .recover_tx(&self.type_name, &self.type_suffix_data)
+            .map_err(|e| RejectionReason::Internal(e.to_string()))?;
+
+        if signer_addr != tx.from {
+            return Err(RejectionReason::InvalidSignature);
+        }
+
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_err(|e| RejectionReason::Internal(format!("failed to read system clock '{}'", e)))?
+            .as_secs();
+        if tx.valid_until_time < U256::from(now) {
+            return Err(RejectionReason::Expired);
+        }
+
+        let nonce_manager = ForwarderNonceMiddleware::new(
+            Arc::clone(&self.chain_rpc_provider),
+            tx.domain_verifying_contract,
+        );
+        let on_chain_nonce = nonce_manager
+            .get_nonce(tx.from)
+            .await
+            .map_err(|e| RejectionReason::Internal(format!("failed forwarder getNonce '{}'", e)))?;
+        if on_chain_nonce != tx.nonce {
+            return Err(RejectionReason::NonceMismatch {
+                expected: on_chain_nonce,
+                got: tx.nonce,
+            });
+        }
+
+        let calldata = tx.encode_execute_call(sig.to_vec()).map_err(|e| {
+            RejectionReason::Internal(format!("failed encode_execute_call '{}'", e))
+        })?;
+
+        let mut execute_tx: TypedTransaction = Eip1559TransactionRequest::new()
+            .to(tx.domain_verifying_contract)
+            .data(calldata)
+            .into();
+
+        evm::fill_1559_fees(
+            Arc::clone(&self.chain_rpc_provider),
+            &mut execute_tx,
+            evm::DEFAULT_BASE_FEE_MULTIPLIER,
+            U256::from(evm::DEFAULT_PRIORITY_FEE),
+            3,
+            Duration::from_secs(1),
+        )
+        .await
+        .map_err(|e| RejectionReason::Internal(e.to_string()))?;
+
+        let signer_middleware =
+            SignerMiddleware::new(self.chain_rpc_provider.clone(), self.relayer_signer.clone());
+
+        let pending = signer_middleware
+            .send_transaction(execute_tx, None)
+            .await
+            .map_err(|e| {
+                RejectionReason::Internal(format!("failed to relay execute call '{}'", e))
+            })?;
+
+        Ok(*pending)
+    }
+}
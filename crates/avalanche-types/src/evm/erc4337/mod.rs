@@ -0,0 +1,99 @@
+//! ERC-4337 account-abstraction support.
+//!
+//! Parallel to the EIP-712 GSN forwarder in [`crate::evm::eip712::gsn`], this
+//! module models the account-abstraction flow so gasless operations can be
+//! submitted through a standard bundler (the EntryPoint contract) instead of a
+//! custom relay server. Build a [`UserOperation`], compute its
+//! [`UserOperation::hash`], sign it with a secp256k1 [`crate::key::secp256k1::private_key::Key`],
+//! and submit it via [`crate::jsonrpc::client::evm::send_user_operation`].
+//!
+//! ref. <https://eips.ethereum.org/EIPS/eip-4337>
+
+use std::io::{self, Error, ErrorKind};
+
+use ethers_core::{
+    abi::{encode, Token},
+    types::{Bytes, H160, U256},
+    utils::keccak256,
+};
+
+/// A single account-abstraction operation, matching the EntryPoint
+/// `UserOperation` tuple.
+/// ref. <https://eips.ethereum.org/EIPS/eip-4337#useroperation>
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct UserOperation {
+    /// The account making the operation.
+    pub sender: H160,
+    /// Anti-replay nonce managed by the EntryPoint.
+    pub nonce: U256,
+    /// Account-deployment calldata (empty once the account exists).
+    pub init_code: Bytes,
+    /// The method call to execute on the account.
+    pub call_data: Bytes,
+    /// Gas allocated to the main execution call.
+    pub call_gas_limit: U256,
+    /// Gas allocated to the verification step.
+    pub verification_gas_limit: U256,
+    /// Gas paid to the bundler to compensate for pre-verification overhead.
+    pub pre_verification_gas: U256,
+    /// EIP-1559 max fee per gas.
+    pub max_fee_per_gas: U256,
+    /// EIP-1559 max priority fee per gas.
+    pub max_priority_fee_per_gas: U256,
+    /// Paymaster address and paymaster-specific data (empty if self-paying).
+    pub paymaster_and_data: Bytes,
+    /// Signature over [`UserOperation::hash`], filled by [`UserOperation::sign`].
+    pub signature: Bytes,
+}
+
+impl UserOperation {
+    /// ABI-encodes every field except `signature`, with the dynamic
+    /// `init_code`/`call_data`/`paymaster_and_data` fields replaced by their
+    /// keccak hashes, matching the EntryPoint's `pack` layout.
+    fn packed(&self) -> Vec<u8> {
+        encode(&[
+            Token::Address(self.sender),
+            Token::Uint(self.nonce),
+            Token::FixedBytes(keccak256(&self.init_code).to_vec()),
+            Token::FixedBytes(keccak256(&self.call_data).to_vec()),
+            Token::Uint(self.call_gas_limit),
+            Token::Uint(self.verification_gas_limit),
+            Token::Uint(self.pre_verification_gas),
+            Token::Uint(self.max_fee_per_gas),
+            Token::Uint(self.max_priority_fee_per_gas),
+            Token::FixedBytes(keccak256(&self.paymaster_and_data).to_vec()),
+        ])
+    }
+
+    /// Computes `userOpHash = keccak256(abi.encode(keccak256(packed),
+    /// entry_point, chain_id))`.
+    pub fn hash(&self, entry_point: H160, chain_id: U256) -> [u8; 32] {
+        let packed_hash = keccak256(self.packed());
+        keccak256(encode(&[
+            Token::FixedBytes(packed_hash.to_vec()),
+            Token::Address(entry_point),
+            Token::Uint(chain_id),
+        ]))
+    }
+
+    /// Signs the `userOpHash` with the given secp256k1 key and stores the
+    /// 65-byte signature in `signature`, returning the signed operation.
+    pub fn sign(
+        mut self,
+        key: &crate::key::secp256k1::private_key::Key,
+        entry_point: H160,
+        chain_id: U256,
+    ) -> io::Result<Self> {
+        let hash = self.hash(entry_point, chain_id);
+        let sig = key
+            .sign_digest(&hash)
+            .map_err(|e| Error::new(ErrorKind::Other, format!("failed to sign userOpHash '{}'", e)))?;
+
+        // Ethereum expects v in {27, 28}; sign_digest emits the 0/1 recovery id
+        // in the trailing byte.
+        let mut bytes = sig.to_bytes().to_vec();
+        bytes[64] += 27;
+        self.signature = Bytes::from(bytes);
+        Ok(self)
+    }
+}
@@ -2,6 +2,8 @@
 //!
 //! Includes the Ethereum ABI, EIP-1559, EIP-712, and Foundry.
 pub mod abi;
+pub mod deploy;
 pub mod eip1559;
+pub mod erc4337;
 pub mod eip712;
 pub mod foundry;
@@ -0,0 +1,120 @@
+//! Salted Bloom filter backing the pull-gossip subsystem.
+//!
+//! Each bit index for an id is computed as `H(salt || id, i) mod m` for
+//! `i in 0..k`, so rotating the `salt` moves every element to a fresh set of
+//! bit positions and thereby resets the false positives accumulated as the
+//! filter fills up.
+//! ref. <https://github.com/ava-labs/avalanchego/blob/master/network/p2p/gossip/bloom.go>
+
+use crate::{hash, ids};
+
+/// A salted Bloom filter over [`ids::Id`]s with `m` bits and `k` hash functions.
+#[derive(Debug, Clone)]
+pub struct Filter {
+    /// Per-filter salt mixed into every hash so the filter can be reset by
+    /// rotating it.
+    salt: Vec<u8>,
+    /// Bit array, `m` bits packed into bytes.
+    bits: Vec<u8>,
+    /// Number of bits `m`.
+    num_bits: usize,
+    /// Number of hash functions `k`.
+    num_hashes: usize,
+    /// Number of elements added, used to estimate the fill ratio.
+    count: usize,
+}
+
+impl Filter {
+    /// Creates an empty filter with `num_bits` bits and `num_hashes` hashes.
+    /// `num_bits` is rounded up to a whole number of bytes.
+    pub fn new(num_bits: usize, num_hashes: usize, salt: Vec<u8>) -> Self {
+        let num_bits = num_bits.max(8);
+        Self {
+            salt,
+            bits: vec![0u8; num_bits.div_ceil(8)],
+            num_bits,
+            num_hashes,
+            count: 0,
+        }
+    }
+
+    /// Rebuilds a filter from its wire parts (e.g. a decoded
+    /// [`crate::gossip::PullGossipRequest`]). The element count is unknown from
+    /// the bits alone and is left at zero.
+    pub fn from_parts(salt: Vec<u8>, bits: Vec<u8>, num_bits: usize, num_hashes: usize) -> Self {
+        Self {
+            salt,
+            bits,
+            num_bits,
+            num_hashes,
+            count: 0,
+        }
+    }
+
+    /// Salt currently mixed into the hashes.
+    pub fn salt(&self) -> &[u8] {
+        &self.salt
+    }
+
+    /// Raw packed bit array.
+    pub fn bits(&self) -> &[u8] {
+        &self.bits
+    }
+
+    pub fn num_bits(&self) -> usize {
+        self.num_bits
+    }
+
+    pub fn num_hashes(&self) -> usize {
+        self.num_hashes
+    }
+
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Yields the `k` bit indices for `id`.
+    fn indices(&self, id: &ids::Id) -> Vec<usize> {
+        (0..self.num_hashes)
+            .map(|i| {
+                let mut preimage = Vec::with_capacity(self.salt.len() + ids::LEN + 4);
+                preimage.extend_from_slice(&self.salt);
+                preimage.extend_from_slice(id.as_ref());
+                preimage.extend_from_slice(&(i as u32).to_be_bytes());
+
+                let digest = hash::sha256(&preimage);
+                let mut b = [0u8; 8];
+                b.copy_from_slice(&digest[..8]);
+                (u64::from_be_bytes(b) as usize) % self.num_bits
+            })
+            .collect()
+    }
+
+    /// Adds `id` to the filter.
+    pub fn add(&mut self, id: &ids::Id) {
+        for idx in self.indices(id) {
+            self.bits[idx / 8] |= 1 << (idx % 8);
+        }
+        self.count += 1;
+    }
+
+    /// Reports whether `id` is (probably) present. False positives are possible,
+    /// false negatives are not.
+    pub fn contains(&self, id: &ids::Id) -> bool {
+        self.indices(id)
+            .into_iter()
+            .all(|idx| self.bits[idx / 8] & (1 << (idx % 8)) != 0)
+    }
+
+    /// Estimated fraction of bits set, used as a saturation proxy.
+    pub fn fill_ratio(&self) -> f64 {
+        let set: u32 = self.bits.iter().map(|b| b.count_ones()).sum();
+        f64::from(set) / self.num_bits as f64
+    }
+
+    /// Estimated false-positive rate given the current fill ratio, i.e.
+    /// `fill_ratio^k`.
+    pub fn false_positive_rate(&self) -> f64 {
+        self.fill_ratio().powi(self.num_hashes as i32)
+    }
+}
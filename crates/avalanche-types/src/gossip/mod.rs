@@ -0,0 +1,211 @@
+//! Salted Bloom-filter pull-gossip subsystem.
+//!
+//! A requesting node serializes the set of tx ids it already knows into a
+//! [`PullGossipRequest`] (a Bloom `filter` plus its `salt`); the responding node
+//! walks its own known set, tests each item against the received filter, and
+//! returns the byte encodings of the items that are *not* present in
+//! [`PullGossipResponse::gossip`]. As the local filter fills up, the accumulated
+//! false-positive rate is tracked and, once it crosses a configurable threshold,
+//! the salt is rotated and the filter rebuilt so the node stops leaking gossip.
+//! ref. <https://github.com/ava-labs/avalanchego/tree/master/network/p2p/gossip>
+
+pub mod bloom;
+
+use rand::{thread_rng, RngCore};
+
+use crate::{
+    errors::{Error, Result},
+    ids,
+};
+
+/// Length of a freshly generated filter salt, in bytes.
+pub const SALT_LEN: usize = 32;
+
+/// Mirrors the `PullGossipRequest { filter, salt }` prost message: the requester
+/// sends the serialized Bloom filter of what it already has plus its salt.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PullGossipRequest {
+    pub filter: Vec<u8>,
+    pub salt: Vec<u8>,
+}
+
+/// Mirrors the `PullGossipResponse { gossip }` prost message: the byte encodings
+/// of the items the responder holds that the requester is missing.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PullGossipResponse {
+    pub gossip: Vec<Vec<u8>>,
+}
+
+/// Serializes a filter to the wire layout `[num_hashes: u32][num_bits: u32][bits..]`,
+/// returning it alongside the salt carried separately in the request.
+fn encode_filter(filter: &bloom::Filter) -> (Vec<u8>, Vec<u8>) {
+    let mut b = Vec::with_capacity(8 + filter.bits().len());
+    b.extend_from_slice(&(filter.num_hashes() as u32).to_be_bytes());
+    b.extend_from_slice(&(filter.num_bits() as u32).to_be_bytes());
+    b.extend_from_slice(filter.bits());
+    (b, filter.salt().to_vec())
+}
+
+/// Reconstructs a filter from a received `filter`/`salt` pair, rejecting a
+/// truncated or size-inconsistent payload rather than panicking.
+fn decode_filter(filter: &[u8], salt: &[u8]) -> Result<bloom::Filter> {
+    if filter.len() < 8 {
+        return Err(Error::Other {
+            message: format!("pull-gossip filter too short ({} bytes)", filter.len()),
+            retryable: false,
+        });
+    }
+    let num_hashes = u32::from_be_bytes([filter[0], filter[1], filter[2], filter[3]]) as usize;
+    let num_bits = u32::from_be_bytes([filter[4], filter[5], filter[6], filter[7]]) as usize;
+    let bits = &filter[8..];
+    if bits.len() != num_bits.div_ceil(8) {
+        return Err(Error::Other {
+            message: format!(
+                "pull-gossip filter bit length {} does not match declared {num_bits} bits",
+                bits.len()
+            ),
+            retryable: false,
+        });
+    }
+    Ok(bloom::Filter::from_parts(
+        salt.to_vec(),
+        bits.to_vec(),
+        num_bits,
+        num_hashes,
+    ))
+}
+
+/// Builds the response to a `PullGossipRequest`: every locally known item whose
+/// id is not present in the requester's filter is returned by its encoding.
+pub fn respond(
+    request: &PullGossipRequest,
+    local: &[(ids::Id, Vec<u8>)],
+) -> Result<PullGossipResponse> {
+    let filter = decode_filter(&request.filter, &request.salt)?;
+    let gossip = local
+        .iter()
+        .filter(|(id, _)| !filter.contains(id))
+        .map(|(_, bytes)| bytes.clone())
+        .collect();
+    Ok(PullGossipResponse { gossip })
+}
+
+/// Generates a fresh random salt.
+fn random_salt() -> Vec<u8> {
+    let mut salt = vec![0u8; SALT_LEN];
+    thread_rng().fill_bytes(&mut salt);
+    salt
+}
+
+/// A node's known set, maintaining a Bloom filter over its members and rotating
+/// the salt (rebuilding the filter) once the false-positive rate saturates.
+#[derive(Debug, Clone)]
+pub struct Set {
+    filter: bloom::Filter,
+    elements: Vec<(ids::Id, Vec<u8>)>,
+    num_bits: usize,
+    num_hashes: usize,
+    max_false_positive_rate: f64,
+}
+
+impl Set {
+    /// Creates an empty set sized `num_bits`/`num_hashes`, rotating the salt
+    /// whenever the estimated false-positive rate exceeds
+    /// `max_false_positive_rate`.
+    pub fn new(num_bits: usize, num_hashes: usize, max_false_positive_rate: f64) -> Self {
+        Self {
+            filter: bloom::Filter::new(num_bits, num_hashes, random_salt()),
+            elements: Vec::new(),
+            num_bits,
+            num_hashes,
+            max_false_positive_rate,
+        }
+    }
+
+    /// Adds an element and rotates the salt if the filter has saturated.
+    pub fn add(&mut self, id: ids::Id, bytes: Vec<u8>) {
+        self.filter.add(&id);
+        self.elements.push((id, bytes));
+        if self.filter.false_positive_rate() > self.max_false_positive_rate {
+            self.rotate();
+        }
+    }
+
+    /// Rotates the salt and rebuilds the filter over the current members, which
+    /// resets the accumulated false positives to their new bit positions.
+    fn rotate(&mut self) {
+        let mut filter = bloom::Filter::new(self.num_bits, self.num_hashes, random_salt());
+        for (id, _) in &self.elements {
+            filter.add(id);
+        }
+        self.filter = filter;
+    }
+
+    /// Serializes the current filter into a request a peer can answer.
+    pub fn to_pull_request(&self) -> PullGossipRequest {
+        let (filter, salt) = encode_filter(&self.filter);
+        PullGossipRequest { filter, salt }
+    }
+
+    pub fn filter(&self) -> &bloom::Filter {
+        &self.filter
+    }
+
+    pub fn len(&self) -> usize {
+        self.elements.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.elements.is_empty()
+    }
+}
+
+fn id_of(b: u8) -> ids::Id {
+    ids::Id::from_slice(&[b; ids::LEN])
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- gossip::test_respond_returns_only_unknown --exact --show-output
+#[test]
+fn test_respond_returns_only_unknown() {
+    // requester knows ids 1..=3
+    let mut set = Set::new(1 << 12, 8, 0.01);
+    for i in 1..=3u8 {
+        set.add(id_of(i), vec![i]);
+    }
+    let request = set.to_pull_request();
+
+    // responder holds 1..=5; only 4 and 5 are missing from the requester
+    let local: Vec<(ids::Id, Vec<u8>)> = (1..=5u8).map(|i| (id_of(i), vec![i])).collect();
+    let resp = respond(&request, &local).expect("failed to respond");
+
+    assert_eq!(resp.gossip, vec![vec![4u8], vec![5u8]]);
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- gossip::test_no_false_negatives --exact --show-output
+#[test]
+fn test_no_false_negatives() {
+    let mut filter = bloom::Filter::new(1 << 10, 6, vec![0xab; SALT_LEN]);
+    for i in 0..200u8 {
+        filter.add(&id_of(i));
+    }
+    // every added id must still test present (no false negatives)
+    for i in 0..200u8 {
+        assert!(filter.contains(&id_of(i)));
+    }
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- gossip::test_saturation_rotates_salt --exact --show-output
+#[test]
+fn test_saturation_rotates_salt() {
+    // a tiny filter with a low threshold saturates after a few inserts
+    let mut set = Set::new(64, 4, 0.001);
+    let salt_before = set.filter().salt().to_vec();
+    for i in 0..32u8 {
+        set.add(id_of(i), vec![i]);
+    }
+    // the salt must have rotated, and every member is still present afterwards
+    assert_ne!(set.filter().salt().to_vec(), salt_before);
+    for i in 0..32u8 {
+        assert!(set.filter().contains(&id_of(i)));
+    }
+}
@@ -251,6 +251,11 @@ pub struct GetUtxosParams {
     pub addresses: Vec<String>,
     pub limit: u32,
     pub encoding: String,
+
+    /// Cursor for paginated calls, set to the previous page's "endIndex" to
+    /// fetch the next one. "None" starts from the beginning.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_index: Option<super::EndIndex>,
 }
 
 /// ref. <https://docs.avax.network/apis/avalanchego/apis/x-chain/#avmgetutxos>
@@ -1,9 +1,219 @@
 //! EVM RPC client.
 use std::time::Duration;
 
-use crate::errors::{Error, Result};
+use crate::{
+    errors::{Error, Result},
+    evm::erc4337::UserOperation,
+};
+use ethers_core::{
+    abi::{Function, Param, ParamType, StateMutability, Token},
+    types::{
+        transaction::{eip2718::TypedTransaction, eip2930::AccessList},
+        BlockId, Bytes,
+    },
+};
 use ethers_providers::{Http, Middleware, Provider};
 use primitive_types::{H160, U256};
+use serde_json::{json, Value};
+
+/// The canonical, CREATE2-deployed Multicall3 contract address, identical
+/// across every EVM chain it has been deployed to (including Avalanche's
+/// C-chain and subnet-evm chains).
+/// ref. <https://github.com/mds1/multicall#deployments>
+pub const MULTICALL3_ADDRESS: &str = "0xcA11bde05977b3631167028862bE2a173976CA11";
+
+/// One call accumulated into a [`Multicall`] batch.
+struct MulticallCall {
+    target: H160,
+    allow_failure: bool,
+    func: Function,
+    args: Vec<Token>,
+}
+
+/// Batches multiple read-only contract calls into a single `eth_call` against
+/// the Multicall3 contract's `aggregate3`, trading N round-trips for one.
+///
+/// Each call declares its own `allow_failure`: when `true`, that call's
+/// `returnData` is decoded independently and a revert surfaces only as an
+/// `Err` in that call's slot, not a failure of the whole batch.
+/// ref. <https://github.com/mds1/multicall> "aggregate3"
+#[derive(Default)]
+pub struct Multicall {
+    calls: Vec<MulticallCall>,
+}
+
+impl Multicall {
+    pub fn new() -> Self {
+        Self { calls: Vec::new() }
+    }
+
+    /// Accumulates a call to `func` on `target`, to be issued as part of the
+    /// next [`Multicall::call`]. `func.outputs` is kept to decode that call's
+    /// slice of the batched response.
+    #[must_use]
+    pub fn add(
+        mut self,
+        target: H160,
+        func: Function,
+        args: Vec<Token>,
+        allow_failure: bool,
+    ) -> Self {
+        self.calls.push(MulticallCall {
+            target,
+            allow_failure,
+            func,
+            args,
+        });
+        self
+    }
+
+    /// The `aggregate3((address,bool,bytes)[]) returns ((bool,bytes)[])`
+    /// function descriptor, hand-built since Multicall3 ships no ABI JSON
+    /// artifact in this crate.
+    fn aggregate3_function() -> Function {
+        Function {
+            name: "aggregate3".to_string(),
+            inputs: vec![Param {
+                name: "calls".to_string(),
+                kind: ParamType::Array(Box::new(ParamType::Tuple(vec![
+                    ParamType::Address, // target
+                    ParamType::Bool,    // allowFailure
+                    ParamType::Bytes,   // callData
+                ]))),
+                internal_type: None,
+            }],
+            outputs: vec![Param {
+                name: "returnData".to_string(),
+                kind: ParamType::Array(Box::new(ParamType::Tuple(vec![
+                    ParamType::Bool,  // success
+                    ParamType::Bytes, // returnData
+                ]))),
+                internal_type: None,
+            }],
+            constant: None,
+            state_mutability: StateMutability::View,
+        }
+    }
+
+    /// Encodes the accumulated calls, issues a single `eth_call` against
+    /// [`MULTICALL3_ADDRESS`], and decodes each call's `returnData` against its
+    /// own declared outputs. The outer `Vec` preserves call order; a slot is
+    /// `Err` for a call whose `allow_failure` was set and that reverted, or
+    /// whose `returnData` does not decode against its declared outputs.
+    pub async fn call(self, rpc_ep: &str) -> Result<Vec<Result<Vec<Token>>>> {
+        let provider = Provider::<Http>::try_from(rpc_ep)
+            .map_err(|e| Error::API {
+                message: format!("failed to create provider '{}'", e),
+                retryable: false,
+            })?
+            .interval(Duration::from_millis(2000u64));
+
+        let mut call_tokens = Vec::with_capacity(self.calls.len());
+        for c in &self.calls {
+            let calldata = c.func.encode_input(&c.args).map_err(|e| Error::API {
+                message: format!("failed to encode_input for '{}': {}", c.func.name, e),
+                retryable: false,
+            })?;
+            call_tokens.push(Token::Tuple(vec![
+                Token::Address(c.target),
+                Token::Bool(c.allow_failure),
+                Token::Bytes(calldata),
+            ]));
+        }
+
+        let aggregate3 = Self::aggregate3_function();
+        let calldata = aggregate3
+            .encode_input(&[Token::Array(call_tokens)])
+            .map_err(|e| Error::API {
+                message: format!("failed to encode_input for 'aggregate3': {}", e),
+                retryable: false,
+            })?;
+
+        let multicall_addr: H160 = MULTICALL3_ADDRESS.parse().map_err(|e| Error::API {
+            message: format!("failed to parse Multicall3 address '{}'", e),
+            retryable: false,
+        })?;
+        let mut tx = TypedTransaction::default();
+        tx.set_to(multicall_addr);
+        tx.set_data(Bytes::from(calldata));
+
+        log::info!(
+            "calling aggregate3 with {} batched calls via {rpc_ep}",
+            self.calls.len()
+        );
+        let raw = provider.call(&tx, None).await.map_err(|e| Error::API {
+            message: format!("failed eth_call 'aggregate3' '{}'", e),
+            retryable: false,
+        })?;
+
+        let decoded = aggregate3.decode_output(&raw).map_err(|e| Error::API {
+            message: format!("failed to decode_output for 'aggregate3': {}", e),
+            retryable: false,
+        })?;
+        let results = match decoded.into_iter().next() {
+            Some(Token::Array(results)) => results,
+            _ => {
+                return Err(Error::API {
+                    message: "aggregate3 returned an unexpected token shape".to_string(),
+                    retryable: false,
+                })
+            }
+        };
+        if results.len() != self.calls.len() {
+            return Err(Error::API {
+                message: format!(
+                    "aggregate3 returned {} results for {} calls",
+                    results.len(),
+                    self.calls.len()
+                ),
+                retryable: false,
+            });
+        }
+
+        let mut out = Vec::with_capacity(results.len());
+        for (call, result) in self.calls.into_iter().zip(results.into_iter()) {
+            let (success, return_data) = match result {
+                Token::Tuple(mut fields) if fields.len() == 2 => {
+                    let return_data = fields.remove(1);
+                    let success = fields.remove(0);
+                    (success, return_data)
+                }
+                _ => {
+                    out.push(Err(Error::API {
+                        message: format!(
+                            "unexpected aggregate3 result shape for '{}'",
+                            call.func.name
+                        ),
+                        retryable: false,
+                    }));
+                    continue;
+                }
+            };
+            let success = matches!(success, Token::Bool(true));
+            let return_data = match return_data {
+                Token::Bytes(b) => b,
+                _ => Vec::new(),
+            };
+            if !success {
+                out.push(Err(Error::API {
+                    message: format!("call to '{}' reverted", call.func.name),
+                    retryable: false,
+                }));
+                continue;
+            }
+            out.push(
+                call.func
+                    .decode_output(&return_data)
+                    .map_err(|e| Error::API {
+                        message: format!("failed to decode_output for '{}': {}", call.func.name, e),
+                        retryable: false,
+                    }),
+            );
+        }
+
+        Ok(out)
+    }
+}
 
 /// Fetches the chain Id from "{http_rpc}/ext/bc/{chain_id_alias}/rpc".
 /// "chain_id_alias" is "C" for C-chain, and blockchain Id for subnet-evm.
@@ -49,3 +259,231 @@ pub async fn get_balance(rpc_ep: &str, eth_addr: H160) -> Result<U256> {
                 retryable: false,
             })
 }
+
+/// Submits an ERC-4337 `UserOperation` to a bundler RPC via
+/// `eth_sendUserOperation([userop, entry_point])` and returns the userOpHash
+/// reported by the bundler.
+///
+/// This mirrors the GSN forwarder flow but targets the EntryPoint contract
+/// through a standard bundler instead of a custom relay server.
+/// ref. <https://eips.ethereum.org/EIPS/eip-4337#rpc-methods-eth-namespace>
+pub async fn send_user_operation(
+    rpc_ep: &str,
+    user_op: &UserOperation,
+    entry_point: H160,
+) -> Result<String> {
+    let provider = Provider::<Http>::try_from(rpc_ep)
+        .map_err(|e| Error::API {
+            message: format!("failed to create provider '{}'", e),
+            retryable: false,
+        })?
+        .interval(Duration::from_millis(2000u64));
+
+    log::info!("sending user operation to bundler {rpc_ep}");
+    let op = user_operation_to_json(user_op);
+    provider
+        .request("eth_sendUserOperation", [op, json!(entry_point)])
+        .await
+        .map_err(|e| Error::API {
+            message: format!("failed eth_sendUserOperation '{}'", e),
+            retryable: false,
+        })
+}
+
+/// Calls `eth_createAccessList` to compute the EIP-2930 access list the node
+/// would warm for `tx`, returning the `AccessList` alongside the gas the node
+/// estimates the transaction uses with it applied. Callers attach the returned
+/// list to the outgoing transaction (see [`crate::evm::eip1559::Transaction::access_list`])
+/// to reduce gas via pre-warmed storage slots or to submit an EIP-2930 tx.
+/// ref. <https://eips.ethereum.org/EIPS/eip-2930>
+pub async fn create_access_list(
+    rpc_ep: &str,
+    tx: &TypedTransaction,
+    block: Option<BlockId>,
+) -> Result<(AccessList, U256)> {
+    let provider = Provider::<Http>::try_from(rpc_ep)
+        .map_err(|e| Error::API {
+            message: format!("failed to create provider '{}'", e),
+            retryable: false,
+        })?
+        .interval(Duration::from_millis(2000u64));
+
+    log::info!("creating access list via {rpc_ep}");
+    let res = provider
+        .create_access_list(tx, block)
+        .await
+        .map_err(|e| Error::API {
+            message: format!("failed eth_createAccessList '{}'", e),
+            retryable: false,
+        })?;
+    Ok((res.access_list, res.gas_used))
+}
+
+/// Default number of trailing blocks `estimate_eip1559_fees` pulls `eth_feeHistory`
+/// over when no explicit window is given.
+pub const DEFAULT_FEE_HISTORY_BLOCK_WINDOW: u64 = 10;
+
+/// Default reward percentile (median) `estimate_eip1559_fees` uses when no
+/// explicit percentile is given.
+pub const DEFAULT_FEE_HISTORY_REWARD_PERCENTILE: f64 = 50.0;
+
+/// Static priority-fee tip used as a fallback by `estimate_eip1559_fees` when
+/// `eth_feeHistory` returns no usable reward data (e.g. a very young chain).
+pub const DEFAULT_FALLBACK_MAX_PRIORITY_FEE_PER_GAS: u64 = 1_000_000_000; // 1 gwei
+
+/// Estimates EIP-1559 fee fields by querying `eth_feeHistory` over the last
+/// `block_window` blocks: the tip is the average of the `reward_percentile`-th
+/// percentile priority fee across the window (or
+/// [`DEFAULT_FALLBACK_MAX_PRIORITY_FEE_PER_GAS`] if the node returns no reward
+/// data at all), and `max_fee_per_gas` is the latest `baseFeePerGas` doubled
+/// plus that tip, to give headroom for the base fee to rise before inclusion.
+/// Callers apply the result via
+/// [`crate::evm::eip1559::Transaction::max_fee_per_gas`]/
+/// [`crate::evm::eip1559::Transaction::max_priority_fee_per_gas`].
+/// ref. <https://docs.alchemy.com/docs/eip-1559-how-it-works#fee-history-endpoint>
+pub async fn estimate_eip1559_fees(
+    rpc_ep: &str,
+    block_window: u64,
+    reward_percentile: f64,
+) -> Result<(U256, U256)> {
+    let provider = Provider::<Http>::try_from(rpc_ep)
+        .map_err(|e| Error::API {
+            message: format!("failed to create provider '{}'", e),
+            retryable: false,
+        })?
+        .interval(Duration::from_millis(2000u64));
+
+    log::info!("estimating eip-1559 fees over the last {block_window} block(s) via {rpc_ep}");
+    let history = provider
+        .fee_history(
+            U256::from(block_window),
+            ethers_core::types::BlockNumber::Latest,
+            &[reward_percentile],
+        )
+        .await
+        .map_err(|e| Error::API {
+            message: format!("failed eth_feeHistory '{}'", e),
+            retryable: true,
+        })?;
+
+    let base_fee = history.base_fee_per_gas.last().copied().unwrap_or_default();
+
+    let rewards: Vec<U256> = history
+        .reward
+        .iter()
+        .filter_map(|block_rewards| block_rewards.first().copied())
+        .collect();
+    let max_priority_fee_per_gas = if rewards.is_empty() {
+        log::warn!(
+            "eth_feeHistory returned no reward data via {rpc_ep}, \
+             falling back to a static max_priority_fee_per_gas"
+        );
+        U256::from(DEFAULT_FALLBACK_MAX_PRIORITY_FEE_PER_GAS)
+    } else {
+        rewards.iter().fold(U256::zero(), |acc, r| acc + r) / U256::from(rewards.len())
+    };
+
+    let max_fee_per_gas = base_fee * U256::from(2u64) + max_priority_fee_per_gas;
+    Ok((max_fee_per_gas, max_priority_fee_per_gas))
+}
+
+/// Polls `eth_getUserOperationReceipt` for the receipt of a submitted
+/// operation, returning `None` until the bundler has included it.
+pub async fn get_user_operation_receipt(rpc_ep: &str, user_op_hash: &str) -> Result<Option<Value>> {
+    let provider = Provider::<Http>::try_from(rpc_ep)
+        .map_err(|e| Error::API {
+            message: format!("failed to create provider '{}'", e),
+            retryable: false,
+        })?
+        .interval(Duration::from_millis(2000u64));
+
+    log::info!("getting user operation receipt for {user_op_hash} via {rpc_ep}");
+    provider
+        .request("eth_getUserOperationReceipt", [user_op_hash])
+        .await
+        .map_err(|e| Error::API {
+            message: format!("failed eth_getUserOperationReceipt '{}'", e),
+            retryable: true,
+        })
+}
+
+/// Encodes a `UserOperation` into the JSON object bundlers expect (camelCase
+/// keys, 0x-prefixed hex values).
+fn user_operation_to_json(op: &UserOperation) -> Value {
+    json!({
+        "sender": op.sender,
+        "nonce": op.nonce,
+        "initCode": op.init_code,
+        "callData": op.call_data,
+        "callGasLimit": op.call_gas_limit,
+        "verificationGasLimit": op.verification_gas_limit,
+        "preVerificationGas": op.pre_verification_gas,
+        "maxFeePerGas": op.max_fee_per_gas,
+        "maxPriorityFeePerGas": op.max_priority_fee_per_gas,
+        "paymasterAndData": op.paymaster_and_data,
+        "signature": op.signature,
+    })
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib --features="evm" -- jsonrpc::client::evm::test_multicall_aggregate3_encoding --exact --show-output
+#[test]
+fn test_multicall_aggregate3_encoding() {
+    use std::str::FromStr;
+
+    let _ = env_logger::builder()
+        .filter_level(log::LevelFilter::Debug)
+        .is_test(true)
+        .try_init();
+
+    // parsed function of "balanceOf(address) returns (uint256)"
+    let balance_of = Function {
+        name: "balanceOf".to_string(),
+        inputs: vec![Param {
+            name: "account".to_string(),
+            kind: ParamType::Address,
+            internal_type: None,
+        }],
+        outputs: vec![Param {
+            name: "".to_string(),
+            kind: ParamType::Uint(256),
+            internal_type: None,
+        }],
+        constant: None,
+        state_mutability: StateMutability::View,
+    };
+    let target = H160::from_str("0x8db97c7cece249c2b98bdc0226cc4c2a57bf52fc").unwrap();
+    let args = vec![Token::Address(H160::random())];
+
+    let mc = Multicall::new().add(target, balance_of.clone(), args.clone(), true);
+    assert_eq!(mc.calls.len(), 1);
+
+    // the batch wraps each call's calldata into the (address,bool,bytes)[] tuple
+    // array "aggregate3" expects.
+    let calldata = balance_of.encode_input(&args).unwrap();
+    let aggregate3 = Multicall::aggregate3_function();
+    let agg_calldata = aggregate3
+        .encode_input(&[Token::Array(vec![Token::Tuple(vec![
+            Token::Address(target),
+            Token::Bool(true),
+            Token::Bytes(calldata),
+        ])])])
+        .unwrap();
+    log::info!("aggregate3 calldata: 0x{}", hex::encode(&agg_calldata));
+
+    // a fabricated single-success response decodes back into the "(bool,bytes)[]"
+    // shape that "Multicall::call" unpacks per-entry.
+    let mut encoded_balance = [0u8; 32];
+    encoded_balance[31] = 42;
+    let response = ethers_core::abi::encode(&[Token::Array(vec![Token::Tuple(vec![
+        Token::Bool(true),
+        Token::Bytes(encoded_balance.to_vec()),
+    ])])]);
+    let decoded = aggregate3.decode_output(&response).unwrap();
+    assert_eq!(
+        decoded,
+        vec![Token::Array(vec![Token::Tuple(vec![
+            Token::Bool(true),
+            Token::Bytes(encoded_balance.to_vec()),
+        ])])]
+    );
+}
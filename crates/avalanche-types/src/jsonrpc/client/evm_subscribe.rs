@@ -0,0 +1,123 @@
+//! WebSocket pub/sub subscriptions for the EVM JSON-RPC client.
+//!
+//! [`crate::jsonrpc::client::evm`] is HTTP request/response only, so
+//! confirming a submitted transaction or watching contract events means
+//! polling. This module opens an [`ethers_providers::Ws`] connection --
+//! which already demultiplexes incoming `eth_subscription` notifications by
+//! their `params.subscription` id internally, the same job a hand-rolled
+//! reader task would otherwise have to do -- and wraps each of the three
+//! standard subscription kinds (`newHeads`, `logs`, `newPendingTransactions`)
+//! in the same spawn-a-background-task-plus-[`mpsc::Receiver`] shape as
+//! [`crate::jsonrpc::client::subscription`]. Callers get a typed,
+//! event-driven stream instead of a busy-poll loop; the EVM wallet's
+//! `.check_acceptance`/`.check_receipt` flows do not use it and still poll.
+
+use crate::errors::{Error, Result};
+use ethers_core::types::{Block, Filter, Log, TxHash, H256};
+use ethers_providers::{Middleware, Provider, Ws};
+use futures::StreamExt;
+use tokio::{sync::mpsc, task::JoinHandle};
+
+/// Default buffer size for the channels returned by the `subscribe_*` functions.
+pub const DEFAULT_CHANNEL_BUFFER: usize = 64;
+
+/// Subscribes to `newHeads`, forwarding each new block header as it arrives.
+pub async fn subscribe_new_heads(
+    ws_ep: &str,
+) -> Result<(mpsc::Receiver<Result<Block<H256>>>, JoinHandle<()>)> {
+    let provider = connect(ws_ep).await?;
+    let (tx, rx) = mpsc::channel(DEFAULT_CHANNEL_BUFFER);
+
+    let handle = tokio::spawn(async move {
+        let mut stream = match provider.subscribe_blocks().await {
+            Ok(s) => s,
+            Err(e) => {
+                let _ = tx
+                    .send(Err(Error::API {
+                        message: format!("failed eth_subscribe 'newHeads' '{}'", e),
+                        retryable: true,
+                    }))
+                    .await;
+                return;
+            }
+        };
+        while let Some(block) = stream.next().await {
+            if tx.send(Ok(block)).await.is_err() {
+                break; // receiver dropped
+            }
+        }
+    });
+    Ok((rx, handle))
+}
+
+/// Subscribes to `logs` matching `filter` (address + topics), forwarding each
+/// matching log as it arrives.
+pub async fn subscribe_logs(
+    ws_ep: &str,
+    filter: Filter,
+) -> Result<(mpsc::Receiver<Result<Log>>, JoinHandle<()>)> {
+    let provider = connect(ws_ep).await?;
+    let (tx, rx) = mpsc::channel(DEFAULT_CHANNEL_BUFFER);
+
+    let handle = tokio::spawn(async move {
+        let mut stream = match provider.subscribe_logs(&filter).await {
+            Ok(s) => s,
+            Err(e) => {
+                let _ = tx
+                    .send(Err(Error::API {
+                        message: format!("failed eth_subscribe 'logs' '{}'", e),
+                        retryable: true,
+                    }))
+                    .await;
+                return;
+            }
+        };
+        while let Some(log) = stream.next().await {
+            if tx.send(Ok(log)).await.is_err() {
+                break;
+            }
+        }
+    });
+    Ok((rx, handle))
+}
+
+/// Subscribes to `newPendingTransactions`, forwarding each pending tx hash as
+/// it enters the mempool.
+pub async fn subscribe_pending_transactions(
+    ws_ep: &str,
+) -> Result<(mpsc::Receiver<Result<TxHash>>, JoinHandle<()>)> {
+    let provider = connect(ws_ep).await?;
+    let (tx, rx) = mpsc::channel(DEFAULT_CHANNEL_BUFFER);
+
+    let handle = tokio::spawn(async move {
+        let mut stream = match provider.subscribe_pending_txs().await {
+            Ok(s) => s,
+            Err(e) => {
+                let _ = tx
+                    .send(Err(Error::API {
+                        message: format!("failed eth_subscribe 'newPendingTransactions' '{}'", e),
+                        retryable: true,
+                    }))
+                    .await;
+                return;
+            }
+        };
+        while let Some(tx_hash) = stream.next().await {
+            if tx.send(Ok(tx_hash)).await.is_err() {
+                break;
+            }
+        }
+    });
+    Ok((rx, handle))
+}
+
+/// Opens the WebSocket connection shared by the `subscribe_*` functions.
+async fn connect(ws_ep: &str) -> Result<Provider<Ws>> {
+    log::info!("connecting to {ws_ep} for pub/sub subscriptions");
+    Provider::<Ws>::connect(ws_ep)
+        .await
+        .map_err(|e| Error::API {
+            message: format!("failed to connect to '{}': {}", ws_ep, e),
+            retryable: true,
+        })
+}
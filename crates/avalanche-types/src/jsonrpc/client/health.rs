@@ -7,12 +7,50 @@ use crate::{
     jsonrpc::health,
     utils::urls::extract_scheme_host_port_path_chain_alias,
 };
+use lazy_static::lazy_static;
+use prometheus::{register_histogram, register_int_counter_vec, HistogramVec, IntCounterVec};
 use reqwest::ClientBuilder;
 
+lazy_static! {
+    /// Count of health probe outcomes, labeled by probe kind ("liveness"/"readiness")
+    /// and result ("ok"/"error").
+    static ref HEALTH_CHECKS: prometheus::Result<IntCounterVec> = register_int_counter_vec!(
+        "health_check_probes",
+        "Count of health probe outcomes by probe kind and result",
+        &["probe", "result"]
+    );
+
+    /// Latency of health probe requests, labeled by probe kind.
+    static ref HEALTH_CHECK_LATENCY: prometheus::Result<HistogramVec> = register_histogram!(
+        "health_check_latency_seconds",
+        "Latency of health probe requests in seconds",
+        &["probe"]
+    );
+}
+
+/// Records a probe outcome and its latency against the Prometheus metrics.
+fn observe_probe(liveness: bool, ok: bool, elapsed: Duration) {
+    let probe = if liveness { "liveness" } else { "readiness" };
+    let result = if ok { "ok" } else { "error" };
+    if let Ok(c) = HEALTH_CHECKS.as_ref() {
+        c.with_label_values(&[probe, result]).inc();
+    }
+    if let Ok(h) = HEALTH_CHECK_LATENCY.as_ref() {
+        h.with_label_values(&[probe]).observe(elapsed.as_secs_f64());
+    }
+}
+
 /// "If a single piece of data must be accessible from more than one task
 /// concurrently, then it must be shared using synchronization primitives such as Arc."
 /// ref. <https://tokio.rs/tokio/tutorial/spawning>
 pub async fn check(http_rpc: Arc<String>, liveness: bool) -> Result<health::Response> {
+    let start = std::time::Instant::now();
+    let res = check_inner(http_rpc, liveness).await;
+    observe_probe(liveness, res.is_ok(), start.elapsed());
+    res
+}
+
+async fn check_inner(http_rpc: Arc<String>, liveness: bool) -> Result<health::Response> {
     let (scheme, host, port, _, _) =
         extract_scheme_host_port_path_chain_alias(&http_rpc).map_err(|e| Error::Other {
             message: format!("failed extract_scheme_host_port_path_chain_alias '{}'", e),
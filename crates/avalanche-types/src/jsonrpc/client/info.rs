@@ -4,11 +4,48 @@ use std::{collections::HashMap, time::Duration};
 use crate::{
     errors::{Error, Result},
     ids,
-    jsonrpc::client::url,
+    jsonrpc::client::{provider as client_provider, url},
     jsonrpc::{self, info},
     utils,
 };
 use reqwest::{header::CONTENT_TYPE, ClientBuilder};
+use serde::de::DeserializeOwned;
+
+/// Default maximum response-body size (in bytes) accepted from an Info API
+/// endpoint before the read is aborted with a non-retryable error. This guards
+/// against an unbounded allocation if a misbehaving endpoint streams a huge
+/// payload (e.g. a very large `info.peers` response on a big network).
+pub const DEFAULT_MAX_RESPONSE_BYTES: usize = 50 * 1024 * 1024;
+
+/// Reads the full response body into memory while enforcing `max_bytes`, so a
+/// misbehaving endpoint cannot trigger an unbounded allocation. Transparent
+/// gzip/brotli decompression is handled by the underlying `reqwest::Client`;
+/// the bound here applies to the decompressed stream as it is consumed.
+async fn read_bounded_body(mut resp: reqwest::Response, max_bytes: usize) -> Result<Vec<u8>> {
+    // reject up front when the server advertises an oversized body
+    if let Some(len) = resp.content_length() {
+        if len as usize > max_bytes {
+            return Err(Error::Other {
+                message: format!("response body length {len} exceeds max {max_bytes} bytes"),
+                retryable: false,
+            });
+        }
+    }
+    let mut out: Vec<u8> = Vec::new();
+    while let Some(chunk) = resp.chunk().await.map_err(|e| Error::Other {
+        message: format!("failed reqwest response chunk '{}'", e),
+        retryable: false,
+    })? {
+        if out.len() + chunk.len() > max_bytes {
+            return Err(Error::Other {
+                message: format!("response body exceeds max {max_bytes} bytes"),
+                retryable: false,
+            });
+        }
+        out.extend_from_slice(&chunk);
+    }
+    Ok(out)
+}
 
 /// e.g., "info.getNetworkName".
 /// ref. <https://docs.avax.network/build/avalanchego-apis/info/#infogetnetworkname>
@@ -35,6 +72,8 @@ pub async fn get_network_name(http_rpc: &str) -> Result<info::GetNetworkNameResp
         .danger_accept_invalid_certs(true)
         .timeout(Duration::from_secs(15))
         .connection_verbose(true)
+        .gzip(true)
+        .brotli(true)
         .build()
         .map_err(|e| {
             // TODO: check retryable
@@ -55,14 +94,7 @@ pub async fn get_network_name(http_rpc: &str) -> Result<info::GetNetworkNameResp
                 message: format!("failed reqwest::Client.send '{}'", e),
                 retryable: false,
             })?;
-    let out = resp.bytes().await.map_err(|e| {
-        // TODO: check retryable
-        Error::Other {
-            message: format!("failed reqwest response bytes '{}'", e),
-            retryable: false,
-        }
-    })?;
-    let out: Vec<u8> = out.into();
+    let out = read_bounded_body(resp, DEFAULT_MAX_RESPONSE_BYTES).await?;
 
     serde_json::from_slice(&out).map_err(|e| Error::Other {
         message: format!("failed serde_json::from_slice '{}'", e),
@@ -95,6 +127,8 @@ pub async fn get_network_id(http_rpc: &str) -> Result<info::GetNetworkIdResponse
         .danger_accept_invalid_certs(true)
         .timeout(Duration::from_secs(15))
         .connection_verbose(true)
+        .gzip(true)
+        .brotli(true)
         .build()
         .map_err(|e| {
             // TODO: check retryable
@@ -115,14 +149,7 @@ pub async fn get_network_id(http_rpc: &str) -> Result<info::GetNetworkIdResponse
                 message: format!("failed reqwest::Client.send '{}'", e),
                 retryable: false,
             })?;
-    let out = resp.bytes().await.map_err(|e| {
-        // TODO: check retryable
-        Error::Other {
-            message: format!("failed reqwest response bytes '{}'", e),
-            retryable: false,
-        }
-    })?;
-    let out: Vec<u8> = out.into();
+    let out = read_bounded_body(resp, DEFAULT_MAX_RESPONSE_BYTES).await?;
 
     serde_json::from_slice(&out).map_err(|e| Error::Other {
         message: format!("failed serde_json::from_slice '{}'", e),
@@ -162,6 +189,8 @@ pub async fn get_blockchain_id(
         .danger_accept_invalid_certs(true)
         .timeout(Duration::from_secs(15))
         .connection_verbose(true)
+        .gzip(true)
+        .brotli(true)
         .build()
         .map_err(|e| {
             // TODO: check retryable
@@ -182,14 +211,7 @@ pub async fn get_blockchain_id(
                 message: format!("failed reqwest::Client.send '{}'", e),
                 retryable: false,
             })?;
-    let out = resp.bytes().await.map_err(|e| {
-        // TODO: check retryable
-        Error::Other {
-            message: format!("failed reqwest response bytes '{}'", e),
-            retryable: false,
-        }
-    })?;
-    let out: Vec<u8> = out.into();
+    let out = read_bounded_body(resp, DEFAULT_MAX_RESPONSE_BYTES).await?;
 
     serde_json::from_slice(&out).map_err(|e| Error::Other {
         message: format!("failed serde_json::from_slice '{}'", e),
@@ -221,6 +243,8 @@ pub async fn get_node_id(http_rpc: &str) -> Result<info::GetNodeIdResponse> {
         .danger_accept_invalid_certs(true)
         .timeout(Duration::from_secs(15))
         .connection_verbose(true)
+        .gzip(true)
+        .brotli(true)
         .build()
         .map_err(|e| {
             // TODO: check retryable
@@ -241,49 +265,42 @@ pub async fn get_node_id(http_rpc: &str) -> Result<info::GetNodeIdResponse> {
                 message: format!("failed reqwest::Client.send '{}'", e),
                 retryable: false,
             })?;
-    let out = resp.bytes().await.map_err(|e| {
-        // TODO: check retryable
-        Error::Other {
-            message: format!("failed reqwest response bytes '{}'", e),
-            retryable: false,
-        }
-    })?;
-    let out: Vec<u8> = out.into();
+    let out = read_bounded_body(resp, DEFAULT_MAX_RESPONSE_BYTES).await?;
 
     let resp: info::GetNodeIdResponse = serde_json::from_slice(&out).map_err(|e| Error::Other {
         message: format!("failed serde_json::from_slice '{}'", e),
         retryable: false,
     })?;
+    decode_node_id_pubkey(resp)
+}
 
-    if let Some(res) = &resp.result {
-        if let Some(pop) = &res.node_pop {
-            let pubkey = pop.load_pubkey().map_err(|e| Error::Other {
-                message: format!("failed pop.load_pubkey '{}'", e),
-                retryable: false,
-            })?;
+/// Loads the BLS public key embedded in `resp.result.node_pop` in place;
+/// shared by [`get_node_id`] and [`get_all_info`] so both paths decode a
+/// `GetNodeIdResponse` the same way.
+fn decode_node_id_pubkey(resp: info::GetNodeIdResponse) -> Result<info::GetNodeIdResponse> {
+    let res = resp.result.as_ref().ok_or_else(|| Error::Other {
+        message: "no result found".to_string(),
+        retryable: false,
+    })?;
+    let pop = res.node_pop.as_ref().ok_or_else(|| Error::Other {
+        message: "no result.node_pop found".to_string(),
+        retryable: false,
+    })?;
 
-            let mut cloned_pop = pop.clone();
-            cloned_pop.pubkey = Some(pubkey);
+    let pubkey = pop.load_pubkey().map_err(|e| Error::Other {
+        message: format!("failed pop.load_pubkey '{}'", e),
+        retryable: false,
+    })?;
 
-            let mut cloned_result = res.clone();
-            cloned_result.node_pop = Some(cloned_pop);
+    let mut cloned_pop = pop.clone();
+    cloned_pop.pubkey = Some(pubkey);
 
-            let mut cloned_resp = resp.clone();
-            cloned_resp.result = Some(cloned_result);
+    let mut cloned_result = res.clone();
+    cloned_result.node_pop = Some(cloned_pop);
 
-            Ok(cloned_resp)
-        } else {
-            return Err(Error::Other {
-                message: "no result.node_pop found".to_string(),
-                retryable: false,
-            });
-        }
-    } else {
-        return Err(Error::Other {
-            message: "no result found".to_string(),
-            retryable: false,
-        });
-    }
+    let mut cloned_resp = resp;
+    cloned_resp.result = Some(cloned_result);
+    Ok(cloned_resp)
 }
 
 /// e.g., "info.getNodeVersion".
@@ -311,6 +328,8 @@ pub async fn get_node_version(http_rpc: &str) -> Result<info::GetNodeVersionResp
         .danger_accept_invalid_certs(true)
         .timeout(Duration::from_secs(15))
         .connection_verbose(true)
+        .gzip(true)
+        .brotli(true)
         .build()
         .map_err(|e| {
             // TODO: check retryable
@@ -331,14 +350,7 @@ pub async fn get_node_version(http_rpc: &str) -> Result<info::GetNodeVersionResp
                 message: format!("failed reqwest::Client.send '{}'", e),
                 retryable: false,
             })?;
-    let out = resp.bytes().await.map_err(|e| {
-        // TODO: check retryable
-        Error::Other {
-            message: format!("failed reqwest response bytes '{}'", e),
-            retryable: false,
-        }
-    })?;
-    let out: Vec<u8> = out.into();
+    let out = read_bounded_body(resp, DEFAULT_MAX_RESPONSE_BYTES).await?;
 
     serde_json::from_slice(&out).map_err(|e| Error::Other {
         message: format!("failed serde_json::from_slice '{}'", e),
@@ -371,6 +383,8 @@ pub async fn get_vms(http_rpc: &str) -> Result<info::GetVmsResponse> {
         .danger_accept_invalid_certs(true)
         .timeout(Duration::from_secs(15))
         .connection_verbose(true)
+        .gzip(true)
+        .brotli(true)
         .build()
         .map_err(|e| {
             // TODO: check retryable
@@ -391,14 +405,7 @@ pub async fn get_vms(http_rpc: &str) -> Result<info::GetVmsResponse> {
                 message: format!("failed reqwest::Client.send '{}'", e),
                 retryable: false,
             })?;
-    let out = resp.bytes().await.map_err(|e| {
-        // TODO: check retryable
-        Error::Other {
-            message: format!("failed reqwest response bytes '{}'", e),
-            retryable: false,
-        }
-    })?;
-    let out: Vec<u8> = out.into();
+    let out = read_bounded_body(resp, DEFAULT_MAX_RESPONSE_BYTES).await?;
 
     serde_json::from_slice(&out).map_err(|e| Error::Other {
         message: format!("failed serde_json::from_slice '{}'", e),
@@ -431,6 +438,8 @@ pub async fn is_bootstrapped(http_rpc: &str) -> Result<info::IsBootstrappedRespo
         .danger_accept_invalid_certs(true)
         .timeout(Duration::from_secs(15))
         .connection_verbose(true)
+        .gzip(true)
+        .brotli(true)
         .build()
         .map_err(|e| {
             // TODO: check retryable
@@ -451,14 +460,7 @@ pub async fn is_bootstrapped(http_rpc: &str) -> Result<info::IsBootstrappedRespo
                 message: format!("failed reqwest::Client.send '{}'", e),
                 retryable: false,
             })?;
-    let out = resp.bytes().await.map_err(|e| {
-        // TODO: check retryable
-        Error::Other {
-            message: format!("failed reqwest response bytes '{}'", e),
-            retryable: false,
-        }
-    })?;
-    let out: Vec<u8> = out.into();
+    let out = read_bounded_body(resp, DEFAULT_MAX_RESPONSE_BYTES).await?;
 
     serde_json::from_slice(&out).map_err(|e| Error::Other {
         message: format!("failed serde_json::from_slice '{}'", e),
@@ -493,6 +495,8 @@ pub async fn get_tx_fee(http_rpc: &str) -> Result<info::GetTxFeeResponse> {
         .danger_accept_invalid_certs(true)
         .timeout(Duration::from_secs(15))
         .connection_verbose(true)
+        .gzip(true)
+        .brotli(true)
         .build()
         .map_err(|e| {
             // TODO: check retryable
@@ -513,14 +517,7 @@ pub async fn get_tx_fee(http_rpc: &str) -> Result<info::GetTxFeeResponse> {
                 message: format!("failed reqwest::Client.send '{}'", e),
                 retryable: false,
             })?;
-    let out = resp.bytes().await.map_err(|e| {
-        // TODO: check retryable
-        Error::Other {
-            message: format!("failed reqwest response bytes '{}'", e),
-            retryable: false,
-        }
-    })?;
-    let out: Vec<u8> = out.into();
+    let out = read_bounded_body(resp, DEFAULT_MAX_RESPONSE_BYTES).await?;
 
     serde_json::from_slice(&out).map_err(|e| Error::Other {
         message: format!("failed serde_json::from_slice '{}'", e),
@@ -565,6 +562,8 @@ pub async fn peers(
         .danger_accept_invalid_certs(true)
         .timeout(Duration::from_secs(15))
         .connection_verbose(true)
+        .gzip(true)
+        .brotli(true)
         .build()
         .map_err(|e| {
             // TODO: check retryable
@@ -585,17 +584,306 @@ pub async fn peers(
                 message: format!("failed reqwest::Client.send '{}'", e),
                 retryable: false,
             })?;
-    let out = resp.bytes().await.map_err(|e| {
-        // TODO: check retryable
-        Error::Other {
-            message: format!("failed reqwest response bytes '{}'", e),
-            retryable: false,
-        }
-    })?;
-    let out: Vec<u8> = out.into();
+    let out = read_bounded_body(resp, DEFAULT_MAX_RESPONSE_BYTES).await?;
 
     serde_json::from_slice(&out).map_err(|e| Error::Other {
         message: format!("failed serde_json::from_slice '{}'", e),
         retryable: false,
     })
 }
+
+/// Everything [`get_all_info`] gathers in one batched request: network
+/// name/Id, the X/P/C-chain blockchain Ids, this node's Id, and its current
+/// peers.
+#[derive(Debug, Clone)]
+pub struct AllInfo {
+    pub network_name: info::GetNetworkNameResponse,
+    pub network_id: info::GetNetworkIdResponse,
+    pub x_chain_id: info::GetBlockchainIdResponse,
+    pub p_chain_id: info::GetBlockchainIdResponse,
+    pub c_chain_id: info::GetBlockchainIdResponse,
+    pub node_id: info::GetNodeIdResponse,
+    pub peers: info::PeersResponse,
+}
+
+/// One call packed into a JSON-RPC 2.0 batch request body; "id" must be
+/// unique within the batch so the matching response element (required by the
+/// spec to echo the same "id") can be correlated back to it.
+struct BatchCall {
+    id: u32,
+    method: &'static str,
+    params: Option<serde_json::Value>,
+}
+
+impl BatchCall {
+    fn to_value(&self) -> serde_json::Value {
+        let mut v = serde_json::json!({
+            "jsonrpc": jsonrpc::DEFAULT_VERSION,
+            "id": self.id,
+            "method": self.method,
+        });
+        if let Some(params) = &self.params {
+            v["params"] = params.clone();
+        }
+        v
+    }
+}
+
+/// Packs "calls" into a single JSON-RPC 2.0 batch request (one HTTP POST
+/// carrying a JSON array body, per the batch section of the spec) against the
+/// Info API at "http_rpc", and returns each call's raw response value keyed
+/// by the "id" it was sent with.
+async fn post_batch(
+    http_rpc: &str,
+    calls: &[BatchCall],
+) -> Result<HashMap<u32, serde_json::Value>> {
+    let (scheme, host, port, _, _) =
+        utils::urls::extract_scheme_host_port_path_chain_alias(http_rpc).map_err(|e| {
+            Error::Other {
+                message: format!("failed extract_scheme_host_port_path_chain_alias '{}'", e),
+                retryable: false,
+            }
+        })?;
+    let url = url::try_create_url(url::Path::Info, scheme.as_deref(), host.as_str(), port)?;
+    log::info!("sending batch of {} info call(s) to {url}", calls.len());
+
+    let body = serde_json::to_string(&calls.iter().map(BatchCall::to_value).collect::<Vec<_>>())
+        .map_err(|e| Error::Other {
+            message: format!("failed to serialize JSON-RPC batch '{}'", e),
+            retryable: false,
+        })?;
+
+    let req_cli_builder = ClientBuilder::new()
+        .user_agent(env!("CARGO_PKG_NAME"))
+        .danger_accept_invalid_certs(true)
+        .timeout(Duration::from_secs(15))
+        .connection_verbose(true)
+        .gzip(true)
+        .brotli(true)
+        .build()
+        .map_err(|e| {
+            // TODO: check retryable
+            Error::Other {
+                message: format!("failed reqwest::ClientBuilder.build '{}'", e),
+                retryable: false,
+            }
+        })?;
+    let resp = req_cli_builder
+        .post(url.to_string())
+        .header(CONTENT_TYPE, "application/json")
+        .body(body)
+        .send()
+        .await
+        .map_err(|e| Error::API {
+            message: format!("failed reqwest::Client.send '{}'", e),
+            retryable: e.is_timeout() || e.is_connect(),
+        })?;
+    if resp.status().is_server_error() {
+        return Err(Error::API {
+            message: format!("info batch endpoint returned {}", resp.status()),
+            retryable: true,
+        });
+    }
+    let out = read_bounded_body(resp, DEFAULT_MAX_RESPONSE_BYTES).await?;
+
+    let values: Vec<serde_json::Value> =
+        serde_json::from_slice(&out).map_err(|e| Error::Other {
+            message: format!("failed serde_json::from_slice of batch response '{}'", e),
+            retryable: false,
+        })?;
+
+    let mut by_id = HashMap::with_capacity(values.len());
+    for v in values {
+        let id = v
+            .get("id")
+            .and_then(serde_json::Value::as_u64)
+            .ok_or_else(|| Error::Other {
+                message: "batch response element missing numeric \"id\"".to_string(),
+                retryable: false,
+            })? as u32;
+        by_id.insert(id, v);
+    }
+    Ok(by_id)
+}
+
+/// Removes and decodes the response for "id" out of a batch decoded by
+/// [`post_batch`], failing with "method" in the error message if it's
+/// missing or doesn't decode as "T".
+fn take_response<T: DeserializeOwned>(
+    by_id: &mut HashMap<u32, serde_json::Value>,
+    id: u32,
+    method: &str,
+) -> Result<T> {
+    let v = by_id.remove(&id).ok_or_else(|| Error::Other {
+        message: format!("batch response missing result for '{method}' (id {id})"),
+        retryable: false,
+    })?;
+    serde_json::from_value(v).map_err(|e| Error::Other {
+        message: format!("failed to decode '{method}' batch response '{}'", e),
+        retryable: false,
+    })
+}
+
+/// Fetches network name/Id, the X/P/C-chain blockchain Ids, this node's Id,
+/// and its current peers in a single batched JSON-RPC 2.0 HTTP request,
+/// instead of one round trip per method (see the `jsonrpc_client_info`
+/// example for the non-batched equivalent).
+pub async fn get_all_info(http_rpc: &str) -> Result<AllInfo> {
+    let calls = [
+        BatchCall {
+            id: 1,
+            method: "info.getNetworkName",
+            params: None,
+        },
+        BatchCall {
+            id: 2,
+            method: "info.getNetworkID",
+            params: None,
+        },
+        BatchCall {
+            id: 3,
+            method: "info.getBlockchainID",
+            params: Some(serde_json::json!({"alias": "X"})),
+        },
+        BatchCall {
+            id: 4,
+            method: "info.getBlockchainID",
+            params: Some(serde_json::json!({"alias": "P"})),
+        },
+        BatchCall {
+            id: 5,
+            method: "info.getBlockchainID",
+            params: Some(serde_json::json!({"alias": "C"})),
+        },
+        BatchCall {
+            id: 6,
+            method: "info.getNodeID",
+            params: None,
+        },
+        BatchCall {
+            id: 7,
+            method: "info.peers",
+            params: Some(serde_json::json!({"nodeIDs": Vec::<String>::new()})),
+        },
+    ];
+
+    let mut by_id = post_batch(http_rpc, &calls).await?;
+
+    let network_name = take_response(&mut by_id, 1, "info.getNetworkName")?;
+    let network_id = take_response(&mut by_id, 2, "info.getNetworkID")?;
+    let x_chain_id = take_response(&mut by_id, 3, "info.getBlockchainID(X)")?;
+    let p_chain_id = take_response(&mut by_id, 4, "info.getBlockchainID(P)")?;
+    let c_chain_id = take_response(&mut by_id, 5, "info.getBlockchainID(C)")?;
+    let node_id = decode_node_id_pubkey(take_response(&mut by_id, 6, "info.getNodeID")?)?;
+    let peers = take_response(&mut by_id, 7, "info.peers")?;
+
+    Ok(AllInfo {
+        network_name,
+        network_id,
+        x_chain_id,
+        p_chain_id,
+        c_chain_id,
+        node_id,
+        peers,
+    })
+}
+
+/// A high-level client that binds an HTTP RPC endpoint and exposes every
+/// `info` method as a method call, so callers do not repeat the endpoint on
+/// each request.
+#[derive(Clone, Debug)]
+pub struct InfoClient {
+    /// The `info` API HTTP RPC endpoint (e.g. `http://127.0.0.1:9650`).
+    pub http_rpc: String,
+
+    /// Optional retry policy wrapping [`InfoClient::get_all_info`], so
+    /// transient rate-limit/transport errors are retried instead of failing
+    /// the whole batch. `None` makes a single attempt.
+    pub retry_policy: Option<client_provider::RetryPolicy>,
+}
+
+impl InfoClient {
+    /// Creates a new client bound to the given HTTP RPC endpoint.
+    #[must_use]
+    pub fn new(http_rpc: &str) -> Self {
+        Self {
+            http_rpc: http_rpc.to_string(),
+            retry_policy: None,
+        }
+    }
+
+    /// Sets the retry policy wrapping [`InfoClient::get_all_info`].
+    #[must_use]
+    pub fn retry_policy(mut self, retry_policy: client_provider::RetryPolicy) -> Self {
+        self.retry_policy = Some(retry_policy);
+        self
+    }
+
+    /// Runs `f` once, or through `self.retry_policy` when one is set.
+    async fn with_retry<F, Fut, R>(&self, f: F) -> Result<R>
+    where
+        F: FnMut() -> Fut,
+        Fut: std::future::Future<Output = Result<R>>,
+    {
+        match &self.retry_policy {
+            Some(policy) => policy.retry(f).await,
+            None => {
+                let mut f = f;
+                f().await
+            }
+        }
+    }
+
+    /// See [`get_all_info`], retried per [`Self::retry_policy`].
+    pub async fn get_all_info(&self) -> Result<AllInfo> {
+        self.with_retry(|| get_all_info(&self.http_rpc)).await
+    }
+
+    /// See [`get_network_name`].
+    pub async fn get_network_name(&self) -> Result<info::GetNetworkNameResponse> {
+        get_network_name(&self.http_rpc).await
+    }
+
+    /// See [`get_network_id`].
+    pub async fn get_network_id(&self) -> Result<info::GetNetworkIdResponse> {
+        get_network_id(&self.http_rpc).await
+    }
+
+    /// See [`get_blockchain_id`].
+    pub async fn get_blockchain_id(
+        &self,
+        chain_alias: &str,
+    ) -> Result<info::GetBlockchainIdResponse> {
+        get_blockchain_id(&self.http_rpc, chain_alias).await
+    }
+
+    /// See [`get_node_id`].
+    pub async fn get_node_id(&self) -> Result<info::GetNodeIdResponse> {
+        get_node_id(&self.http_rpc).await
+    }
+
+    /// See [`get_node_version`].
+    pub async fn get_node_version(&self) -> Result<info::GetNodeVersionResponse> {
+        get_node_version(&self.http_rpc).await
+    }
+
+    /// See [`get_vms`].
+    pub async fn get_vms(&self) -> Result<info::GetVmsResponse> {
+        get_vms(&self.http_rpc).await
+    }
+
+    /// See [`is_bootstrapped`].
+    pub async fn is_bootstrapped(&self) -> Result<info::IsBootstrappedResponse> {
+        is_bootstrapped(&self.http_rpc).await
+    }
+
+    /// See [`get_tx_fee`].
+    pub async fn get_tx_fee(&self) -> Result<info::GetTxFeeResponse> {
+        get_tx_fee(&self.http_rpc).await
+    }
+
+    /// See [`peers`].
+    pub async fn peers(&self, node_ids: Option<Vec<ids::node::Id>>) -> Result<info::PeersResponse> {
+        peers(&self.http_rpc, node_ids).await
+    }
+}
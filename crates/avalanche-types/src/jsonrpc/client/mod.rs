@@ -2,8 +2,11 @@
 
 pub mod admin;
 pub mod evm;
+pub mod evm_subscribe;
 pub mod health;
 pub mod info;
 pub mod p;
+pub mod provider;
+pub mod subscription;
 pub mod url;
 pub mod x;
@@ -0,0 +1,335 @@
+//! Resilient multi-endpoint provider layer for the JSON-RPC clients.
+//!
+//! The wallet and the P-chain/C-chain polling loops historically fire every
+//! request at a single picked URL, so a flaky or rate-limited node surfaces as
+//! a hard failure even when several RPCs are configured. This module adds two
+//! composable primitives over a set of endpoints:
+//!
+//! * [`RetryPolicy`] -- this crate's equivalent of ethers'
+//!   `HttpRateLimitRetryPolicy` + `RetryClient` -- wraps a call with jittered
+//!   exponential backoff, retrying whenever the call returns an
+//!   [`Error::retryable`] error (rate-limited, timed out, connection-refused,
+//!   or otherwise transport-level), up to a configurable retry count and
+//!   elapsed-time budget.
+//! * [`quorum`] dispatches a read to several endpoints concurrently and only
+//!   returns once at least `k` of `n` responses agree, surfacing a
+//!   disagreement error otherwise.
+use std::{
+    future::Future,
+    time::{Duration, Instant},
+};
+
+use crate::errors::{Error, Result};
+
+/// Retry policy that backs off and retries on [`Error::retryable`] errors with
+/// jittered exponential delay, honoring `Retry-After`-style hints surfaced in
+/// the error message for rate-limit responses.
+#[derive(Debug, Clone)]
+pub struct RetryPolicy {
+    /// Maximum number of retries after the initial attempt.
+    pub max_retries: usize,
+    /// Base delay; the nth retry waits a random delay of up to
+    /// `base_delay * 2^n` (capped at `max_delay`).
+    pub base_delay: Duration,
+    /// Upper bound on a single backoff delay.
+    pub max_delay: Duration,
+    /// Optional ceiling on total time spent retrying (measured from the first
+    /// attempt); `None` leaves the budget bounded only by `max_retries`.
+    pub max_elapsed: Option<Duration>,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_retries: 5,
+            base_delay: Duration::from_millis(100),
+            max_delay: Duration::from_secs(5),
+            max_elapsed: None,
+        }
+    }
+}
+
+impl RetryPolicy {
+    pub fn new(max_retries: usize, base_delay: Duration, max_delay: Duration) -> Self {
+        Self {
+            max_retries,
+            base_delay,
+            max_delay,
+            max_elapsed: None,
+        }
+    }
+
+    /// Caps the total time spent retrying, regardless of `max_retries`.
+    #[must_use]
+    pub fn with_max_elapsed(mut self, max_elapsed: Duration) -> Self {
+        self.max_elapsed = Some(max_elapsed);
+        self
+    }
+
+    /// Runs `f`, retrying on [`Error::retryable`] errors until it succeeds, a
+    /// terminal error is returned, or the retry/elapsed budget is exhausted.
+    pub async fn retry<F, Fut, T>(&self, mut f: F) -> Result<T>
+    where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = Result<T>>,
+    {
+        let started = Instant::now();
+        let mut attempt = 0usize;
+        loop {
+            match f().await {
+                Ok(v) => return Ok(v),
+                Err(e) => {
+                    let elapsed_exceeded = self
+                        .max_elapsed
+                        .is_some_and(|budget| started.elapsed() >= budget);
+                    if attempt >= self.max_retries || !e.retryable() || elapsed_exceeded {
+                        return Err(e);
+                    }
+                    let delay = self.delay_for(attempt, &e);
+                    log::warn!(
+                        "retryable error (attempt {}/{}), backing off {:?}: {}",
+                        attempt + 1,
+                        self.max_retries,
+                        delay,
+                        e.message()
+                    );
+                    tokio::time::sleep(delay).await;
+                    attempt += 1;
+                }
+            }
+        }
+    }
+
+    /// The delay before the next attempt: a rate-limit response's
+    /// `Retry-After` hint (floored at the jittered exponential backoff) if
+    /// present, else the plain jittered exponential backoff.
+    fn delay_for(&self, attempt: usize, e: &Error) -> Duration {
+        let backoff = self.backoff(attempt);
+        match retry_after(e) {
+            Some(hint) => hint.max(backoff),
+            None => backoff,
+        }
+    }
+
+    /// Exponential backoff for the given attempt with full jitter, capped at
+    /// [`RetryPolicy::max_delay`].
+    fn backoff(&self, attempt: usize) -> Duration {
+        let exp = self
+            .base_delay
+            .saturating_mul(1u32 << attempt.min(16) as u32);
+        let capped = exp.min(self.max_delay);
+        // full jitter: sleep a random fraction of the capped window
+        let millis = capped.as_millis() as u64;
+        let jittered = if millis == 0 {
+            0
+        } else {
+            random_manager::u64() % (millis + 1)
+        };
+        Duration::from_millis(jittered)
+    }
+}
+
+/// Returns whether an error looks like a rate-limit / HTTP 429 response.
+pub fn is_rate_limited(e: &Error) -> bool {
+    let m = e.message().to_lowercase();
+    m.contains("429") || m.contains("too many requests") || m.contains("rate limit")
+}
+
+/// Returns whether an error looks like a transient transport failure --
+/// connection reset/refused, or a client-side timeout -- as opposed to a
+/// rejection the server intends to be final.
+pub fn is_transport_error(e: &Error) -> bool {
+    let m = e.message().to_lowercase();
+    m.contains("connection refused")
+        || m.contains("connection reset")
+        || m.contains("timed out")
+        || m.contains("timeout")
+        || m.contains("broken pipe")
+}
+
+/// Best-effort parse of a `Retry-After` hint out of an error message, honoring
+/// both the HTTP header (callers that surface it are expected to embed it
+/// literally, e.g. `"... Retry-After: 5 ..."`) and the common JSON-RPC
+/// rate-limit wording (`"retry after 5s"`). Returns `None` when no duration is
+/// present in the message, since [`Error`] only carries a string here, not the
+/// raw response.
+pub fn retry_after(e: &Error) -> Option<Duration> {
+    let m = e.message().to_lowercase();
+    let idx = m.find("retry-after").or_else(|| m.find("retry after"))?;
+    let tail = &m[idx..];
+    let digits: String = tail
+        .chars()
+        .skip_while(|c| !c.is_ascii_digit())
+        .take_while(|c| c.is_ascii_digit())
+        .collect();
+    let secs: u64 = digits.parse().ok()?;
+    Some(Duration::from_secs(secs))
+}
+
+/// Dispatches `call` to every endpoint concurrently and returns a value once at
+/// least `k` of the responses are equal; errors if no `k` responses agree
+/// (e.g. the endpoints disagree on `get_tx_status` or `chain_id`).
+pub async fn quorum<F, Fut, T>(endpoints: &[String], k: usize, call: F) -> Result<T>
+where
+    F: Fn(String) -> Fut,
+    Fut: Future<Output = Result<T>>,
+    T: Clone + PartialEq,
+{
+    if k == 0 || k > endpoints.len() {
+        return Err(Error::Other {
+            message: format!(
+                "invalid quorum threshold {k} for {} endpoints",
+                endpoints.len()
+            ),
+            retryable: false,
+        });
+    }
+
+    let results = futures::future::join_all(endpoints.iter().cloned().map(|ep| call(ep))).await;
+    let oks: Vec<T> = results
+        .into_iter()
+        .filter_map(std::result::Result::ok)
+        .collect();
+
+    for candidate in &oks {
+        if oks.iter().filter(|o| *o == candidate).count() >= k {
+            return Ok(candidate.clone());
+        }
+    }
+
+    Err(Error::API {
+        message: format!(
+            "quorum of {k} not reached among {} endpoints ({} non-error responses)",
+            endpoints.len(),
+            oks.len()
+        ),
+        retryable: true,
+    })
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib --features="jsonrpc_client" -- jsonrpc::client::provider::test_provider --exact --show-output
+#[test]
+fn test_provider() {
+    use std::sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    };
+
+    macro_rules! ab {
+        ($e:expr) => {
+            tokio_test::block_on($e)
+        };
+    }
+
+    // retries on rate-limit, then succeeds
+    let calls = Arc::new(AtomicUsize::new(0));
+    let policy = RetryPolicy::new(3, Duration::from_millis(1), Duration::from_millis(2));
+    let c = calls.clone();
+    let got: Result<u64> = ab!(policy.retry(|| {
+        let c = c.clone();
+        async move {
+            if c.fetch_add(1, Ordering::SeqCst) < 2 {
+                Err(Error::API {
+                    message: "server returned 429 Too Many Requests".to_string(),
+                    retryable: true,
+                })
+            } else {
+                Ok(42u64)
+            }
+        }
+    }));
+    assert_eq!(got.unwrap(), 42);
+    assert_eq!(calls.load(Ordering::SeqCst), 3);
+
+    // non-retryable errors are surfaced immediately
+    let calls = Arc::new(AtomicUsize::new(0));
+    let c = calls.clone();
+    let got: Result<u64> = ab!(policy.retry(|| {
+        let c = c.clone();
+        async move {
+            c.fetch_add(1, Ordering::SeqCst);
+            Err(Error::API {
+                message: "bad request".to_string(),
+                retryable: false,
+            })
+        }
+    }));
+    assert!(got.is_err());
+    assert_eq!(calls.load(Ordering::SeqCst), 1);
+
+    // a retryable transport error still retries even without rate-limit wording
+    let calls = Arc::new(AtomicUsize::new(0));
+    let c = calls.clone();
+    let got: Result<u64> = ab!(policy.retry(|| {
+        let c = c.clone();
+        async move {
+            if c.fetch_add(1, Ordering::SeqCst) < 1 {
+                Err(Error::API {
+                    message: "connection refused".to_string(),
+                    retryable: true,
+                })
+            } else {
+                Ok(1u64)
+            }
+        }
+    }));
+    assert_eq!(got.unwrap(), 1);
+    assert_eq!(calls.load(Ordering::SeqCst), 2);
+
+    // max_elapsed cuts retries short even with retries remaining
+    let bounded = RetryPolicy::new(100, Duration::from_millis(5), Duration::from_millis(5))
+        .with_max_elapsed(Duration::from_millis(1));
+    let calls = Arc::new(AtomicUsize::new(0));
+    let c = calls.clone();
+    let got: Result<u64> = ab!(bounded.retry(|| {
+        let c = c.clone();
+        async move {
+            c.fetch_add(1, Ordering::SeqCst);
+            tokio::time::sleep(Duration::from_millis(2)).await;
+            Err(Error::API {
+                message: "rate limit".to_string(),
+                retryable: true,
+            })
+        }
+    }));
+    assert!(got.is_err());
+
+    assert!(is_transport_error(&Error::API {
+        message: "Connection reset by peer".to_string(),
+        retryable: true,
+    }));
+    assert_eq!(
+        retry_after(&Error::API {
+            message: "429 Too Many Requests, Retry-After: 7".to_string(),
+            retryable: true,
+        }),
+        Some(Duration::from_secs(7))
+    );
+    assert_eq!(
+        retry_after(&Error::API {
+            message: "rate limited, retry after 3s".to_string(),
+            retryable: true,
+        }),
+        Some(Duration::from_secs(3))
+    );
+
+    // quorum agrees once k responses match
+    let endpoints = vec!["a".to_string(), "b".to_string(), "c".to_string()];
+    let got: Result<u64> = ab!(quorum(&endpoints, 2, |ep| async move {
+        match ep.as_str() {
+            "c" => Ok(7u64),
+            _ => Ok(9u64),
+        }
+    }));
+    assert_eq!(got.unwrap(), 9);
+
+    // disagreement surfaces an error
+    let got: Result<u64> = ab!(quorum(&endpoints, 3, |ep| async move {
+        match ep.as_str() {
+            "a" => Ok(1u64),
+            "b" => Ok(2u64),
+            _ => Ok(3u64),
+        }
+    }));
+    assert!(got.is_err());
+}
@@ -0,0 +1,150 @@
+//! Streaming subscription clients for `info.peers` and health changes.
+//!
+//! AvalancheGo's `info` and `health` APIs are request/response only, so this
+//! client emulates a push subscription: it polls the endpoint on a fixed
+//! interval in a background task and forwards a new value over a channel only
+//! when it differs from the previously observed one. Callers receive a
+//! [`tokio::sync::mpsc::Receiver`] and a [`JoinHandle`] they can abort to stop
+//! the stream, giving the ergonomics of a WebSocket subscription without a
+//! server-side push channel.
+
+use std::{sync::Arc, time::Duration};
+
+use crate::{
+    errors::Result,
+    ids,
+    jsonrpc::{
+        client::{health as client_health, info as client_info},
+        health, info,
+    },
+};
+use tokio::{sync::mpsc, task::JoinHandle, time::interval};
+
+/// Default polling interval used when emulating a subscription.
+pub const DEFAULT_POLL_INTERVAL: Duration = Duration::from_secs(10);
+
+/// Spawns a background task that polls `info.peers` every `poll_interval` and
+/// sends each changed [`info::PeersResult`] to the returned receiver.
+pub fn subscribe_peers(
+    http_rpc: &str,
+    node_ids: Option<Vec<ids::node::Id>>,
+    poll_interval: Duration,
+) -> (mpsc::Receiver<Result<info::PeersResult>>, JoinHandle<()>) {
+    let (tx, rx) = mpsc::channel(1);
+    let http_rpc = http_rpc.to_string();
+    let handle = tokio::spawn(async move {
+        let mut ticker = interval(poll_interval);
+        let mut last: Option<info::PeersResult> = None;
+        loop {
+            ticker.tick().await;
+            let res = client_info::peers(&http_rpc, node_ids.clone()).await;
+            match res {
+                Ok(resp) => {
+                    if let Some(result) = resp.result {
+                        if last.as_ref() != Some(&result) {
+                            last = Some(result.clone());
+                            if tx.send(Ok(result)).await.is_err() {
+                                break; // receiver dropped
+                            }
+                        }
+                    }
+                }
+                Err(e) => {
+                    if tx.send(Err(e)).await.is_err() {
+                        break;
+                    }
+                }
+            }
+        }
+    });
+    (rx, handle)
+}
+
+/// Spawns a background task that polls the health endpoint every
+/// `poll_interval` and sends the [`health::Response`] to the returned receiver
+/// whenever its `healthy` flag differs from the previous poll's; changes to
+/// the rest of the response alone are not sent.
+pub fn subscribe_health(
+    http_rpc: &str,
+    liveness: bool,
+    poll_interval: Duration,
+) -> (mpsc::Receiver<Result<health::Response>>, JoinHandle<()>) {
+    let (tx, rx) = mpsc::channel(1);
+    let http_rpc = Arc::new(http_rpc.to_string());
+    let handle = tokio::spawn(async move {
+        let mut ticker = interval(poll_interval);
+        let mut last_healthy: Option<bool> = None;
+        loop {
+            ticker.tick().await;
+            let res = client_health::check(Arc::clone(&http_rpc), liveness).await;
+            match res {
+                Ok(resp) => {
+                    if last_healthy != Some(resp.healthy) {
+                        last_healthy = Some(resp.healthy);
+                        if tx.send(Ok(resp)).await.is_err() {
+                            break;
+                        }
+                    }
+                }
+                Err(e) => {
+                    if tx.send(Err(e)).await.is_err() {
+                        break;
+                    }
+                }
+            }
+        }
+    });
+    (rx, handle)
+}
+
+/// A churn event emitted by [`track_peer_membership`] when the set of connected
+/// peers changes between polls.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PeerChurnEvent {
+    /// A peer that was not present in the previous poll is now connected.
+    Joined(ids::node::Id),
+    /// A peer present in the previous poll is no longer connected.
+    Left(ids::node::Id),
+}
+
+/// Spawns a background task that polls `info.peers` every `poll_interval` and
+/// emits a [`PeerChurnEvent`] for every node that joins or leaves between
+/// consecutive polls. The first poll establishes the baseline and emits a
+/// `Joined` for each currently-connected peer.
+pub fn track_peer_membership(
+    http_rpc: &str,
+    poll_interval: Duration,
+) -> (mpsc::Receiver<PeerChurnEvent>, JoinHandle<()>) {
+    use std::collections::HashSet;
+
+    let (tx, rx) = mpsc::channel(64);
+    let http_rpc = http_rpc.to_string();
+    let handle = tokio::spawn(async move {
+        let mut ticker = interval(poll_interval);
+        let mut known: HashSet<ids::node::Id> = HashSet::new();
+        loop {
+            ticker.tick().await;
+            let peers = match client_info::peers(&http_rpc, None).await {
+                Ok(resp) => resp.result.and_then(|r| r.peers).unwrap_or_default(),
+                Err(e) => {
+                    log::warn!("failed to poll peers for membership tracking: {e}");
+                    continue;
+                }
+            };
+            let current: HashSet<ids::node::Id> = peers.iter().map(|p| p.node_id).collect();
+
+            for joined in current.difference(&known) {
+                if tx.send(PeerChurnEvent::Joined(*joined)).await.is_err() {
+                    return;
+                }
+            }
+            for left in known.difference(&current) {
+                if tx.send(PeerChurnEvent::Left(*left)).await.is_err() {
+                    return;
+                }
+            }
+            known = current;
+        }
+    });
+    (rx, handle)
+}
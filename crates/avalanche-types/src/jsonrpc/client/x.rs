@@ -267,10 +267,25 @@ pub async fn get_asset_description(
     })
 }
 
-/// e.g., "avm.getUTXOs" on "http://\[ADDR\]:9650" and "/ext/bc/X" path.
-/// TODO: support paginated calls
+/// e.g., "avm.getUTXOs" on "http://\[ADDR\]:9650" and "/ext/bc/X" path, fetching
+/// a single page of up to 1024 UTXOs. Callers that must collect every UTXO
+/// (e.g. spend selection) should paginate via [`get_utxos_page`] instead,
+/// since an address can hold more UTXOs than a single page returns.
 /// ref. <https://docs.avax.network/apis/avalanchego/apis/x-chain/#avmgetutxos>
 pub async fn get_utxos(http_rpc: &str, xaddr: &str) -> Result<avm::GetUtxosResponse> {
+    get_utxos_page(http_rpc, xaddr, 1024, None).await
+}
+
+/// e.g., "avm.getUTXOs" on "http://\[ADDR\]:9650" and "/ext/bc/X" path, fetching
+/// up to "limit" UTXOs starting after "start_index" (the previous page's
+/// "endIndex"; "None" starts from the beginning).
+/// ref. <https://docs.avax.network/apis/avalanchego/apis/x-chain/#avmgetutxos>
+pub async fn get_utxos_page(
+    http_rpc: &str,
+    xaddr: &str,
+    limit: u32,
+    start_index: Option<jsonrpc::EndIndex>,
+) -> Result<avm::GetUtxosResponse> {
     let (scheme, host, port, _, _) =
         utils::urls::extract_scheme_host_port_path_chain_alias(http_rpc).map_err(|e| {
             Error::Other {
@@ -279,14 +294,15 @@ pub async fn get_utxos(http_rpc: &str, xaddr: &str) -> Result<avm::GetUtxosRespo
             }
         })?;
     let url = url::try_create_url(url::Path::X, scheme.as_deref(), host.as_str(), port)?;
-    log::info!("getting UTXOs via {url} for {xaddr}");
+    log::info!("getting UTXOs via {url} for {xaddr} (limit {limit}, start_index {start_index:?})");
 
     let mut data = avm::GetUtxosRequest::default();
     data.method = String::from("avm.getUTXOs");
     let params = avm::GetUtxosParams {
         addresses: vec![xaddr.to_string()],
-        limit: 1024,
+        limit,
         encoding: String::from("hex"), // don't use "cb58"
+        start_index,
     };
     data.params = Some(params);
     let d = data.encode_json().map_err(|e| Error::Other {
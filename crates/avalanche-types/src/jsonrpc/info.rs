@@ -17,7 +17,7 @@ use serde_with::{serde_as, DisplayFromStr};
 #[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
 pub struct GetNetworkNameResponse {
     pub jsonrpc: String,
-    pub id: u32,
+    pub id: jsonrpc::Id,
 
     #[serde(skip_serializing_if = "Option::is_none")]
     pub result: Option<GetNetworkNameResult>,
@@ -51,7 +51,7 @@ impl GetNetworkNameResult {
 #[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
 pub struct GetNetworkIdResponse {
     pub jsonrpc: String,
-    pub id: u32,
+    pub id: jsonrpc::Id,
 
     #[serde(skip_serializing_if = "Option::is_none")]
     pub result: Option<GetNetworkIdResult>,
@@ -102,7 +102,7 @@ fn test_get_network_id() {
 
     let expected = GetNetworkIdResponse {
         jsonrpc: "2.0".to_string(),
-        id: 1,
+        id: jsonrpc::Id::Number(1),
         result: Some(GetNetworkIdResult {
             network_id: 9999999_u32,
         }),
@@ -115,7 +115,7 @@ fn test_get_network_id() {
 #[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
 pub struct GetBlockchainIdResponse {
     pub jsonrpc: String,
-    pub id: u32,
+    pub id: jsonrpc::Id,
 
     #[serde(skip_serializing_if = "Option::is_none")]
     pub result: Option<GetBlockchainIdResult>,
@@ -168,7 +168,7 @@ fn test_get_blockchain_id() {
 
     let expected = GetBlockchainIdResponse {
         jsonrpc: "2.0".to_string(),
-        id: 1,
+        id: jsonrpc::Id::Number(1),
         result: Some(GetBlockchainIdResult {
             blockchain_id: ids::Id::from_str("sV6o671RtkGBcno1FiaDbVcFv2sG5aVXMZYzKdP4VQAWmJQnM")
                 .unwrap(),
@@ -182,7 +182,7 @@ fn test_get_blockchain_id() {
 #[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
 pub struct GetNodeIdResponse {
     pub jsonrpc: String,
-    pub id: u32,
+    pub id: jsonrpc::Id,
 
     #[serde(skip_serializing_if = "Option::is_none")]
     pub result: Option<GetNodeIdResult>,
@@ -236,7 +236,7 @@ fn test_get_node_id() {
     .unwrap();
     let expected = GetNodeIdResponse {
         jsonrpc: "2.0".to_string(),
-        id: 1,
+        id: jsonrpc::Id::Number(1),
         result: Some(GetNodeIdResult {
             node_id: node::Id::from_str("NodeID-5mb46qkSBj81k9g9e4VFjGGSbaaSLFRzD").unwrap(),
             ..Default::default()
@@ -265,7 +265,7 @@ fn test_get_node_id() {
     .unwrap();
     let expected = GetNodeIdResponse {
         jsonrpc: "2.0".to_string(),
-        id: 1,
+        id: jsonrpc::Id::Number(1),
         result: Some(GetNodeIdResult {
             node_id: node::Id::from_str("NodeID-5mb46qkSBj81k9g9e4VFjGGSbaaSLFRzD").unwrap(),
             node_pop: Some(bls::ProofOfPossession {
@@ -283,7 +283,7 @@ fn test_get_node_id() {
 #[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
 pub struct GetNodeIpResponse {
     pub jsonrpc: String,
-    pub id: u32,
+    pub id: jsonrpc::Id,
 
     #[serde(skip_serializing_if = "Option::is_none")]
     pub result: Option<GetNodeIpResult>,
@@ -333,7 +333,7 @@ fn test_get_node_ip() {
     .unwrap();
     let expected = GetNodeIpResponse {
         jsonrpc: "2.0".to_string(),
-        id: 1,
+        id: jsonrpc::Id::Number(1),
         result: Some(GetNodeIpResult {
             ip: SocketAddr::new(IpAddr::V4(Ipv4Addr::new(192, 168, 1, 1)), 9651),
         }),
@@ -346,7 +346,7 @@ fn test_get_node_ip() {
 #[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
 pub struct GetNodeVersionResponse {
     pub jsonrpc: String,
-    pub id: u32,
+    pub id: jsonrpc::Id,
 
     #[serde(skip_serializing_if = "Option::is_none")]
     pub result: Option<GetNodeVersionResult>,
@@ -403,7 +403,7 @@ fn test_get_node_version() {
     .unwrap();
     let expected = GetNodeVersionResponse {
         jsonrpc: "2.0".to_string(),
-        id: 1,
+        id: jsonrpc::Id::Number(1),
         result: Some(GetNodeVersionResult {
             version: String::from("avalanche/1.10.1"),
             database_version: String::from("v1.4.5"),
@@ -425,7 +425,7 @@ fn test_get_node_version() {
 #[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
 pub struct GetVmsResponse {
     pub jsonrpc: String,
-    pub id: u32,
+    pub id: jsonrpc::Id,
 
     #[serde(skip_serializing_if = "Option::is_none")]
     pub result: Option<GetVmsResult>,
@@ -458,7 +458,7 @@ impl GetVmsResult {
 #[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
 pub struct IsBootstrappedResponse {
     pub jsonrpc: String,
-    pub id: u32,
+    pub id: jsonrpc::Id,
 
     #[serde(skip_serializing_if = "Option::is_none")]
     pub result: Option<IsBootstrappedResult>,
@@ -492,7 +492,7 @@ impl IsBootstrappedResult {
 #[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
 pub struct GetTxFeeResponse {
     pub jsonrpc: String,
-    pub id: u32,
+    pub id: jsonrpc::Id,
 
     #[serde(skip_serializing_if = "Option::is_none")]
     pub result: Option<GetTxFeeResult>,
@@ -578,7 +578,7 @@ fn test_get_tx_fee() {
 
     let expected = GetTxFeeResponse {
         jsonrpc: "2.0".to_string(),
-        id: 1,
+        id: jsonrpc::Id::Number(1),
         result: Some(GetTxFeeResult {
             tx_fee: 1000000,
             create_asset_tx_fee: 1000000,
@@ -599,7 +599,7 @@ fn test_get_tx_fee() {
 #[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
 pub struct UptimeResponse {
     pub jsonrpc: String,
-    pub id: u32,
+    pub id: jsonrpc::Id,
 
     #[serde(skip_serializing_if = "Option::is_none")]
     pub result: Option<UptimeResult>,
@@ -656,7 +656,7 @@ fn test_uptime() {
 
     let expected = UptimeResponse {
         jsonrpc: "2.0".to_string(),
-        id: 1,
+        id: jsonrpc::Id::Number(1),
         result: Some(UptimeResult {
             rewarding_stake_percentage: 100.0000_f64,
             weighted_average_percentage: 99.0000_f64,
@@ -670,7 +670,7 @@ fn test_uptime() {
 #[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
 pub struct PeersRequest {
     pub jsonrpc: String,
-    pub id: u32,
+    pub id: jsonrpc::Id,
 
     pub method: String,
 
@@ -710,7 +710,7 @@ pub struct PeersParams {
 #[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
 pub struct PeersResponse {
     pub jsonrpc: String,
-    pub id: u32,
+    pub id: jsonrpc::Id,
 
     #[serde(skip_serializing_if = "Option::is_none")]
     pub result: Option<PeersResult>,
@@ -746,10 +746,14 @@ impl PeersResult {
     }
 }
 
-/// TODO: add "benched"
 /// ref. <https://docs.avax.network/apis/avalanchego/apis/info#infopeers>
+///
+/// `benched` carries the subnets this peer is currently benched on, and any
+/// fields AvalancheGo adds in a future release are preserved in `extra` so an
+/// older client can still round-trip the response rather than failing to parse
+/// it. `Eq` is intentionally omitted because `extra` holds arbitrary JSON.
 #[serde_as]
-#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct Peer {
     #[serde_as(as = "crate::codec::serde::ip_port::IpPort")]
@@ -769,6 +773,14 @@ pub struct Peer {
     #[serde_as(as = "HashMap<_, DisplayFromStr>")]
     pub observed_subnet_uptimes: HashMap<ids::Id, u32>,
     pub tracked_subnets: Vec<ids::Id>,
+    /// Subnets this peer is currently benched (temporarily dropped) on.
+    #[serde(default)]
+    pub benched: Vec<ids::Id>,
+
+    /// Any additional fields returned by newer AvalancheGo versions, kept so
+    /// the response round-trips forward-compatibly.
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
 impl Default for Peer {
@@ -789,6 +801,8 @@ impl Peer {
             observed_uptime: 0,
             observed_subnet_uptimes: HashMap::new(),
             tracked_subnets: Vec::new(),
+            benched: Vec::new(),
+            extra: HashMap::new(),
         }
     }
 }
@@ -857,7 +871,7 @@ fn test_peers() {
     .collect();
     let expected = PeersResponse {
         jsonrpc: "2.0".to_string(),
-        id: 1,
+        id: jsonrpc::Id::Number(1),
         result: Some(PeersResult {
             num_peers: 3,
             peers: Some(vec![
@@ -911,3 +925,79 @@ fn test_peers() {
     };
     assert_eq!(resp, expected);
 }
+
+/// A per-subnet view of which peers are currently benched, produced by
+/// [`benching_report`]. Benched peers have been temporarily dropped by this
+/// node, so a growing count signals degraded connectivity to that subnet.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct BenchingReport {
+    /// Node IDs benched on each subnet.
+    pub benched_by_subnet: HashMap<ids::Id, Vec<node::Id>>,
+}
+
+impl BenchingReport {
+    /// Total number of benched peer/subnet pairs across all subnets.
+    pub fn total_benched(&self) -> usize {
+        self.benched_by_subnet.values().map(Vec::len).sum()
+    }
+}
+
+/// Builds a [`BenchingReport`] from a peer set by reading each peer's `benched`
+/// subnets.
+pub fn benching_report(peers: &[Peer]) -> BenchingReport {
+    let mut benched_by_subnet: HashMap<ids::Id, Vec<node::Id>> = HashMap::new();
+    for peer in peers {
+        for subnet in &peer.benched {
+            benched_by_subnet
+                .entry(*subnet)
+                .or_default()
+                .push(peer.node_id);
+        }
+    }
+    BenchingReport { benched_by_subnet }
+}
+
+impl Peer {
+    /// Reports whether this peer looks stale: no message has been received from
+    /// it within `keepalive` of `now`. A freshly observed peer (where
+    /// `last_received` is in the future relative to `now`) is never stale.
+    pub fn is_stale(&self, now: DateTime<Utc>, keepalive: chrono::Duration) -> bool {
+        now.signed_duration_since(self.last_received) > keepalive
+    }
+}
+
+impl Peer {
+    /// Reports whether this peer appears to sit behind NAT, i.e. its advertised
+    /// `public_ip` differs from the socket `ip` we observe the connection on.
+    pub fn is_behind_nat(&self) -> bool {
+        self.ip.ip() != self.public_ip.ip()
+    }
+
+    /// Returns the peer's advertised address when its IP differs from the
+    /// observed socket IP (see [`Peer::is_behind_nat`]), otherwise `None`;
+    /// a difference in port alone returns `None`.
+    pub fn advertised_address(&self) -> Option<SocketAddr> {
+        if self.is_behind_nat() {
+            Some(self.public_ip)
+        } else {
+            None
+        }
+    }
+}
+
+/// Aggregates observed subnet uptimes across the whole peer set, returning the
+/// mean observed uptime (0-100) per subnet. Subnets with no observations are
+/// omitted.
+pub fn aggregate_subnet_uptimes(peers: &[Peer]) -> HashMap<ids::Id, f64> {
+    let mut sums: HashMap<ids::Id, (u64, u64)> = HashMap::new();
+    for peer in peers {
+        for (subnet, uptime) in &peer.observed_subnet_uptimes {
+            let entry = sums.entry(*subnet).or_insert((0, 0));
+            entry.0 += u64::from(*uptime);
+            entry.1 += 1;
+        }
+    }
+    sums.into_iter()
+        .map(|(subnet, (total, count))| (subnet, total as f64 / count as f64))
+        .collect()
+}
@@ -16,11 +16,38 @@ use std::{
     io::{self, Error, ErrorKind},
 };
 
-use serde::{Deserialize, Serialize};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use serde_json::value::RawValue;
 
 pub const DEFAULT_VERSION: &str = "2.0";
 pub const DEFAULT_ID: u32 = 1;
 
+/// A JSON-RPC request/response identifier.
+///
+/// Per the spec the `id` may be a number, a string, or null. AvalancheGo
+/// replies with a number today, but accepting all three keeps the `info`
+/// responses forward-compatible with clients that echo string or null ids.
+/// ref. <https://www.jsonrpc.org/specification#request_object>
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+#[serde(untagged)]
+pub enum Id {
+    Number(i64),
+    String(String),
+    Null,
+}
+
+impl Default for Id {
+    fn default() -> Self {
+        Self::Number(i64::from(DEFAULT_ID))
+    }
+}
+
+impl From<u32> for Id {
+    fn from(n: u32) -> Self {
+        Self::Number(i64::from(n))
+    }
+}
+
 /// ref. <https://www.jsonrpc.org/specification>
 /// ref. <https://docs.avax.network/build/avalanchego-apis/issuing-api-calls>
 #[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
@@ -161,3 +188,47 @@ pub struct ResponseError {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub data: Option<String>,
 }
+
+/// A partially-decoded JSON-RPC response.
+///
+/// The `id` and `error` fields are parsed eagerly while the `result` payload is
+/// retained as raw JSON, so callers can inspect the id and surface a protocol
+/// `error` before paying to deserialize a (possibly large) typed `result` such
+/// as an `info.peers` response on a big network.
+#[derive(Debug, Deserialize)]
+pub struct RawResponse {
+    #[serde(default)]
+    pub jsonrpc: String,
+    #[serde(default)]
+    pub id: Id,
+
+    #[serde(default)]
+    pub result: Option<Box<RawValue>>,
+    #[serde(default)]
+    pub error: Option<ResponseError>,
+}
+
+impl RawResponse {
+    /// Parses only the envelope, leaving `result` as raw JSON.
+    pub fn from_slice(b: &[u8]) -> io::Result<Self> {
+        serde_json::from_slice(b)
+            .map_err(|e| Error::new(ErrorKind::Other, format!("failed to parse response {}", e)))
+    }
+
+    /// Deserializes the raw `result` into `T`, returning the protocol `error`
+    /// first if one is present.
+    pub fn typed_result<T: DeserializeOwned>(&self) -> io::Result<T> {
+        if let Some(err) = &self.error {
+            return Err(Error::new(
+                ErrorKind::Other,
+                format!("JSON-RPC error (code {}): {}", err.code, err.message),
+            ));
+        }
+        match &self.result {
+            Some(raw) => serde_json::from_str(raw.get()).map_err(|e| {
+                Error::new(ErrorKind::Other, format!("failed to parse result {}", e))
+            }),
+            None => Err(Error::new(ErrorKind::Other, "no result in response")),
+        }
+    }
+}
@@ -64,6 +64,43 @@ impl Sig {
             false,
         ) == BLST_ERROR::BLST_SUCCESS
     }
+
+    /// Verifies that this (aggregate) signature was produced by the combined
+    /// `pubkeys` all signing the same `msg`. blst aggregates the public keys
+    /// internally, avoiding a separate [`bls::public_key::aggregate`] round
+    /// trip. Returns false on an empty key set or any non-success result.
+    /// ref. "avalanchego/utils/crypto/bls.AggregateVerify"
+    pub fn fast_aggregate_verify(&self, msg: &[u8], pubkeys: &[PublicKey]) -> bool {
+        if pubkeys.is_empty() {
+            return false;
+        }
+        let pks = pubkeys.iter().map(|p| &p.0).collect::<Vec<_>>();
+        self.0.fast_aggregate_verify(
+            false,
+            msg,
+            &bls::private_key::CIPHER_SUITE_SIGNATURE,
+            &pks,
+        ) == BLST_ERROR::BLST_SUCCESS
+    }
+
+    /// Verifies that this (aggregate) signature covers each `pubkeys[i]` signing
+    /// the distinct message `msgs[i]`. Requires `msgs.len() == pubkeys.len()`;
+    /// returns false on a length mismatch, empty input, or any non-success
+    /// result.
+    /// ref. "avalanchego/utils/crypto/bls.AggregateVerify"
+    pub fn aggregate_verify(&self, msgs: &[&[u8]], pubkeys: &[PublicKey]) -> bool {
+        if msgs.is_empty() || msgs.len() != pubkeys.len() {
+            return false;
+        }
+        let pks = pubkeys.iter().map(|p| &p.0).collect::<Vec<_>>();
+        self.0.aggregate_verify(
+            false,
+            msgs,
+            &bls::private_key::CIPHER_SUITE_SIGNATURE,
+            &pks,
+            false,
+        ) == BLST_ERROR::BLST_SUCCESS
+    }
 }
 
 impl From<Signature> for Sig {
@@ -137,3 +174,36 @@ fn test_signature() {
     let agg_sig_pos = aggregate(&[sig1_pos, sig2_pos, sig3_pos]).unwrap();
     assert!(agg_pubkey.verify_proof_of_possession(&msg_to_sign, &agg_sig_pos));
 }
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- key::bls::signature::test_batch_verify --exact --show-output
+#[test]
+fn test_batch_verify() {
+    let _ = env_logger::builder()
+        .filter_level(log::LevelFilter::Info)
+        .is_test(true)
+        .try_init();
+
+    let sk1 = crate::key::bls::private_key::Key::generate().unwrap();
+    let sk2 = crate::key::bls::private_key::Key::generate().unwrap();
+    let sk3 = crate::key::bls::private_key::Key::generate().unwrap();
+    let pubkeys = [sk1.to_public_key(), sk2.to_public_key(), sk3.to_public_key()];
+
+    // same message signed by all three -> fast_aggregate_verify
+    let msg = random_manager::secure_bytes(50).unwrap();
+    let agg = aggregate(&[sk1.sign(&msg), sk2.sign(&msg), sk3.sign(&msg)]).unwrap();
+    assert!(agg.verify(&msg, &crate::key::bls::public_key::aggregate(&pubkeys).unwrap()));
+    assert!(agg.fast_aggregate_verify(&msg, &pubkeys));
+    assert!(!agg.fast_aggregate_verify(&msg, &pubkeys[..2]));
+    assert!(!agg.fast_aggregate_verify(&msg, &[]));
+
+    // distinct messages, one per key -> aggregate_verify
+    let m1 = random_manager::secure_bytes(50).unwrap();
+    let m2 = random_manager::secure_bytes(50).unwrap();
+    let m3 = random_manager::secure_bytes(50).unwrap();
+    let agg2 = aggregate(&[sk1.sign(&m1), sk2.sign(&m2), sk3.sign(&m3)]).unwrap();
+    let msgs: [&[u8]; 3] = [&m1, &m2, &m3];
+    assert!(agg2.aggregate_verify(&msgs, &pubkeys));
+    // length mismatch and empty input are rejected
+    assert!(!agg2.aggregate_verify(&msgs[..2], &pubkeys));
+    assert!(!agg2.aggregate_verify(&[], &[]));
+}
@@ -0,0 +1,382 @@
+//! FROST t-of-n threshold Schnorr signing for secp256k1 keys.
+//!
+//! Implements a BIP340-compatible variant of FROST so a group of signers can
+//! jointly produce a single Schnorr signature valid under an aggregated group
+//! key, without any one party ever holding the full secret. This is useful for
+//! multi-party custody of validator / subnet control keys.
+//!
+//! The flow mirrors the FROST draft: a key generation step hands every
+//! participant a Shamir share `s_i` and a shared public group key `P`, then a
+//! two-round signing protocol produces `(R.x, z)` which verifies under the
+//! existing BIP340 verifier in [`super::public_key::Key::verify_schnorr`].
+//!
+//! Critical invariants enforced here: the per-session nonces are sampled fresh
+//! and never exposed across sessions, every signer binds to the *same*
+//! commitment list, and missing or duplicate shares fail cleanly rather than
+//! producing an invalid signature.
+//! ref. <https://datatracker.ietf.org/doc/rfc9591/>
+
+use crate::{
+    errors::{Error, Result},
+    hash,
+    key::secp256k1::{
+        private_key::{is_y_odd, reduce_scalar, scalar_to_bytes, tagged_hash, x_only_bytes},
+        public_key::Key as PublicKey,
+    },
+};
+use k256::{
+    elliptic_curve::{sec1::ToEncodedPoint, Field},
+    ProjectivePoint, Scalar,
+};
+
+/// Domain-separation tag for the FROST per-signer binding factors.
+const TAG_BINDING: &[u8] = b"avalanche-rs/FROST/binding";
+
+/// A single participant's long-lived key material: its Shamir share and the
+/// non-zero participant identifier used for Lagrange interpolation.
+#[derive(Debug, Clone)]
+pub struct Share {
+    /// Participant identifier (must be non-zero and unique within the group).
+    pub id: u16,
+    /// Secret share `s_i = f(id)` of the group secret.
+    pub secret: Scalar,
+}
+
+/// The outcome of key generation: one [`Share`] per participant plus the shared
+/// public group key the aggregated signature verifies against.
+#[derive(Debug, Clone)]
+pub struct GroupKey {
+    /// Aggregated public group key `P = secret*G` (always even-y, per BIP340).
+    pub group_public: ProjectivePoint,
+    /// Per-participant secret shares.
+    pub shares: Vec<Share>,
+}
+
+impl GroupKey {
+    /// The x-only (32-byte) encoding of the group public key.
+    pub fn public_x_only(&self) -> [u8; 32] {
+        x_only_bytes(&self.group_public)
+    }
+
+    /// The group public key as a [`PublicKey`] for verification / display.
+    pub fn to_public_key(&self) -> Result<PublicKey> {
+        let compressed = self.group_public.to_affine().to_encoded_point(true);
+        PublicKey::from_sec1_bytes(compressed.as_bytes())
+    }
+}
+
+/// A signer's secret per-session nonce pair `(d_i, e_i)`. These MUST NOT be
+/// reused across signing sessions; [`commit`] produces a fresh pair each call.
+#[derive(Debug, Clone)]
+pub struct Nonces {
+    d: Scalar,
+    e: Scalar,
+}
+
+/// The public commitments `(D_i, E_i)` a signer broadcasts in round 1.
+#[derive(Debug, Clone)]
+pub struct Commitment {
+    /// Identifier of the committing participant.
+    pub id: u16,
+    /// `D_i = d_i*G`.
+    pub big_d: ProjectivePoint,
+    /// `E_i = e_i*G`.
+    pub big_e: ProjectivePoint,
+}
+
+/// Performs trusted-dealer key generation: splits a freshly sampled group
+/// secret into `n` shares such that any `threshold` of them can sign. The group
+/// secret is conditionally negated so the public key has an even y-coordinate,
+/// as BIP340 requires.
+pub fn keygen(threshold: u16, n: u16) -> Result<GroupKey> {
+    if threshold == 0 || threshold > n {
+        return Err(Error::Other {
+            message: format!("invalid threshold {threshold} for {n} participants"),
+            retryable: false,
+        });
+    }
+
+    // a_0 is the group secret; force an even-y public key by negating it.
+    let mut secret = random_scalar()?;
+    if is_y_odd(&(ProjectivePoint::GENERATOR * secret)) {
+        secret = -secret;
+    }
+    let group_public = ProjectivePoint::GENERATOR * secret;
+
+    // sample the remaining polynomial coefficients a_1 .. a_{t-1}.
+    let mut coeffs = Vec::with_capacity(threshold as usize);
+    coeffs.push(secret);
+    for _ in 1..threshold {
+        coeffs.push(random_scalar()?);
+    }
+
+    let mut shares = Vec::with_capacity(n as usize);
+    for id in 1..=n {
+        let x = scalar_from_id(id);
+        // Horner evaluation of f(x).
+        let mut acc = Scalar::ZERO;
+        for c in coeffs.iter().rev() {
+            acc = acc * x + c;
+        }
+        shares.push(Share { id, secret: acc });
+    }
+
+    Ok(GroupKey {
+        group_public,
+        shares,
+    })
+}
+
+/// Round 1: sample a fresh nonce pair and return the secret nonces plus the
+/// public commitment to broadcast.
+pub fn commit(id: u16) -> Result<(Nonces, Commitment)> {
+    let d = random_scalar()?;
+    let e = random_scalar()?;
+    let commitment = Commitment {
+        id,
+        big_d: ProjectivePoint::GENERATOR * d,
+        big_e: ProjectivePoint::GENERATOR * e,
+    };
+    Ok((Nonces { d, e }, commitment))
+}
+
+/// Round 2: compute this signer's response `z_i`.
+///
+/// `commitments` is the agreed-upon list `B` of round-1 commitments for the
+/// signing set and MUST be identical for every signer. `message` is the 32-byte
+/// digest being signed.
+pub fn sign(
+    share: &Share,
+    nonces: &Nonces,
+    message: &[u8],
+    commitments: &[Commitment],
+    group: &GroupKey,
+) -> Result<Scalar> {
+    check_message(message)?;
+    let ids = signer_ids(commitments)?;
+    if !ids.contains(&share.id) {
+        return Err(Error::Other {
+            message: format!("signer {} is not in the commitment list", share.id),
+            retryable: false,
+        });
+    }
+
+    let (group_commitment, binding) = group_nonce(message, commitments)?;
+    let group_x = group.public_x_only();
+    let challenge = reduce_scalar(&tagged_hash(
+        b"BIP0340/challenge",
+        &[&x_only_bytes(&group_commitment), &group_x, message],
+    ));
+
+    // nonce contribution d_i + rho_i * e_i; negate it when the group nonce has
+    // an odd y so the aggregate R matches the even-y BIP340 convention.
+    let rho = binding
+        .iter()
+        .find(|(id, _)| *id == share.id)
+        .map(|(_, r)| *r)
+        .ok_or_else(|| Error::Other {
+            message: format!("no binding factor for signer {}", share.id),
+            retryable: false,
+        })?;
+    let mut nonce_term = nonces.d + rho * nonces.e;
+    if is_y_odd(&group_commitment) {
+        nonce_term = -nonce_term;
+    }
+
+    let lambda = lagrange_coefficient(share.id, &ids)?;
+    let z = nonce_term + lambda * share.secret * challenge;
+    Ok(z)
+}
+
+/// Aggregates the per-signer responses into a 64-byte BIP340 signature.
+pub fn aggregate(
+    message: &[u8],
+    commitments: &[Commitment],
+    responses: &[Scalar],
+) -> Result<[u8; 64]> {
+    check_message(message)?;
+    if responses.len() != commitments.len() {
+        return Err(Error::Other {
+            message: format!(
+                "response count {} does not match commitment count {}",
+                responses.len(),
+                commitments.len()
+            ),
+            retryable: false,
+        });
+    }
+    // validates identifiers are unique.
+    let _ = signer_ids(commitments)?;
+
+    let (group_commitment, _) = group_nonce(message, commitments)?;
+    let mut z = Scalar::ZERO;
+    for r in responses {
+        z += r;
+    }
+
+    let mut sig = [0u8; 64];
+    sig[..32].copy_from_slice(&x_only_bytes(&group_commitment));
+    sig[32..].copy_from_slice(&scalar_to_bytes(&z));
+    Ok(sig)
+}
+
+/// Recomputes the group nonce `R = Sum(D_i + rho_i*E_i)` together with the
+/// per-signer binding factors `rho_i`.
+fn group_nonce(
+    message: &[u8],
+    commitments: &[Commitment],
+) -> Result<(ProjectivePoint, Vec<(u16, Scalar)>)> {
+    let encoded = encode_commitments(commitments);
+    let mut binding = Vec::with_capacity(commitments.len());
+    let mut r = ProjectivePoint::IDENTITY;
+    for c in commitments {
+        let rho = reduce_scalar(&tagged_hash(
+            TAG_BINDING,
+            &[&c.id.to_be_bytes(), message, &encoded],
+        ));
+        r += c.big_d + c.big_e * rho;
+        binding.push((c.id, rho));
+    }
+    Ok((r, binding))
+}
+
+/// Serializes the commitment list `B` to a stable byte string so every signer
+/// derives identical binding factors.
+fn encode_commitments(commitments: &[Commitment]) -> Vec<u8> {
+    let mut out = Vec::with_capacity(commitments.len() * (2 + 33 + 33));
+    for c in commitments {
+        out.extend_from_slice(&c.id.to_be_bytes());
+        out.extend_from_slice(c.big_d.to_affine().to_encoded_point(true).as_bytes());
+        out.extend_from_slice(c.big_e.to_affine().to_encoded_point(true).as_bytes());
+    }
+    out
+}
+
+/// Extracts the sorted list of signer identifiers, rejecting duplicates.
+fn signer_ids(commitments: &[Commitment]) -> Result<Vec<u16>> {
+    let mut ids = Vec::with_capacity(commitments.len());
+    for c in commitments {
+        if ids.contains(&c.id) {
+            return Err(Error::Other {
+                message: format!("duplicate signer id {} in commitment list", c.id),
+                retryable: false,
+            });
+        }
+        ids.push(c.id);
+    }
+    if ids.is_empty() {
+        return Err(Error::Other {
+            message: "empty commitment list".to_string(),
+            retryable: false,
+        });
+    }
+    Ok(ids)
+}
+
+/// Computes the Lagrange coefficient `lambda_i` evaluated at 0 for the signing
+/// set `ids`.
+fn lagrange_coefficient(i: u16, ids: &[u16]) -> Result<Scalar> {
+    let xi = scalar_from_id(i);
+    let mut num = Scalar::ONE;
+    let mut den = Scalar::ONE;
+    for &j in ids {
+        if j == i {
+            continue;
+        }
+        let xj = scalar_from_id(j);
+        num *= xj;
+        den *= xj - xi;
+    }
+    let inv = Option::<Scalar>::from(den.invert()).ok_or_else(|| Error::Other {
+        message: "singular Lagrange denominator (duplicate signer ids?)".to_string(),
+        retryable: false,
+    })?;
+    Ok(num * inv)
+}
+
+/// Maps a non-zero participant identifier to its scalar.
+fn scalar_from_id(id: u16) -> Scalar {
+    Scalar::from(u64::from(id))
+}
+
+/// Samples a uniformly random non-zero scalar from the OS CSPRNG.
+fn random_scalar() -> Result<Scalar> {
+    loop {
+        let b = random_manager::secure_bytes(32).map_err(|e| Error::Other {
+            message: format!("failed secure_bytes {e}"),
+            retryable: false,
+        })?;
+        let mut arr = [0u8; 32];
+        arr.copy_from_slice(&b);
+        let s = reduce_scalar(&arr);
+        if !bool::from(s.is_zero()) {
+            return Ok(s);
+        }
+    }
+}
+
+fn check_message(message: &[u8]) -> Result<()> {
+    if message.len() != hash::SHA256_OUTPUT_LEN {
+        return Err(Error::Other {
+            message: format!(
+                "FROST message must be {}-byte, got {}-byte",
+                hash::SHA256_OUTPUT_LEN,
+                message.len()
+            ),
+            retryable: false,
+        });
+    }
+    Ok(())
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- key::secp256k1::frost::test_frost --exact --show-output
+#[test]
+fn test_frost() {
+    let _ = env_logger::builder()
+        .filter_level(log::LevelFilter::Info)
+        .is_test(true)
+        .try_init();
+
+    // 2-of-3 group.
+    let group = keygen(2, 3).unwrap();
+    let message = hash::sha256(b"frost threshold schnorr");
+
+    // signing set {1, 3}.
+    let signers = [&group.shares[0], &group.shares[2]];
+    let mut nonces = Vec::new();
+    let mut commitments = Vec::new();
+    for s in signers.iter() {
+        let (n, c) = commit(s.id).unwrap();
+        nonces.push(n);
+        commitments.push(c);
+    }
+
+    let mut responses = Vec::new();
+    for (s, n) in signers.iter().zip(nonces.iter()) {
+        responses.push(sign(s, n, &message, &commitments, &group).unwrap());
+    }
+
+    let sig = aggregate(&message, &commitments, &responses).unwrap();
+
+    // the aggregated signature verifies under the BIP340 verifier.
+    let pubkey = group.to_public_key().unwrap();
+    assert!(pubkey.verify_schnorr(&message, &sig).unwrap());
+
+    // a different message must not verify.
+    let other = hash::sha256(b"not the signed message");
+    assert!(!pubkey.verify_schnorr(&other, &sig).unwrap());
+}
+
+/// Duplicate identifiers in the commitment list must fail cleanly.
+#[test]
+fn test_frost_duplicate_commitment() {
+    let group = keygen(2, 3).unwrap();
+    let message = hash::sha256(b"dup");
+
+    let (n0, c0) = commit(group.shares[0].id).unwrap();
+    let (_n1, mut c1) = commit(group.shares[1].id).unwrap();
+    c1.id = c0.id; // duplicate
+
+    let res = sign(&group.shares[0], &n0, &message, &[c0, c1], &group);
+    assert!(res.is_err());
+}
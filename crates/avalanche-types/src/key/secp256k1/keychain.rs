@@ -1,6 +1,10 @@
 use std::collections::HashMap;
 
-use crate::{ids::short, key};
+use crate::{
+    errors::{Error, Result},
+    ids::short,
+    key, txs,
+};
 use serde::{Deserialize, Serialize};
 
 /// Support multiple keys as a chain.
@@ -91,4 +95,61 @@ where
             keys,
         ))
     }
+
+    /// Signs "tx" by resolving the signers for each of its transferable inputs
+    /// from "utxos" -- the UTXOs the tx consumes -- instead of requiring the
+    /// caller to assemble the positional `Vec<Vec<T>>` expected by
+    /// [`txs::Signable::sign`] by hand.
+    ///
+    /// For each input (in wire order) the referenced UTXO's `OutputOwners` are
+    /// matched against the keychain via [`Keychain::match_threshold`] at "time",
+    /// yielding the keys in `sig_indices` order; the per-input key sets become
+    /// the credential list. Returns an error if a consumed UTXO is missing from
+    /// "utxos" or the keychain cannot meet an owner's threshold.
+    /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/secp256k1fx#Keychain.Spend>
+    pub async fn sign<S>(&self, tx: &mut S, utxos: &[txs::utxo::Utxo], time: u64) -> Result<()>
+    where
+        S: txs::Signable,
+    {
+        let mut signers: Vec<Vec<T>> = Vec::new();
+        if let Some(transferable_inputs) = &tx.base_tx().transferable_inputs {
+            for transferable_input in transferable_inputs.iter() {
+                let utxo = utxos
+                    .iter()
+                    .find(|utxo| utxo.utxo_id == transferable_input.utxo_id)
+                    .ok_or(Error::Other {
+                        message: format!(
+                            "no UTXO provided for consumed input '{}'",
+                            transferable_input.utxo_id.tx_id
+                        ),
+                        retryable: false,
+                    })?;
+
+                let owners = if let Some(out) = &utxo.transfer_output {
+                    &out.output_owners
+                } else if let Some(lock) = &utxo.stakeable_lock_out {
+                    &lock.transfer_output.output_owners
+                } else {
+                    return Err(Error::Other {
+                        message: format!(
+                            "UTXO '{}' has no transfer output to spend",
+                            transferable_input.utxo_id.tx_id
+                        ),
+                        retryable: false,
+                    });
+                };
+
+                let (_, keys) = self.match_threshold(owners, time).ok_or(Error::Other {
+                    message: format!(
+                        "keychain cannot meet threshold {} for input '{}'",
+                        owners.threshold, transferable_input.utxo_id.tx_id
+                    ),
+                    retryable: false,
+                })?;
+                signers.push(keys);
+            }
+        }
+
+        tx.sign(signers).await
+    }
 }
@@ -0,0 +1,407 @@
+//! Hardware-wallet (Ledger) signing backend for secp256k1.
+//!
+//! Implements [`key::secp256k1::SignOnly`] by talking APDUs to the Avalanche
+//! Ledger app instead of holding a raw private key, so signers (e.g. subnet
+//! validators) can be added without exposing key material. Because constrained
+//! devices must display what they sign, the signer is handed not only the
+//! 32-byte digest but also the `tx_bytes_with_no_signature` and the derived
+//! summary fields (node id / start / end / weight / subnet id) so the device can
+//! present them for on-device confirmation before returning the recoverable
+//! signature.
+//!
+//! The key type is generic over a [`Transport`], so the wire protocol can be
+//! exercised against a mock without a physical device, and it plugs into the
+//! existing `signers: Vec<Vec<T>>` multi-sig loop unchanged.
+//! ref. <https://github.com/ava-labs/ledger-avalanche>
+
+use std::sync::Mutex;
+
+use async_trait::async_trait;
+use k256::ecdsa::SigningKey;
+
+use crate::{
+    errors::{Error, Result},
+    ids::{node, Id},
+    key,
+};
+
+/// APDU class byte for the Avalanche Ledger app.
+const CLA: u8 = 0x80;
+/// Instruction that returns the secp256k1 public key for a derivation path.
+const INS_GET_PUBLIC_KEY: u8 = 0x01;
+/// Instruction that asks the device to display and confirm the transaction.
+const INS_SIGN_TX: u8 = 0x02;
+/// Instruction that returns the recoverable signature over a digest.
+const INS_SIGN_HASH: u8 = 0x04;
+/// Trailing status word returned by the device on success.
+const SW_OK: u16 = 0x9000;
+/// The device is locked (PIN not entered) -- the caller can retry once unlocked.
+const SW_DEVICE_LOCKED: u16 = 0x5515;
+/// Security status not satisfied; on Ledger this is the app-not-open / locked
+/// state, which clears once the operator opens the Avalanche app.
+const SW_SECURITY_NOT_SATISFIED: u16 = 0x6982;
+/// The operator rejected the prompt on the device -- a terminal decision.
+const SW_USER_REJECTED: u16 = 0x6985;
+
+/// Bidirectional APDU transport to a connected Ledger device.
+#[async_trait]
+pub trait Transport {
+    /// Sends a single APDU and returns the raw device response (payload plus
+    /// the 2-byte status word).
+    async fn exchange(&self, apdu: &[u8]) -> Result<Vec<u8>>;
+}
+
+/// Human-readable summary of a `platformvm.AddSubnetValidatorTx` shown on the
+/// device so the operator can confirm exactly what is being signed.
+#[derive(Debug, Clone)]
+pub struct ConfirmationSummary {
+    pub node_id: node::Id,
+    pub start: u64,
+    pub end: u64,
+    pub weight: u64,
+    pub subnet_id: Id,
+}
+
+/// The context a device needs to confirm a signature: the unsigned tx bytes it
+/// re-parses for display plus the pre-derived [`ConfirmationSummary`].
+#[derive(Debug, Clone)]
+pub struct SignRequest {
+    pub tx_bytes_with_no_signature: Vec<u8>,
+    pub summary: ConfirmationSummary,
+}
+
+/// A secp256k1 signer backed by a Ledger device. The private key never leaves
+/// the device; signing happens through [`Transport::exchange`].
+pub struct Key<T: Transport> {
+    /// APDU transport to the device.
+    pub transport: T,
+    /// BIP-32 derivation path of the signing key (e.g. m/44'/9000'/0'/0/0).
+    pub derivation_path: Vec<u32>,
+    /// Cached public key for address derivation, fetched from the device.
+    pub public_key: Option<key::secp256k1::public_key::Key>,
+
+    /// Confirmation context sent before every `sign_digest` call until it is
+    /// replaced, if any.
+    pending: Mutex<Option<SignRequest>>,
+}
+
+impl<T: Transport> Key<T> {
+    /// Creates a new Ledger-backed key over the given transport and path.
+    pub fn new(
+        transport: T,
+        derivation_path: Vec<u32>,
+        public_key: Option<key::secp256k1::public_key::Key>,
+    ) -> Self {
+        Self {
+            transport,
+            derivation_path,
+            public_key,
+            pending: Mutex::new(None),
+        }
+    }
+
+    /// Stages the transaction context the device displays before each
+    /// `sign_digest`; it stays staged until the next `prepare`. Callers set
+    /// this right before running the multi-sig loop.
+    pub fn prepare(&self, request: SignRequest) {
+        *self.pending.lock().unwrap() = Some(request);
+    }
+
+    /// Encodes the BIP-32 derivation path as a length-prefixed big-endian list.
+    fn encode_path(&self) -> Vec<u8> {
+        let mut b = Vec::with_capacity(1 + self.derivation_path.len() * 4);
+        b.push(self.derivation_path.len() as u8);
+        for component in &self.derivation_path {
+            b.extend_from_slice(&component.to_be_bytes());
+        }
+        b
+    }
+
+    /// Builds the "display and confirm" APDU carrying the unsigned tx bytes the
+    /// device re-parses to render the [`ConfirmationSummary`].
+    fn build_confirm_apdu(&self, request: &SignRequest) -> Vec<u8> {
+        let mut data = self.encode_path();
+        data.extend_from_slice(&request.tx_bytes_with_no_signature);
+        apdu(INS_SIGN_TX, &data)
+    }
+
+    /// Builds the APDU that signs a 32-byte digest.
+    fn build_sign_apdu(&self, digest: &[u8]) -> Vec<u8> {
+        let mut data = self.encode_path();
+        data.extend_from_slice(digest);
+        apdu(INS_SIGN_HASH, &data)
+    }
+
+    /// Asks the device for the secp256k1 public key at the configured path,
+    /// caches it on `self`, and returns it. Subsequent address lookups reuse the
+    /// cache so address derivation never requires a second device round-trip.
+    pub async fn load_public_key(&mut self) -> Result<key::secp256k1::public_key::Key> {
+        let resp = self
+            .transport
+            .exchange(&apdu(INS_GET_PUBLIC_KEY, &self.encode_path()))
+            .await?;
+        let payload = split_response(&resp)?;
+        // the device returns the SEC1 uncompressed point first
+        let uncompressed_len = key::secp256k1::public_key::UNCOMPRESSED_LEN;
+        if payload.len() < uncompressed_len {
+            return Err(Error::Other {
+                message: format!(
+                    "ledger public-key payload too short ({} bytes)",
+                    payload.len()
+                ),
+                retryable: false,
+            });
+        }
+        let pubkey =
+            key::secp256k1::public_key::Key::from_sec1_bytes(&payload[..uncompressed_len])?;
+        self.public_key = Some(pubkey);
+        Ok(pubkey)
+    }
+
+    /// Returns the cached public key, loading it from the device on first use.
+    pub async fn public_key(&mut self) -> Result<key::secp256k1::public_key::Key> {
+        match self.public_key {
+            Some(pubkey) => Ok(pubkey),
+            None => self.load_public_key().await,
+        }
+    }
+
+    /// Bech32 address for the given chain alias (e.g. `"X"` or `"C"`) on
+    /// `network_id`, loading the public key from the device if not yet cached.
+    pub async fn hrp_address(&mut self, network_id: u32, chain_id_alias: &str) -> Result<String> {
+        let pubkey = self.public_key().await?;
+        pubkey.to_hrp_address(network_id, chain_id_alias)
+    }
+
+    /// Short (20-byte) address derived from the device's public key, loading
+    /// it from the device if not yet cached.
+    pub async fn short_address(&mut self) -> Result<crate::ids::short::Id> {
+        let pubkey = self.public_key().await?;
+        pubkey.to_short_id()
+    }
+}
+
+/// Frames an APDU command: `CLA | INS | P1 | P2 | Lc | data`.
+fn apdu(ins: u8, data: &[u8]) -> Vec<u8> {
+    let mut b = Vec::with_capacity(5 + data.len());
+    b.push(CLA);
+    b.push(ins);
+    b.push(0x00); // P1
+    b.push(0x00); // P2
+    b.push(data.len() as u8);
+    b.extend_from_slice(data);
+    b
+}
+
+/// Splits a device response into its payload and status word, mapping a non-OK
+/// status to an [`Error`]. Transient states the operator can clear -- a locked
+/// device or the app not being open -- are surfaced as `retryable`, while a
+/// user rejection and every other status are terminal.
+fn split_response(resp: &[u8]) -> Result<&[u8]> {
+    if resp.len() < 2 {
+        return Err(Error::Other {
+            message: format!("ledger response too short ({} bytes)", resp.len()),
+            retryable: false,
+        });
+    }
+    let (payload, sw) = resp.split_at(resp.len() - 2);
+    let status = u16::from_be_bytes([sw[0], sw[1]]);
+    match status {
+        SW_OK => Ok(payload),
+        SW_DEVICE_LOCKED | SW_SECURITY_NOT_SATISFIED => Err(Error::Other {
+            message: format!("ledger is locked or the Avalanche app is not open ({status:#06x})"),
+            retryable: true,
+        }),
+        SW_USER_REJECTED => Err(Error::Other {
+            message: "signature rejected on device".to_string(),
+            retryable: false,
+        }),
+        _ => Err(Error::Other {
+            message: format!("ledger returned status {status:#06x}"),
+            retryable: false,
+        }),
+    }
+}
+
+/// Parses a device response into the 65-byte recoverable signature, rejecting a
+/// non-OK status word or a short payload rather than returning garbage.
+fn parse_signature(resp: &[u8]) -> Result<[u8; key::secp256k1::signature::LEN]> {
+    let payload = split_response(resp)?;
+    if payload.len() < key::secp256k1::signature::LEN {
+        return Err(Error::Other {
+            message: format!(
+                "ledger signature payload too short ({} bytes)",
+                payload.len()
+            ),
+            retryable: false,
+        });
+    }
+    let sig = &payload[payload.len() - key::secp256k1::signature::LEN..];
+    let mut b = [0u8; key::secp256k1::signature::LEN];
+    b.copy_from_slice(sig);
+    Ok(b)
+}
+
+/// A [`Transport`] backed by the Ledger USB-HID interface, gated behind the
+/// `ledger` feature so a build that only uses software or KMS keys does not pull
+/// in `hidapi`. Open it with [`HidTransport::open`] and hand it to
+/// [`Key::new`].
+#[cfg(feature = "ledger")]
+pub struct HidTransport {
+    inner: ledger_transport_hid::TransportNativeHID,
+}
+
+#[cfg(feature = "ledger")]
+impl HidTransport {
+    /// Opens the first connected Ledger device over USB HID. A device that is
+    /// absent or already claimed by another process is reported as `retryable`
+    /// so a signing loop can back off and wait for it.
+    pub fn open() -> Result<Self> {
+        let api = ledger_transport_hid::hidapi::HidApi::new().map_err(|e| Error::Other {
+            message: format!("failed to init hidapi: {e}"),
+            retryable: true,
+        })?;
+        let inner =
+            ledger_transport_hid::TransportNativeHID::new(&api).map_err(|e| Error::Other {
+                message: format!("failed to open ledger device: {e}"),
+                retryable: true,
+            })?;
+        Ok(Self { inner })
+    }
+}
+
+#[cfg(feature = "ledger")]
+#[async_trait]
+impl Transport for HidTransport {
+    async fn exchange(&self, apdu: &[u8]) -> Result<Vec<u8>> {
+        // the raw APDU is framed as CLA | INS | P1 | P2 | Lc | data
+        if apdu.len() < 5 {
+            return Err(Error::Other {
+                message: format!("malformed apdu ({} bytes)", apdu.len()),
+                retryable: false,
+            });
+        }
+        let command = ledger_apdu::APDUCommand {
+            cla: apdu[0],
+            ins: apdu[1],
+            p1: apdu[2],
+            p2: apdu[3],
+            data: apdu[5..].to_vec(),
+        };
+        let answer = self.inner.exchange(&command).map_err(|e| Error::Other {
+            message: format!("ledger apdu exchange failed: {e}"),
+            retryable: true,
+        })?;
+        // re-append the status word so [`split_response`] can classify it
+        let mut resp = answer.apdu_data().to_vec();
+        resp.extend_from_slice(&answer.retcode().to_be_bytes());
+        Ok(resp)
+    }
+}
+
+#[async_trait]
+impl<T: Transport + Send + Sync> key::secp256k1::SignOnly for Key<T> {
+    fn signing_key(&self) -> Result<SigningKey> {
+        unimplemented!("signing key not implemented for Ledger")
+    }
+
+    async fn sign_digest(&self, msg: &[u8]) -> Result<[u8; key::secp256k1::signature::LEN]> {
+        // drop the lock before awaiting so the guard is never held across .await
+        let pending = self.pending.lock().unwrap().clone();
+        if let Some(request) = pending {
+            // let the device render and confirm the tx before it signs the hash
+            let confirm = self.build_confirm_apdu(&request);
+            self.transport.exchange(&confirm).await?;
+        }
+
+        let resp = self.transport.exchange(&self.build_sign_apdu(msg)).await?;
+        parse_signature(&resp)
+    }
+}
+
+/// Transport that echoes a fixed 65-byte signature with an OK status word and
+/// records the APDUs it was handed, for exercising the signer without a device.
+#[cfg(test)]
+struct MockTransport {
+    signature: [u8; key::secp256k1::signature::LEN],
+    seen: Mutex<Vec<Vec<u8>>>,
+}
+
+#[cfg(test)]
+#[async_trait]
+impl Transport for MockTransport {
+    async fn exchange(&self, apdu: &[u8]) -> Result<Vec<u8>> {
+        self.seen.lock().unwrap().push(apdu.to_vec());
+        let mut resp = self.signature.to_vec();
+        resp.extend_from_slice(&SW_OK.to_be_bytes());
+        Ok(resp)
+    }
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- key::secp256k1::ledger::test_sign_digest_confirms_then_signs --exact --show-output
+#[tokio::test]
+async fn test_sign_digest_confirms_then_signs() {
+    let transport = MockTransport {
+        signature: [7u8; key::secp256k1::signature::LEN],
+        seen: Mutex::new(Vec::new()),
+    };
+    let key = Key::new(
+        transport,
+        vec![44 | 0x8000_0000, 9000 | 0x8000_0000, 0x8000_0000, 0, 0],
+        None,
+    );
+    key.prepare(SignRequest {
+        tx_bytes_with_no_signature: vec![0xde, 0xad, 0xbe, 0xef],
+        summary: ConfirmationSummary {
+            node_id: node::Id::empty(),
+            start: 1,
+            end: 2,
+            weight: 3,
+            subnet_id: Id::empty(),
+        },
+    });
+
+    let sig = key.sign_digest(&[0u8; 32]).await.unwrap();
+    assert_eq!(sig, [7u8; key::secp256k1::signature::LEN]);
+
+    // first APDU is the display/confirm, second is the hash signing
+    let seen = key.transport.seen.lock().unwrap();
+    assert_eq!(seen.len(), 2);
+    assert_eq!(seen[0][1], INS_SIGN_TX);
+    assert_eq!(seen[1][1], INS_SIGN_HASH);
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- key::secp256k1::ledger::test_parse_signature_rejects_bad_status --exact --show-output
+#[test]
+fn test_parse_signature_rejects_bad_status() {
+    let mut resp = vec![0u8; key::secp256k1::signature::LEN];
+    resp.extend_from_slice(&0x6a80u16.to_be_bytes());
+    assert!(parse_signature(&resp).is_err());
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- key::secp256k1::ledger::test_status_word_retryability --exact --show-output
+#[test]
+fn test_status_word_retryability() {
+    let with_status = |sw: u16| {
+        let mut resp = vec![0u8; 4];
+        resp.extend_from_slice(&sw.to_be_bytes());
+        split_response(&resp).map(|p| p.to_vec())
+    };
+
+    // a locked device / closed app is transient -- the caller may retry
+    for sw in [SW_DEVICE_LOCKED, SW_SECURITY_NOT_SATISFIED] {
+        match with_status(sw) {
+            Err(Error::Other { retryable, .. }) => assert!(retryable, "{sw:#06x} should retry"),
+            other => panic!("unexpected {other:?}"),
+        }
+    }
+
+    // a user rejection is terminal
+    match with_status(SW_USER_REJECTED) {
+        Err(Error::Other { retryable, .. }) => assert!(!retryable),
+        other => panic!("unexpected {other:?}"),
+    }
+
+    // OK returns the payload
+    assert_eq!(with_status(SW_OK).unwrap(), vec![0u8; 4]);
+}
@@ -12,8 +12,15 @@ use crate::{
 use async_trait::async_trait;
 use k256::{
     ecdsa::{hazmat::SignPrimitive, SigningKey},
-    elliptic_curve::generic_array::GenericArray,
-    SecretKey,
+    elliptic_curve::{
+        bigint::U256,
+        generic_array::GenericArray,
+        ops::Reduce,
+        point::{AffineCoordinates, DecompressPoint},
+        subtle::Choice,
+        PrimeField,
+    },
+    AffinePoint, FieldBytes, ProjectivePoint, Scalar, SecretKey,
 };
 use lazy_static::lazy_static;
 use rand::{seq::SliceRandom, thread_rng};
@@ -247,6 +254,381 @@ impl Key {
         let kb = self.to_bytes();
         ethers_core::k256::ecdsa::SigningKey::from_bytes(GenericArray::from_slice(&kb)).unwrap()
     }
+
+    /// Produces a 64-byte BIP340 x-only Schnorr signature over the 32-byte message.
+    /// The secret scalar is conditionally negated so the x-only public key always
+    /// has an even y-coordinate, matching the BIP340 convention.
+    /// ref. <https://github.com/bitcoin/bips/blob/master/bip-0340.mediawiki>
+    pub fn sign_schnorr_digest(&self, digest: &[u8]) -> Result<[u8; SCHNORR_SIG_LEN]> {
+        if digest.len() != hash::SHA256_OUTPUT_LEN {
+            return Err(Error::Other {
+                message: format!(
+                    "sign_schnorr_digest only takes {}-byte, got {}-byte",
+                    hash::SHA256_OUTPUT_LEN,
+                    digest.len()
+                ),
+                retryable: false,
+            });
+        }
+
+        // d' = secret scalar, P = d'*G; use d = d' or n-d' so that P has even y.
+        let d_prime: Scalar = *self.0 .0.to_nonzero_scalar();
+        let point = ProjectivePoint::GENERATOR * d_prime;
+        let px = x_only_bytes(&point);
+        let d = if is_y_odd(&point) { -d_prime } else { d_prime };
+
+        // nonce: k0 = H_nonce(H_aux(aux) XOR d || P.x || m); aux_rand is all-zero,
+        // which BIP340 permits when no fresh randomness is available.
+        let mut t = scalar_to_bytes(&d);
+        let aux = tagged_hash(TAG_AUX, &[&[0u8; 32]]);
+        for (ti, ai) in t.iter_mut().zip(aux.iter()) {
+            *ti ^= *ai;
+        }
+        let rand = tagged_hash(TAG_NONCE, &[&t, &px, digest]);
+        let k0 = reduce_scalar(&rand);
+        if bool::from(k0.is_zero()) {
+            return Err(Error::Other {
+                message: "derived zero Schnorr nonce".to_string(),
+                retryable: false,
+            });
+        }
+
+        // R = k0*G; negate k if R has odd y.
+        let r_point = ProjectivePoint::GENERATOR * k0;
+        let rx = x_only_bytes(&r_point);
+        let k = if is_y_odd(&r_point) { -k0 } else { k0 };
+
+        // e = H_challenge(R.x || P.x || m); s = k + e*d.
+        let e = reduce_scalar(&tagged_hash(TAG_CHALLENGE, &[&rx, &px, digest]));
+        let s = k + e * d;
+
+        let mut sig = [0u8; SCHNORR_SIG_LEN];
+        sig[..32].copy_from_slice(&rx);
+        sig[32..].copy_from_slice(&scalar_to_bytes(&s));
+        Ok(sig)
+    }
+
+    /// Signs EIP-712 typed structured data and returns a recoverable signature
+    /// whose 65-byte form is `r || s || recovery id` (0 or 1, not the
+    /// Ethereum 27/28 `v`). The signing digest is
+    /// `keccak256(0x1901 || domainSeparator || hashStruct(message))`, signed
+    /// with the ECDSA path so the resulting signature can authorize
+    /// transactions and off-chain messages for C-Chain / EVM subnets.
+    /// ref. <https://eips.ethereum.org/EIPS/eip-712>
+    pub fn sign_typed_data(&self, data: &Eip712TypedData) -> Result<Sig> {
+        let digest = data.signing_hash()?;
+        self.sign_digest(&digest)
+    }
+}
+
+/// The size (in bytes) of a BIP340 Schnorr signature.
+pub const SCHNORR_SIG_LEN: usize = 64;
+
+const TAG_AUX: &[u8] = b"BIP0340/aux";
+const TAG_NONCE: &[u8] = b"BIP0340/nonce";
+const TAG_CHALLENGE: &[u8] = b"BIP0340/challenge";
+
+/// Computes the BIP340 tagged hash `SHA256(SHA256(tag) || SHA256(tag) || msg)`
+/// where `msg` is the concatenation of `parts`.
+pub(crate) fn tagged_hash(tag: &[u8], parts: &[&[u8]]) -> [u8; 32] {
+    let tag_hash = hash::sha256(tag);
+    let mut data = Vec::with_capacity(tag_hash.len() * 2 + parts.iter().map(|p| p.len()).sum::<usize>());
+    data.extend_from_slice(&tag_hash);
+    data.extend_from_slice(&tag_hash);
+    for p in parts {
+        data.extend_from_slice(p);
+    }
+    let out = hash::sha256(&data);
+    let mut b = [0u8; 32];
+    b.copy_from_slice(&out);
+    b
+}
+
+/// Reduces 32 big-endian bytes into a scalar modulo the curve order.
+pub(crate) fn reduce_scalar(b: &[u8; 32]) -> Scalar {
+    <Scalar as Reduce<U256>>::reduce(U256::from_be_slice(b))
+}
+
+/// Serializes a scalar to its 32-byte big-endian representation.
+pub(crate) fn scalar_to_bytes(s: &Scalar) -> [u8; 32] {
+    let fb: FieldBytes = s.to_bytes();
+    let mut b = [0u8; 32];
+    b.copy_from_slice(&fb);
+    b
+}
+
+/// Returns the x-only (32-byte) encoding of a point's affine x-coordinate.
+pub(crate) fn x_only_bytes(point: &ProjectivePoint) -> [u8; 32] {
+    let aff = point.to_affine();
+    let x = aff.x();
+    let mut b = [0u8; 32];
+    b.copy_from_slice(&x);
+    b
+}
+
+/// Whether a point's affine y-coordinate is odd.
+pub(crate) fn is_y_odd(point: &ProjectivePoint) -> bool {
+    bool::from(point.to_affine().y_is_odd())
+}
+
+/// Lifts an x-only coordinate to the point with even y, per BIP340 `lift_x`.
+/// Returns `None` when no curve point has the given x-coordinate.
+pub(crate) fn lift_x(x: &[u8; 32]) -> Option<ProjectivePoint> {
+    let fb = FieldBytes::clone_from_slice(x);
+    let aff = AffinePoint::decompress(&fb, Choice::from(0u8));
+    if bool::from(aff.is_some()) {
+        Some(ProjectivePoint::from(aff.unwrap()))
+    } else {
+        None
+    }
+}
+
+/// A named member of an EIP-712 struct type, e.g. `{ name: "wallet", type_name: "address" }`.
+/// ref. <https://eips.ethereum.org/EIPS/eip-712>
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Eip712Field {
+    pub name: String,
+    pub type_name: String,
+}
+
+impl Eip712Field {
+    pub fn new<S>(name: S, type_name: S) -> Self
+    where
+        S: Into<String>,
+    {
+        Self {
+            name: name.into(),
+            type_name: type_name.into(),
+        }
+    }
+}
+
+/// A concrete EIP-712 value. The variant carries the raw content; the declared
+/// field type (looked up in the type registry) drives how it is encoded into a
+/// 32-byte word during `hashStruct`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Eip712Value {
+    /// A referenced struct type; members are `(field name, value)` pairs in the
+    /// order the struct type declares them.
+    Struct(Vec<(String, Eip712Value)>),
+    /// A fixed- or dynamic-size array; elements share the base type.
+    Array(Vec<Eip712Value>),
+    /// A `uintN` / `intN` value, held as its big-endian 256-bit word.
+    Uint(primitive_types::U256),
+    /// A `bool` value.
+    Bool(bool),
+    /// An `address` value.
+    Address(primitive_types::H160),
+    /// A dynamic `bytes` value.
+    Bytes(Vec<u8>),
+    /// A fixed `bytesN` value; encoded left-aligned (high-order) in its word.
+    FixedBytes(Vec<u8>),
+    /// A dynamic `string` value.
+    String(String),
+}
+
+/// An EIP-712 typed-data payload: the type registry (keyed by type name, and
+/// always including `EIP712Domain`), the primary type being signed, the domain
+/// value, and the message value. Signing produces the digest
+/// `keccak256(0x1901 || domainSeparator || hashStruct(message))`.
+/// ref. <https://eips.ethereum.org/EIPS/eip-712>
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Eip712TypedData {
+    pub types: HashMap<String, Vec<Eip712Field>>,
+    pub primary_type: String,
+    pub domain: Eip712Value,
+    pub message: Eip712Value,
+}
+
+impl Eip712TypedData {
+    /// Computes the 32-byte signing digest `keccak256(0x1901 || domainSeparator
+    /// || hashStruct(primaryType, message))`.
+    pub fn signing_hash(&self) -> Result<[u8; 32]> {
+        let domain_separator = self.hash_struct("EIP712Domain", &self.domain)?;
+        let message_hash = self.hash_struct(&self.primary_type, &self.message)?;
+
+        let mut buf = Vec::with_capacity(2 + 32 + 32);
+        buf.extend_from_slice(&[0x19, 0x01]);
+        buf.extend_from_slice(&domain_separator);
+        buf.extend_from_slice(&message_hash);
+
+        Ok(hash::keccak256(&buf).to_fixed_bytes())
+    }
+
+    /// Recovers the signer's Ethereum address from a typed-data signature,
+    /// where `sig` is the 65-byte `r || s || recovery id` produced by
+    /// `Key::sign_typed_data`.
+    pub fn recover_eth_address(&self, sig: &[u8]) -> Result<String> {
+        let digest = self.signing_hash()?;
+        let pubkey = PublicKey::from_signature(&digest, sig)?;
+        Ok(pubkey.to_eth_address())
+    }
+
+    /// `hashStruct(s) = keccak256(typeHash(s) || encodeData(s))`.
+    fn hash_struct(&self, type_name: &str, value: &Eip712Value) -> Result<[u8; 32]> {
+        let members = match value {
+            Eip712Value::Struct(members) => members,
+            _ => {
+                return Err(Error::Other {
+                    message: format!("EIP-712 type '{}' expects a struct value", type_name),
+                    retryable: false,
+                });
+            }
+        };
+        let fields = self.types.get(type_name).ok_or_else(|| Error::Other {
+            message: format!("EIP-712 type '{}' not found in registry", type_name),
+            retryable: false,
+        })?;
+
+        let mut buf = Vec::with_capacity(32 + fields.len() * 32);
+        buf.extend_from_slice(&self.type_hash(type_name)?);
+        for field in fields {
+            let member = members
+                .iter()
+                .find(|(name, _)| name == &field.name)
+                .ok_or_else(|| Error::Other {
+                    message: format!(
+                        "EIP-712 struct '{}' is missing field '{}'",
+                        type_name, field.name
+                    ),
+                    retryable: false,
+                })?;
+            buf.extend_from_slice(&self.encode_value(&field.type_name, &member.1)?);
+        }
+        Ok(hash::keccak256(&buf).to_fixed_bytes())
+    }
+
+    /// `typeHash(s) = keccak256(encodeType(s))`.
+    fn type_hash(&self, type_name: &str) -> Result<[u8; 32]> {
+        Ok(hash::keccak256(self.encode_type(type_name)?.as_bytes()).to_fixed_bytes())
+    }
+
+    /// `encodeType` is the primary type's declaration followed by every
+    /// referenced struct type, sorted alphabetically.
+    fn encode_type(&self, primary_type: &str) -> Result<String> {
+        let mut deps = Vec::new();
+        self.collect_deps(primary_type, &mut deps)?;
+        deps.retain(|d| d != primary_type);
+        deps.sort();
+
+        let mut ordered = Vec::with_capacity(deps.len() + 1);
+        ordered.push(primary_type.to_string());
+        ordered.extend(deps);
+
+        let mut encoded = String::new();
+        for name in ordered {
+            let fields = self.types.get(&name).ok_or_else(|| Error::Other {
+                message: format!("EIP-712 type '{}' not found in registry", name),
+                retryable: false,
+            })?;
+            encoded.push_str(&name);
+            encoded.push('(');
+            for (i, field) in fields.iter().enumerate() {
+                if i > 0 {
+                    encoded.push(',');
+                }
+                encoded.push_str(&field.type_name);
+                encoded.push(' ');
+                encoded.push_str(&field.name);
+            }
+            encoded.push(')');
+        }
+        Ok(encoded)
+    }
+
+    /// Transitively collects the struct types referenced by `type_name`.
+    fn collect_deps(&self, type_name: &str, acc: &mut Vec<String>) -> Result<()> {
+        if acc.iter().any(|d| d == type_name) {
+            return Ok(());
+        }
+        let fields = match self.types.get(type_name) {
+            Some(fields) => fields,
+            None => return Ok(()),
+        };
+        acc.push(type_name.to_string());
+        for field in fields {
+            let base = base_type(&field.type_name);
+            if self.types.contains_key(base) {
+                self.collect_deps(base, acc)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Encodes a single value into its 32-byte `encodeData` word (or, for
+    /// structs/arrays/dynamic types, the keccak256 of the encoded content).
+    fn encode_value(&self, type_name: &str, value: &Eip712Value) -> Result<[u8; 32]> {
+        // Array: keccak256 of the concatenated element encodings.
+        if type_name.ends_with(']') {
+            let base = base_type(type_name);
+            let elems = match value {
+                Eip712Value::Array(elems) => elems,
+                _ => {
+                    return Err(Error::Other {
+                        message: format!("EIP-712 type '{}' expects an array value", type_name),
+                        retryable: false,
+                    });
+                }
+            };
+            let mut buf = Vec::with_capacity(elems.len() * 32);
+            for elem in elems {
+                buf.extend_from_slice(&self.encode_value(base, elem)?);
+            }
+            return Ok(hash::keccak256(&buf).to_fixed_bytes());
+        }
+
+        // Referenced struct type: recurse via hashStruct.
+        if self.types.contains_key(type_name) {
+            return self.hash_struct(type_name, value);
+        }
+
+        let mut word = [0u8; 32];
+        match (type_name, value) {
+            ("string", Eip712Value::String(s)) => {
+                return Ok(hash::keccak256(s.as_bytes()).to_fixed_bytes());
+            }
+            ("bytes", Eip712Value::Bytes(b)) => {
+                return Ok(hash::keccak256(b).to_fixed_bytes());
+            }
+            ("bool", Eip712Value::Bool(b)) => {
+                word[31] = u8::from(*b);
+            }
+            ("address", Eip712Value::Address(a)) => {
+                word[12..].copy_from_slice(a.as_bytes());
+            }
+            (t, Eip712Value::Uint(v)) if t.starts_with("uint") || t.starts_with("int") => {
+                v.to_big_endian(&mut word);
+            }
+            (t, Eip712Value::FixedBytes(b)) if t.starts_with("bytes") => {
+                if b.len() > 32 {
+                    return Err(Error::Other {
+                        message: format!("EIP-712 bytesN value too long: {}", b.len()),
+                        retryable: false,
+                    });
+                }
+                word[..b.len()].copy_from_slice(b);
+            }
+            _ => {
+                return Err(Error::Other {
+                    message: format!(
+                        "EIP-712 value does not match declared type '{}'",
+                        type_name
+                    ),
+                    retryable: false,
+                });
+            }
+        }
+        Ok(word)
+    }
+}
+
+/// Strips the array suffix (`[]` or `[N]`) from an EIP-712 type name, returning
+/// the element type; non-array names are returned unchanged.
+fn base_type(type_name: &str) -> &str {
+    match type_name.rfind('[') {
+        Some(i) => &type_name[..i],
+        None => type_name,
+    }
 }
 
 impl From<&SecretKey> for Key {
@@ -354,6 +736,135 @@ fn test_private_key() {
     assert_eq!(pk3, pk4);
 }
 
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- key::secp256k1::private_key::test_schnorr --exact --show-output
+#[test]
+fn test_schnorr() {
+    let _ = env_logger::builder()
+        .filter_level(log::LevelFilter::Info)
+        .is_test(true)
+        .try_init();
+
+    let pk = Key::generate().unwrap();
+    let pubkey = pk.to_public_key();
+
+    let msg: Vec<u8> = random_manager::secure_bytes(100).unwrap();
+    let hashed = hash::sha256(&msg);
+
+    let sig = pk.sign_schnorr_digest(&hashed).unwrap();
+    assert_eq!(sig.len(), SCHNORR_SIG_LEN);
+
+    // valid signature verifies
+    assert!(pubkey.verify_schnorr(&hashed, &sig).unwrap());
+
+    // a different message must not verify
+    let other = hash::sha256(b"different message");
+    assert!(!pubkey.verify_schnorr(&other, &sig).unwrap());
+
+    // a tampered signature must not verify
+    let mut bad = sig;
+    bad[0] ^= 0x01;
+    assert!(!pubkey.verify_schnorr(&hashed, &bad).unwrap());
+
+    // a different key must not verify
+    let other_pubkey = Key::generate().unwrap().to_public_key();
+    assert!(!other_pubkey.verify_schnorr(&hashed, &sig).unwrap());
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- key::secp256k1::private_key::test_eip712 --exact --show-output
+#[test]
+fn test_eip712() {
+    use primitive_types::{H160, U256};
+    use std::str::FromStr;
+
+    let _ = env_logger::builder()
+        .filter_level(log::LevelFilter::Info)
+        .is_test(true)
+        .try_init();
+
+    // The canonical "Mail" example from the EIP-712 specification.
+    let mut types = HashMap::new();
+    types.insert(
+        "EIP712Domain".to_string(),
+        vec![
+            Eip712Field::new("name", "string"),
+            Eip712Field::new("version", "string"),
+            Eip712Field::new("chainId", "uint256"),
+            Eip712Field::new("verifyingContract", "address"),
+        ],
+    );
+    types.insert(
+        "Person".to_string(),
+        vec![
+            Eip712Field::new("name", "string"),
+            Eip712Field::new("wallet", "address"),
+        ],
+    );
+    types.insert(
+        "Mail".to_string(),
+        vec![
+            Eip712Field::new("from", "Person"),
+            Eip712Field::new("to", "Person"),
+            Eip712Field::new("contents", "string"),
+        ],
+    );
+
+    let domain = Eip712Value::Struct(vec![
+        ("name".to_string(), Eip712Value::String("Ether Mail".to_string())),
+        ("version".to_string(), Eip712Value::String("1".to_string())),
+        ("chainId".to_string(), Eip712Value::Uint(U256::one())),
+        (
+            "verifyingContract".to_string(),
+            Eip712Value::Address(
+                H160::from_str("0xCcCCccccCCCCcCCCCCCcCcCccCcCCCcCcccccccC").unwrap(),
+            ),
+        ),
+    ]);
+
+    let person = |name: &str, wallet: &str| {
+        Eip712Value::Struct(vec![
+            ("name".to_string(), Eip712Value::String(name.to_string())),
+            (
+                "wallet".to_string(),
+                Eip712Value::Address(H160::from_str(wallet).unwrap()),
+            ),
+        ])
+    };
+    let message = Eip712Value::Struct(vec![
+        (
+            "from".to_string(),
+            person("Cow", "0xCD2a3d9F938E13CD947Ec05AbC7FE734Df8DD826"),
+        ),
+        (
+            "to".to_string(),
+            person("Bob", "0xbBbBBBBbbBBBbbbBbbBbbbbBBbBbbbbBbBbbBBbB"),
+        ),
+        (
+            "contents".to_string(),
+            Eip712Value::String("Hello, Bob!".to_string()),
+        ),
+    ]);
+
+    let data = Eip712TypedData {
+        types,
+        primary_type: "Mail".to_string(),
+        domain,
+        message,
+    };
+
+    // Known digest for the specification's "Mail" example.
+    let digest = data.signing_hash().unwrap();
+    assert_eq!(
+        hex::encode(digest),
+        "be609aee343fb3c4b28e1df9e632fca64fcfaede20f02e86244efddf30957bd2"
+    );
+
+    // Signing then recovering must yield the signer's own eth address.
+    let pk = Key::generate().unwrap();
+    let sig = pk.sign_typed_data(&data).unwrap();
+    let recovered = data.recover_eth_address(&sig.to_bytes()).unwrap();
+    assert_eq!(recovered, pk.to_public_key().to_eth_address());
+}
+
 /// Loads keys from texts, assuming each key is line-separated.
 /// Set "permute_keys" true to permute the key order from the contents "d".
 pub fn load_cb58_keys(d: &[u8], permute_keys: bool) -> Result<Vec<Key>> {
@@ -10,8 +10,9 @@ use crate::{
 };
 use k256::{
     ecdsa::{signature::hazmat::PrehashVerifier, VerifyingKey},
+    elliptic_curve::{group::Group, point::AffineCoordinates, PrimeField},
     pkcs8::DecodePublicKey,
-    PublicKey,
+    FieldBytes, ProjectivePoint, PublicKey, Scalar,
 };
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
@@ -147,6 +148,72 @@ impl Key {
         address::h160_to_eth_address(&self.to_h160(), None)
     }
 
+    /// Returns the x-only (32-byte) BIP340 encoding of this public key.
+    pub fn to_x_only_bytes(&self) -> [u8; 32] {
+        let aff = self.0.as_affine();
+        let x = aff.x();
+        let mut b = [0u8; 32];
+        b.copy_from_slice(&x);
+        b
+    }
+
+    /// Verifies a 64-byte BIP340 x-only Schnorr signature over the 32-byte
+    /// message against this public key.
+    /// ref. <https://github.com/bitcoin/bips/blob/master/bip-0340.mediawiki>
+    pub fn verify_schnorr(&self, digest: &[u8], sig: &[u8]) -> Result<bool> {
+        use crate::key::secp256k1::private_key::{lift_x, reduce_scalar, tagged_hash};
+
+        if sig.len() != crate::key::secp256k1::private_key::SCHNORR_SIG_LEN {
+            return Err(Error::Other {
+                message: format!("invalid Schnorr signature length {}", sig.len()),
+                retryable: false,
+            });
+        }
+        if digest.len() != hash::SHA256_OUTPUT_LEN {
+            return Err(Error::Other {
+                message: format!(
+                    "verify_schnorr only takes {}-byte digest, got {}-byte",
+                    hash::SHA256_OUTPUT_LEN,
+                    digest.len()
+                ),
+                retryable: false,
+            });
+        }
+
+        let mut rx = [0u8; 32];
+        rx.copy_from_slice(&sig[..32]);
+        let mut sb = [0u8; 32];
+        sb.copy_from_slice(&sig[32..]);
+
+        // s must be a canonical scalar (< n); a non-canonical value is invalid.
+        let s = Scalar::from_repr(*FieldBytes::from_slice(&sb));
+        if bool::from(s.is_none()) {
+            return Ok(false);
+        }
+        let s = s.unwrap();
+
+        // P is the even-y lift of this key's x-only coordinate.
+        let point = match lift_x(&self.to_x_only_bytes()) {
+            Some(p) => p,
+            None => return Ok(false),
+        };
+
+        let e = reduce_scalar(&tagged_hash(b"BIP0340/challenge", &[&rx, &self.to_x_only_bytes(), digest]));
+
+        // R = s*G - e*P; valid iff R is not infinity, has even y, and R.x == r.
+        let r_point = ProjectivePoint::GENERATOR * s - point * e;
+        if bool::from(r_point.is_identity()) {
+            return Ok(false);
+        }
+        let aff = r_point.to_affine();
+        if bool::from(aff.y_is_odd()) {
+            return Ok(false);
+        }
+        let mut got = [0u8; 32];
+        got.copy_from_slice(&aff.x());
+        Ok(got == rx)
+    }
+
     pub fn to_hrp_address(&self, network_id: u32, chain_id_alias: &str) -> Result<String> {
         let hrp = match constants::NETWORK_ID_TO_HRP.get(&network_id) {
             Some(v) => v,
@@ -200,6 +200,33 @@ pub fn sig_from_digest_bytes_trial_recovery(
     })
 }
 
+/// Returns whether a 65-byte recoverable signature already has a low-S
+/// value, i.e. doesn't need [`normalize_s`] before AvalancheGo's
+/// signature-malleability check would accept it.
+pub fn is_canonical(sig: &[u8]) -> Result<bool> {
+    let parsed = Sig::from_bytes(sig)?;
+    Ok(parsed.0 .0.normalize_s().is_none())
+}
+
+/// Normalizes a 65-byte recoverable signature's "S" value to the lower half
+/// of the curve order, matching the malleability rule Bitcoin-derived
+/// secp256k1 code applies, and flips the recovery id's parity bit to match --
+/// negating "S" reflects the point "R" across the x-axis, which flips the
+/// parity of its "y" coordinate.
+/// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/utils/crypto#SECP256K1RSigLen>
+pub fn normalize_s(sig: &[u8]) -> Result<[u8; LEN]> {
+    let parsed = Sig::from_bytes(sig)?;
+    let (signature, recid) = parsed.0;
+    let (signature, recid) = match signature.normalize_s() {
+        Some(normalized) => (
+            normalized,
+            RecoveryId::new(!recid.is_y_odd(), recid.is_x_reduced()),
+        ),
+        None => (signature, recid),
+    };
+    Ok(Sig((signature, recid)).to_bytes())
+}
+
 /// Modify the v value of a signature to conform to eip155
 /// ref. <https://github.com/gakonst/ethers-rs/blob/master/ethers-signers/src/aws/utils.rs> "apply_eip155"
 /// ref. <https://github.com/gakonst/ethers-rs/pull/2300>
@@ -233,3 +260,30 @@ fn test_signature_serialization() {
     assert_eq!(pubkey.to_eth_address(), recovered_pubkey.to_eth_address());
     assert_eq!(pubkey, recovered_pubkey);
 }
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- key::secp256k1::signature::test_normalize_s_is_idempotent_and_recovers_same_key --exact --show-output
+#[test]
+fn test_normalize_s_is_idempotent_and_recovers_same_key() {
+    let pk = crate::key::secp256k1::private_key::Key::generate().unwrap();
+    let pubkey = pk.to_public_key();
+
+    let msg: Vec<u8> = random_manager::secure_bytes(100).unwrap();
+    let hashed = crate::hash::sha256(&msg);
+    let sig = pk.sign_digest(&hashed).unwrap();
+    let sig_bytes = sig.to_bytes();
+
+    let normalized = normalize_s(&sig_bytes).unwrap();
+    assert!(is_canonical(&normalized).unwrap());
+
+    // normalizing an already-canonical signature is a no-op
+    let re_normalized = normalize_s(&normalized).unwrap();
+    assert_eq!(normalized, re_normalized);
+
+    // the normalized signature still recovers to the original signer
+    let recovered = Sig::from_bytes(&normalized)
+        .unwrap()
+        .recover_public_key(&hashed)
+        .unwrap()
+        .0;
+    assert_eq!(pubkey, recovered);
+}
@@ -1,5 +1,6 @@
 //! secp256k1 credential types.
 pub mod transfer;
+pub mod verify;
 
 use std::cmp::Ordering;
 
@@ -34,6 +35,29 @@ impl Credential {
     pub fn type_id() -> u32 {
         *(codec::X_TYPES.get(&Self::type_name()).unwrap()) as u32
     }
+
+    /// Returns whether every signature already has a low-S value, i.e.
+    /// doesn't need normalizing before AvalancheGo's signature-malleability
+    /// check would accept it.
+    pub fn is_canonical(&self) -> crate::errors::Result<bool> {
+        for sig in &self.signatures {
+            if !super::signature::is_canonical(sig)? {
+                return Ok(false);
+            }
+        }
+        Ok(true)
+    }
+
+    /// Normalizes every signature's "S" value to the lower half of the curve
+    /// order in place, flipping the recovery id's parity bit for any
+    /// signature that had to be mutated.
+    /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/utils/crypto#SECP256K1RSigLen>
+    pub fn normalize_s(&mut self) -> crate::errors::Result<()> {
+        for sig in self.signatures.iter_mut() {
+            *sig = super::signature::normalize_s(sig)?.to_vec();
+        }
+        Ok(())
+    }
 }
 
 impl Ord for Credential {
@@ -246,6 +270,37 @@ impl OutputOwners {
     }
 }
 
+impl crate::packer::Packable for OutputOwners {
+    /// Packs the embedded "secp256k1fx.OutputOwners" body (no type-ID prefix;
+    /// callers that encode it as a standalone field add the prefix themselves).
+    fn pack(&self, packer: &crate::packer::Packer) -> crate::errors::Result<()> {
+        packer.pack_u64(self.locktime)?;
+        packer.pack_u32(self.threshold)?;
+        packer.pack_u32(self.addresses.len() as u32)?;
+        for addr in self.addresses.iter() {
+            packer.pack_bytes(addr.as_ref())?;
+        }
+        Ok(())
+    }
+}
+
+impl crate::packer::Unpackable for OutputOwners {
+    fn unpack(packer: &crate::packer::Packer) -> crate::errors::Result<Self> {
+        let locktime = packer.unpack_u64()?;
+        let threshold = packer.unpack_u32()?;
+        let addrs_len = packer.unpack_u32()? as usize;
+        let mut addresses = Vec::with_capacity(addrs_len);
+        for _ in 0..addrs_len {
+            addresses.push(short::Id::from_slice(&packer.unpack_bytes(short::LEN)?));
+        }
+        Ok(Self {
+            locktime,
+            threshold,
+            addresses,
+        })
+    }
+}
+
 impl Ord for OutputOwners {
     fn cmp(&self, other: &OutputOwners) -> Ordering {
         self.locktime
@@ -49,6 +49,33 @@ impl Output {
     }
 }
 
+impl crate::packer::Packable for Output {
+    /// Emits the "secp256k1fx.TransferOutput" type-ID prefix followed by its body.
+    fn pack(&self, packer: &crate::packer::Packer) -> crate::errors::Result<()> {
+        packer.pack_u32(Self::type_id())?;
+        packer.pack_u64(self.amount)?;
+        packer.pack(&self.output_owners)
+    }
+}
+
+impl crate::packer::Unpackable for Output {
+    fn unpack(packer: &crate::packer::Packer) -> crate::errors::Result<Self> {
+        let type_id = packer.unpack_u32()?;
+        if type_id != Self::type_id() {
+            return Err(crate::errors::Error::Other {
+                message: format!("unexpected type ID {type_id} for secp256k1fx.TransferOutput"),
+                retryable: false,
+            });
+        }
+        let amount = packer.unpack_u64()?;
+        let output_owners = packer.unpack()?;
+        Ok(Self {
+            amount,
+            output_owners,
+        })
+    }
+}
+
 /// RUST_LOG=debug cargo test --package avalanche-types --lib -- key::secp256k1::txs::transfer::test_transfer_output_custom_de_serializer --exact --show-output
 #[test]
 fn test_transfer_output_custom_de_serializer() {
@@ -279,6 +306,41 @@ impl Input {
     }
 }
 
+impl crate::packer::Packable for Input {
+    /// Emits the "secp256k1fx.TransferInput" type-ID prefix followed by its body.
+    fn pack(&self, packer: &crate::packer::Packer) -> crate::errors::Result<()> {
+        packer.pack_u32(Self::type_id())?;
+        packer.pack_u64(self.amount)?;
+        packer.pack_u32(self.sig_indices.len() as u32)?;
+        for idx in self.sig_indices.iter() {
+            packer.pack_u32(*idx)?;
+        }
+        Ok(())
+    }
+}
+
+impl crate::packer::Unpackable for Input {
+    fn unpack(packer: &crate::packer::Packer) -> crate::errors::Result<Self> {
+        let type_id = packer.unpack_u32()?;
+        if type_id != Self::type_id() {
+            return Err(crate::errors::Error::Other {
+                message: format!("unexpected type ID {type_id} for secp256k1fx.TransferInput"),
+                retryable: false,
+            });
+        }
+        let amount = packer.unpack_u64()?;
+        let sig_len = packer.unpack_u32()? as usize;
+        let mut sig_indices = Vec::with_capacity(sig_len);
+        for _ in 0..sig_len {
+            sig_indices.push(packer.unpack_u32()?);
+        }
+        Ok(Self {
+            amount,
+            sig_indices,
+        })
+    }
+}
+
 impl Ord for Input {
     fn cmp(&self, other: &Input) -> Ordering {
         self.amount
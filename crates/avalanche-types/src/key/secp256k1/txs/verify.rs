@@ -0,0 +1,147 @@
+//! Batch signature verification for signed transactions.
+//!
+//! `Signable::sign` produces `creds` but the crate never checks them back --
+//! this module is the inverse: given the unsigned-tx hash a tx was signed
+//! over and the addresses its inputs are allowed to spend, recover the signer
+//! from each `secp256k1fx.Credential` signature and confirm it matches.
+//! Validating a block means checking thousands of these, so [`verify_batch`]
+//! runs one transaction's checks (short-circuiting on the first failure) per
+//! rayon task, verifying many transactions concurrently.
+
+use rayon::prelude::*;
+
+use crate::{
+    errors::{Error, Result},
+    ids::short,
+    key::secp256k1::public_key,
+};
+
+/// A single signature check: does `signature` over `tx_bytes_hash` recover to
+/// `expected_address`? One of these exists per signature in a tx's credential
+/// section, keyed to the address its `sig_indices` entry refers to.
+#[derive(Debug, Clone)]
+pub struct VerifyItem {
+    /// sha256 of the unsigned tx bytes the signature was produced over.
+    pub tx_bytes_hash: Vec<u8>,
+    /// The 65-byte recoverable signature from the tx's `secp256k1fx.Credential`.
+    pub signature: Vec<u8>,
+    /// The address the referenced UTXO's output owners expect at this
+    /// `sig_indices` position.
+    pub expected_address: short::Id,
+}
+
+impl VerifyItem {
+    /// Recovers the signer from `signature` over `tx_bytes_hash` and checks it
+    /// matches `expected_address`.
+    pub fn verify(&self) -> Result<()> {
+        let recovered_key = public_key::Key::from_signature(&self.tx_bytes_hash, &self.signature)?;
+        let recovered_address = recovered_key.to_short_id()?;
+        if recovered_address != self.expected_address {
+            return Err(Error::Other {
+                message: format!(
+                    "signature recovers to address {recovered_address} but expected {}",
+                    self.expected_address
+                ),
+                retryable: false,
+            });
+        }
+        Ok(())
+    }
+}
+
+/// Verifies every [`VerifyItem`] belonging to one transaction's credential
+/// section, in order, stopping at the first failure -- mirroring how a node
+/// rejects a tx as soon as one signature doesn't check out.
+pub fn verify_tx(items: &[VerifyItem]) -> Result<()> {
+    for item in items {
+        item.verify()?;
+    }
+    Ok(())
+}
+
+/// Verifies many transactions' credential sections in parallel with rayon.
+/// Each inner `Vec<VerifyItem>` is one transaction's signatures, checked in
+/// order and short-circuited on the first failure; distinct transactions are
+/// farmed out across rayon's thread pool so validating a block of signatures
+/// doesn't serialize on a single core.
+pub fn verify_batch(txs: &[Vec<VerifyItem>]) -> Vec<Result<()>> {
+    txs.par_iter().map(|items| verify_tx(items)).collect()
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- key::secp256k1::txs::verify::test_verify_tx_accepts_matching_signature --exact --show-output
+#[test]
+fn test_verify_tx_accepts_matching_signature() {
+    use crate::key;
+
+    let test_key = key::secp256k1::private_key::Key::from_cb58(
+        "PrivateKey-24jUJ9vZexUM6expyMcT48LBx27k1m7xpraoV62oSQAHdziao5",
+    )
+    .expect("failed to load private key");
+    let expected_address = test_key
+        .to_public_key()
+        .to_short_id()
+        .expect("failed to_short_id");
+
+    let hash = [0x42u8; 32];
+    let sig = test_key.sign_digest(&hash).expect("failed to sign");
+
+    let item = VerifyItem {
+        tx_bytes_hash: hash.to_vec(),
+        signature: Vec::from(sig),
+        expected_address,
+    };
+    assert!(verify_tx(&[item]).is_ok());
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- key::secp256k1::txs::verify::test_verify_tx_rejects_wrong_address --exact --show-output
+#[test]
+fn test_verify_tx_rejects_wrong_address() {
+    use crate::key;
+
+    let test_key = key::secp256k1::private_key::Key::from_cb58(
+        "PrivateKey-24jUJ9vZexUM6expyMcT48LBx27k1m7xpraoV62oSQAHdziao5",
+    )
+    .expect("failed to load private key");
+
+    let hash = [0x42u8; 32];
+    let sig = test_key.sign_digest(&hash).expect("failed to sign");
+
+    let item = VerifyItem {
+        tx_bytes_hash: hash.to_vec(),
+        signature: Vec::from(sig),
+        expected_address: short::Id::from_slice(&[0xff; 20]),
+    };
+    assert!(verify_tx(&[item]).is_err());
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- key::secp256k1::txs::verify::test_verify_batch_short_circuits_per_tx --exact --show-output
+#[test]
+fn test_verify_batch_short_circuits_per_tx() {
+    use crate::key;
+
+    let test_key = key::secp256k1::private_key::Key::from_cb58(
+        "PrivateKey-24jUJ9vZexUM6expyMcT48LBx27k1m7xpraoV62oSQAHdziao5",
+    )
+    .expect("failed to load private key");
+    let expected_address = test_key
+        .to_public_key()
+        .to_short_id()
+        .expect("failed to_short_id");
+
+    let hash = [0x42u8; 32];
+    let sig = test_key.sign_digest(&hash).expect("failed to sign");
+    let good_item = VerifyItem {
+        tx_bytes_hash: hash.to_vec(),
+        signature: Vec::from(sig),
+        expected_address,
+    };
+    let bad_item = VerifyItem {
+        tx_bytes_hash: hash.to_vec(),
+        signature: Vec::from(sig),
+        expected_address: short::Id::from_slice(&[0xff; 20]),
+    };
+
+    let results = verify_batch(&[vec![good_item.clone()], vec![bad_item, good_item]]);
+    assert!(results[0].is_ok());
+    assert!(results[1].is_err());
+}
@@ -54,6 +54,10 @@ pub mod evm;
 #[cfg_attr(docsrs, doc(cfg(feature = "message")))]
 pub mod message;
 
+#[cfg(feature = "message")]
+#[cfg_attr(docsrs, doc(cfg(feature = "message")))]
+pub mod gossip;
+
 #[cfg(feature = "wallet")]
 #[cfg_attr(docsrs, doc(cfg(feature = "wallet")))]
 pub mod wallet;
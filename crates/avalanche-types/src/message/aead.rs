@@ -0,0 +1,135 @@
+//! Optional authenticated encryption of opaque VM payloads (e.g. the
+//! `app_bytes` carried by `AppRequest`/`AppResponse`), so two VMs that share
+//! a symmetric key out-of-band can exchange confidential application data
+//! without trusting the P2P transport alone.
+//!
+//! Only compiled in when the `app_bytes_aead` feature is enabled.
+#![cfg(feature = "app_bytes_aead")]
+
+use std::io::{self, Error, ErrorKind};
+
+use chacha20poly1305::{
+    aead::{Aead, KeyInit, Payload},
+    ChaCha20Poly1305, Key, Nonce,
+};
+
+/// Length, in bytes, of the symmetric key accepted by [`seal`]/[`open`].
+pub const KEY_LEN: usize = 32;
+
+/// Length, in bytes, of the randomly generated nonce prefixing every sealed
+/// payload (96 bits, as required by ChaCha20-Poly1305).
+pub const NONCE_LEN: usize = 12;
+
+/// Which AEAD construction sealed a payload. Stored as the one-byte scheme
+/// tag prefixing every sealed payload, so a future scheme can be added
+/// without breaking peers still on the old one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Scheme {
+    ChaCha20Poly1305,
+}
+
+impl Scheme {
+    fn tag(self) -> u8 {
+        match self {
+            Scheme::ChaCha20Poly1305 => 1,
+        }
+    }
+
+    fn from_tag(tag: u8) -> io::Result<Self> {
+        match tag {
+            1 => Ok(Scheme::ChaCha20Poly1305),
+            _ => Err(Error::new(
+                ErrorKind::InvalidData,
+                format!("unknown AEAD scheme tag {tag}"),
+            )),
+        }
+    }
+}
+
+/// Seals "plaintext" with "key", authenticating "associated_data" alongside
+/// it (without encrypting it), and returns `scheme_tag || nonce || ciphertext`
+/// (the authentication tag is appended to the ciphertext by the AEAD crate).
+pub fn seal(key: &[u8; KEY_LEN], plaintext: &[u8], associated_data: &[u8]) -> io::Result<Vec<u8>> {
+    let cipher = ChaCha20Poly1305::new(Key::from_slice(key));
+
+    let nonce_bytes = random_manager::secure_bytes(NONCE_LEN)
+        .map_err(|e| Error::new(ErrorKind::Other, format!("failed to generate nonce '{e}'")))?;
+    let nonce = Nonce::from_slice(&nonce_bytes);
+
+    let ciphertext = cipher
+        .encrypt(
+            nonce,
+            Payload {
+                msg: plaintext,
+                aad: associated_data,
+            },
+        )
+        .map_err(|e| Error::new(ErrorKind::Other, format!("failed to seal payload '{e}'")))?;
+
+    let mut out = Vec::with_capacity(1 + NONCE_LEN + ciphertext.len());
+    out.push(Scheme::ChaCha20Poly1305.tag());
+    out.extend_from_slice(&nonce_bytes);
+    out.extend_from_slice(&ciphertext);
+    Ok(out)
+}
+
+/// Opens a payload produced by [`seal`], verifying the authentication tag
+/// against "associated_data" and rejecting on any mismatch (wrong key,
+/// tampered ciphertext, or mismatched associated data).
+pub fn open(key: &[u8; KEY_LEN], sealed: &[u8], associated_data: &[u8]) -> io::Result<Vec<u8>> {
+    if sealed.len() < 1 + NONCE_LEN {
+        return Err(Error::new(
+            ErrorKind::InvalidData,
+            "sealed payload shorter than scheme tag + nonce",
+        ));
+    }
+
+    let scheme = Scheme::from_tag(sealed[0])?;
+    let Scheme::ChaCha20Poly1305 = scheme;
+
+    let nonce = Nonce::from_slice(&sealed[1..1 + NONCE_LEN]);
+    let ciphertext = &sealed[1 + NONCE_LEN..];
+
+    let cipher = ChaCha20Poly1305::new(Key::from_slice(key));
+    cipher
+        .decrypt(
+            nonce,
+            Payload {
+                msg: ciphertext,
+                aad: associated_data,
+            },
+        )
+        .map_err(|e| {
+            Error::new(
+                ErrorKind::InvalidData,
+                format!("failed to open sealed payload '{e}'"),
+            )
+        })
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- message::aead::test_seal_open_round_trip --exact --show-output
+#[test]
+fn test_seal_open_round_trip() {
+    let key: [u8; KEY_LEN] = random_manager::secure_bytes(KEY_LEN)
+        .unwrap()
+        .try_into()
+        .unwrap();
+    let plaintext = b"hello subnet".to_vec();
+    let aad = b"chain-id||request-id".to_vec();
+
+    let sealed = seal(&key, &plaintext, &aad).unwrap();
+    assert_ne!(sealed, plaintext);
+
+    let opened = open(&key, &sealed, &aad).unwrap();
+    assert_eq!(opened, plaintext);
+
+    // wrong associated data must fail to authenticate.
+    assert!(open(&key, &sealed, b"wrong aad").is_err());
+
+    // wrong key must fail to authenticate.
+    let wrong_key: [u8; KEY_LEN] = random_manager::secure_bytes(KEY_LEN)
+        .unwrap()
+        .try_into()
+        .unwrap();
+    assert!(open(&wrong_key, &sealed, &aad).is_err());
+}
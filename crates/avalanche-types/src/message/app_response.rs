@@ -1,12 +1,19 @@
-use std::io::{self, Error, ErrorKind};
+use std::io;
 
-use crate::{ids, message, proto::pb::p2p};
-use prost::Message as ProstMessage;
+use crate::{ids, message, message::codec::P2pMessage, proto::pb::p2p};
 
 #[derive(Debug, PartialEq, Clone)]
 pub struct Message {
     pub msg: p2p::AppResponse,
     pub gzip_compress: bool,
+    pub zstd_compress: bool,
+    pub zstd_compression_level: i32,
+    pub compression_policy: message::codec::CompressionPolicy,
+    /// When set, `app_bytes` is sealed with this key on [`Self::serialize`]
+    /// (see [`Self::encrypt_with`]).
+    #[cfg(feature = "app_bytes_aead")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "app_bytes_aead")))]
+    pub encrypt_key: Option<[u8; message::aead::KEY_LEN]>,
 }
 
 impl Default for Message {
@@ -24,6 +31,11 @@ impl Message {
                 app_bytes: prost::bytes::Bytes::new(),
             },
             gzip_compress: false,
+            zstd_compress: false,
+            zstd_compression_level: message::compress::ZSTD_DEFAULT_LEVEL,
+            compression_policy: message::codec::CompressionPolicy::default(),
+            #[cfg(feature = "app_bytes_aead")]
+            encrypt_key: None,
         }
     }
 
@@ -51,79 +63,115 @@ impl Message {
         self
     }
 
-    pub fn serialize(&self) -> io::Result<Vec<u8>> {
-        let msg = p2p::Message {
-            message: Some(p2p::message::Message::AppResponse(self.msg.clone())),
-        };
-        let encoded = ProstMessage::encode_to_vec(&msg);
-        if !self.gzip_compress {
-            return Ok(encoded);
-        }
+    #[must_use]
+    pub fn zstd_compress(mut self, zstd_compress: bool) -> Self {
+        self.zstd_compress = zstd_compress;
+        self
+    }
+
+    /// Sets the zstd compression level (1-22, default [`message::compress::ZSTD_DEFAULT_LEVEL`]).
+    #[must_use]
+    pub fn zstd_compression_level(mut self, zstd_compression_level: i32) -> Self {
+        self.zstd_compression_level = zstd_compression_level;
+        self
+    }
+
+    /// Sets the policy gating whether a requested compression codec is
+    /// actually applied (default [`message::codec::CompressionPolicy::Always`]).
+    #[must_use]
+    pub fn compression_policy(
+        mut self,
+        compression_policy: message::codec::CompressionPolicy,
+    ) -> Self {
+        self.compression_policy = compression_policy;
+        self
+    }
+
+    /// Seals `app_bytes` with "key" on [`Self::serialize`], authenticating
+    /// `chain_id || request_id` as associated data; the counterpart reads it
+    /// back with [`Self::deserialize_encrypted`] and the same key.
+    #[cfg(feature = "app_bytes_aead")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "app_bytes_aead")))]
+    #[must_use]
+    pub fn encrypt_with(mut self, key: [u8; message::aead::KEY_LEN]) -> Self {
+        self.encrypt_key = Some(key);
+        self
+    }
 
-        let uncompressed_len = encoded.len();
-        let compressed = message::compress::pack_gzip(&encoded)?;
-        let msg = p2p::Message {
-            message: Some(p2p::message::Message::CompressedGzip(
-                prost::bytes::Bytes::from(compressed),
-            )),
-        };
-
-        let compressed_len = msg.encoded_len();
-        if uncompressed_len > compressed_len {
-            log::debug!(
-                "app_response compression saved {} bytes",
-                uncompressed_len - compressed_len
-            );
+    /// Compression codec this message is currently configured to use.
+    fn compression(&self) -> message::codec::Compression {
+        if self.zstd_compress {
+            message::codec::Compression::Zstd {
+                level: self.zstd_compression_level,
+            }
+        } else if self.gzip_compress {
+            message::codec::Compression::Gzip
         } else {
-            log::debug!(
-                "app_response compression added {} byte(s)",
-                compressed_len - uncompressed_len
-            );
+            message::codec::Compression::None
+        }
+    }
+
+    pub fn serialize(&self) -> io::Result<Vec<u8>> {
+        #[cfg(feature = "app_bytes_aead")]
+        if let Some(key) = self.encrypt_key {
+            let mut sealed = self.clone();
+            sealed.msg.app_bytes = prost::bytes::Bytes::from(message::aead::seal(
+                &key,
+                &self.msg.app_bytes,
+                &Self::associated_data(&self.msg),
+            )?);
+            return P2pMessage::serialize(&sealed, sealed.compression(), sealed.compression_policy);
         }
 
-        Ok(ProstMessage::encode_to_vec(&msg))
+        P2pMessage::serialize(self, self.compression(), self.compression_policy)
     }
 
     pub fn deserialize(d: impl AsRef<[u8]>) -> io::Result<Self> {
-        let buf = bytes::Bytes::from(d.as_ref().to_vec());
-        let p2p_msg: p2p::Message = ProstMessage::decode(buf).map_err(|e| {
-            Error::new(
-                ErrorKind::InvalidData,
-                format!("failed prost::Message::decode '{}'", e),
-            )
-        })?;
-
-        match p2p_msg.message.unwrap() {
-            // was not compressed
-            p2p::message::Message::AppResponse(msg) => Ok(Message {
+        message::codec::deserialize(d)
+    }
+
+    /// Like [`Self::deserialize`], but also opens `app_bytes` with "key"
+    /// (see [`Self::encrypt_with`]), rejecting the message if it doesn't
+    /// authenticate.
+    #[cfg(feature = "app_bytes_aead")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "app_bytes_aead")))]
+    pub fn deserialize_encrypted(
+        d: impl AsRef<[u8]>,
+        key: &[u8; message::aead::KEY_LEN],
+    ) -> io::Result<Self> {
+        let mut msg: Self = message::codec::deserialize(d)?;
+        let opened =
+            message::aead::open(key, &msg.msg.app_bytes, &Self::associated_data(&msg.msg))?;
+        msg.msg.app_bytes = prost::bytes::Bytes::from(opened);
+        msg.encrypt_key = Some(*key);
+        Ok(msg)
+    }
+
+    #[cfg(feature = "app_bytes_aead")]
+    fn associated_data(msg: &p2p::AppResponse) -> Vec<u8> {
+        let mut aad = msg.chain_id.to_vec();
+        aad.extend_from_slice(&msg.request_id.to_be_bytes());
+        aad
+    }
+}
+
+impl P2pMessage for Message {
+    fn into_inner(self) -> p2p::message::Message {
+        p2p::message::Message::AppResponse(self.msg)
+    }
+
+    fn from_inner(inner: p2p::message::Message) -> Option<Self> {
+        match inner {
+            p2p::message::Message::AppResponse(msg) => Some(Message {
                 msg,
                 gzip_compress: false,
+                zstd_compress: false,
+                zstd_compression_level: message::compress::ZSTD_DEFAULT_LEVEL,
+                compression_policy: message::codec::CompressionPolicy::default(),
+                #[cfg(feature = "app_bytes_aead")]
+                encrypt_key: None,
             }),
-
-            // was compressed, so need decompress first
-            p2p::message::Message::CompressedGzip(msg) => {
-                let decompressed = message::compress::unpack_gzip(msg.as_ref())?;
-                let decompressed_msg: p2p::Message =
-                    ProstMessage::decode(prost::bytes::Bytes::from(decompressed)).map_err(|e| {
-                        Error::new(
-                            ErrorKind::InvalidData,
-                            format!("failed prost::Message::decode '{}'", e),
-                        )
-                    })?;
-                match decompressed_msg.message.unwrap() {
-                    p2p::message::Message::AppResponse(msg) => Ok(Message {
-                        msg,
-                        gzip_compress: false,
-                    }),
-                    _ => Err(Error::new(
-                        ErrorKind::InvalidInput,
-                        "unknown message type after decompress",
-                    )),
-                }
-            }
-
-            // unknown message enum
-            _ => Err(Error::new(ErrorKind::InvalidInput, "unknown message type")),
+            _ => None,
         }
     }
 }
@@ -157,3 +205,58 @@ fn test_message() {
     let msg2_with_compression_deserialized = Message::deserialize(&data2).unwrap();
     assert_eq!(msg1_with_no_compression, msg2_with_compression_deserialized);
 }
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- message::app_response::test_compression_policy_if_larger_than_falls_back --exact --show-output
+#[test]
+fn test_compression_policy_if_larger_than_falls_back() {
+    use crate::message::codec::CompressionPolicy;
+
+    // tiny payload below the threshold: compression never kicks in, and the
+    // round-tripped message reports "gzip_compress: false" (a deserialized
+    // message never carries the sender's compression settings).
+    let msg = Message::default()
+        .app_bytes(vec![0x01, 0x02, 0x03])
+        .gzip_compress(true)
+        .compression_policy(CompressionPolicy::IfLargerThan(1024));
+
+    let data = msg.serialize().unwrap();
+    let decoded = Message::deserialize(&data).unwrap();
+    assert_eq!(decoded.msg, msg.msg);
+    assert!(!decoded.gzip_compress);
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib --features app_bytes_aead -- message::app_response::test_encrypt_with_round_trip --exact --show-output
+#[cfg(feature = "app_bytes_aead")]
+#[test]
+fn test_encrypt_with_round_trip() {
+    let key: [u8; message::aead::KEY_LEN] = random_manager::secure_bytes(message::aead::KEY_LEN)
+        .unwrap()
+        .try_into()
+        .unwrap();
+
+    let chain_id = ids::Id::from_slice(&random_manager::secure_bytes(32).unwrap());
+    let msg = Message::default()
+        .chain_id(chain_id)
+        .request_id(random_manager::u32())
+        .app_bytes(vec![0x01, 0x02, 0x03])
+        .encrypt_with(key);
+
+    let data = msg.serialize().unwrap();
+    let decrypted = Message::deserialize_encrypted(&data, &key).unwrap();
+    assert_eq!(decrypted.msg.app_bytes.as_ref(), &[0x01, 0x02, 0x03][..]);
+
+    // a peer without the key, or using the plain "deserialize", only ever
+    // sees the sealed (opaque, never plaintext) app_bytes on the wire.
+    let not_decrypted = Message::deserialize(&data).unwrap();
+    assert_ne!(
+        not_decrypted.msg.app_bytes.as_ref(),
+        &[0x01, 0x02, 0x03][..]
+    );
+
+    let wrong_key: [u8; message::aead::KEY_LEN] =
+        random_manager::secure_bytes(message::aead::KEY_LEN)
+            .unwrap()
+            .try_into()
+            .unwrap();
+    assert!(Message::deserialize_encrypted(&data, &wrong_key).is_err());
+}
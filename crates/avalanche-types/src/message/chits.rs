@@ -1,13 +1,15 @@
-use std::io::{self, Error, ErrorKind};
+use std::io;
 
-use crate::{ids, message, proto::pb::p2p};
+use crate::{ids, message, message::codec::P2pMessage, proto::pb::p2p};
 use prost::bytes::Bytes;
-use prost::Message as ProstMessage;
 
 #[derive(Debug, PartialEq, Clone)]
 pub struct Message {
     pub msg: p2p::Chits,
     pub gzip_compress: bool,
+    pub zstd_compress: bool,
+    pub zstd_compression_level: i32,
+    pub compression_policy: message::codec::CompressionPolicy,
 }
 
 impl Default for Message {
@@ -20,6 +22,9 @@ impl Default for Message {
                 accepted_id: Bytes::new(),
             },
             gzip_compress: false,
+            zstd_compress: false,
+            zstd_compression_level: message::compress::ZSTD_DEFAULT_LEVEL,
+            compression_policy: message::codec::CompressionPolicy::default(),
         }
     }
 }
@@ -49,79 +54,67 @@ impl Message {
         self
     }
 
-    pub fn serialize(&self) -> io::Result<Vec<u8>> {
-        let msg = p2p::Message {
-            message: Some(p2p::message::Message::Chits(self.msg.clone())),
-        };
-        let encoded = ProstMessage::encode_to_vec(&msg);
-        if !self.gzip_compress {
-            return Ok(encoded);
-        }
+    #[must_use]
+    pub fn zstd_compress(mut self, zstd_compress: bool) -> Self {
+        self.zstd_compress = zstd_compress;
+        self
+    }
+
+    /// Sets the zstd compression level (1-22, default [`message::compress::ZSTD_DEFAULT_LEVEL`]).
+    #[must_use]
+    pub fn zstd_compression_level(mut self, zstd_compression_level: i32) -> Self {
+        self.zstd_compression_level = zstd_compression_level;
+        self
+    }
+
+    /// Sets the policy gating whether a requested compression codec is
+    /// actually applied (default [`message::codec::CompressionPolicy::Always`]).
+    #[must_use]
+    pub fn compression_policy(
+        mut self,
+        compression_policy: message::codec::CompressionPolicy,
+    ) -> Self {
+        self.compression_policy = compression_policy;
+        self
+    }
 
-        let uncompressed_len = encoded.len();
-        let compressed = message::compress::pack_gzip(&encoded)?;
-        let msg = p2p::Message {
-            message: Some(p2p::message::Message::CompressedGzip(Bytes::from(
-                compressed,
-            ))),
-        };
-
-        let compressed_len = msg.encoded_len();
-        if uncompressed_len > compressed_len {
-            log::debug!(
-                "chits compression saved {} bytes",
-                uncompressed_len - compressed_len
-            );
+    /// Compression codec this message is currently configured to use.
+    fn compression(&self) -> message::codec::Compression {
+        if self.zstd_compress {
+            message::codec::Compression::Zstd {
+                level: self.zstd_compression_level,
+            }
+        } else if self.gzip_compress {
+            message::codec::Compression::Gzip
         } else {
-            log::debug!(
-                "chits compression added {} byte(s)",
-                compressed_len - uncompressed_len
-            );
+            message::codec::Compression::None
         }
+    }
 
-        Ok(ProstMessage::encode_to_vec(&msg))
+    pub fn serialize(&self) -> io::Result<Vec<u8>> {
+        P2pMessage::serialize(self, self.compression(), self.compression_policy)
     }
 
     pub fn deserialize(d: impl AsRef<[u8]>) -> io::Result<Self> {
-        let buf = bytes::Bytes::from(d.as_ref().to_vec());
-        let p2p_msg: p2p::Message = ProstMessage::decode(buf).map_err(|e| {
-            Error::new(
-                ErrorKind::InvalidData,
-                format!("failed prost::Message::decode '{}'", e),
-            )
-        })?;
-
-        match p2p_msg.message.unwrap() {
-            // was not compressed
-            p2p::message::Message::Chits(msg) => Ok(Message {
+        message::codec::deserialize(d)
+    }
+}
+
+impl P2pMessage for Message {
+    fn into_inner(self) -> p2p::message::Message {
+        p2p::message::Message::Chits(self.msg)
+    }
+
+    fn from_inner(inner: p2p::message::Message) -> Option<Self> {
+        match inner {
+            p2p::message::Message::Chits(msg) => Some(Message {
                 msg,
                 gzip_compress: false,
+                zstd_compress: false,
+                zstd_compression_level: message::compress::ZSTD_DEFAULT_LEVEL,
+                compression_policy: message::codec::CompressionPolicy::default(),
             }),
-
-            // was compressed, so need decompress first
-            p2p::message::Message::CompressedGzip(msg) => {
-                let decompressed = message::compress::unpack_gzip(msg.as_ref())?;
-                let decompressed_msg: p2p::Message =
-                    ProstMessage::decode(Bytes::from(decompressed)).map_err(|e| {
-                        Error::new(
-                            ErrorKind::InvalidData,
-                            format!("failed prost::Message::decode '{}'", e),
-                        )
-                    })?;
-                match decompressed_msg.message.unwrap() {
-                    p2p::message::Message::Chits(msg) => Ok(Message {
-                        msg,
-                        gzip_compress: false,
-                    }),
-                    _ => Err(Error::new(
-                        ErrorKind::InvalidInput,
-                        "unknown message type after decompress",
-                    )),
-                }
-            }
-
-            // unknown message enum
-            _ => Err(Error::new(ErrorKind::InvalidInput, "unknown message type")),
+            _ => None,
         }
     }
 }
@@ -0,0 +1,173 @@
+//! Shared serialize/deserialize machinery for P2P message wrappers.
+//!
+//! Every concrete message type (`app_response::Message`, `peerlist::Message`,
+//! `version::Message`, ...) wraps exactly one `p2p::message::Message` proto
+//! variant, prost-encodes it, optionally compresses it, and on the way back
+//! matches the `Compressed*` arm before re-decoding the inner message.
+//! [`P2pMessage`] captures that pattern once, so new message types and new
+//! compression codecs don't need to touch every file in this module.
+
+use std::io::{self, Error, ErrorKind};
+
+use crate::{message, proto::pb::p2p};
+use prost::Message as ProstMessage;
+
+/// Which codec (if any) to apply when serializing a [`P2pMessage`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Compression {
+    None,
+    Gzip,
+    Zstd { level: i32 },
+}
+
+/// Gates whether a requested [`Compression`] is actually applied, so tiny
+/// messages (or ones that happen not to compress well) don't pay the CPU
+/// cost and frame overhead for no benefit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CompressionPolicy {
+    /// Always use the requested codec, even if it grows the payload.
+    Always,
+    /// Only use the requested codec if it ends up smaller than the
+    /// uncompressed frame.
+    IfSmaller,
+    /// Only attempt compression once the uncompressed payload exceeds
+    /// "bytes"; falls back to "IfSmaller" once that applies.
+    IfLargerThan(usize),
+}
+
+impl Default for CompressionPolicy {
+    fn default() -> Self {
+        CompressionPolicy::Always
+    }
+}
+
+/// A type that wraps exactly one `p2p::message::Message` proto variant and
+/// can be losslessly converted to/from it.
+pub trait P2pMessage: Clone + Sized {
+    /// Wraps "self" into the proto oneof variant it corresponds to.
+    fn into_inner(self) -> p2p::message::Message;
+
+    /// Unwraps the proto oneof variant back into "Self", or "None" if
+    /// "inner" is a different variant than this type expects.
+    fn from_inner(inner: p2p::message::Message) -> Option<Self>;
+
+    /// Encodes "self", applying "compression" if "policy" allows it, and
+    /// logs how many bytes compression saved (or would have added).
+    fn serialize(
+        &self,
+        compression: Compression,
+        policy: CompressionPolicy,
+    ) -> io::Result<Vec<u8>> {
+        let msg = p2p::Message {
+            message: Some(self.clone().into_inner()),
+        };
+        let encoded = ProstMessage::encode_to_vec(&msg);
+        let uncompressed_len = encoded.len();
+
+        if compression == Compression::None {
+            return Ok(encoded);
+        }
+        if let CompressionPolicy::IfLargerThan(threshold) = policy {
+            if uncompressed_len <= threshold {
+                return Ok(encoded);
+            }
+        }
+
+        let compressed_variant = match compression {
+            Compression::None => unreachable!("handled above"),
+            Compression::Gzip => p2p::message::Message::CompressedGzip(prost::bytes::Bytes::from(
+                message::compress::pack_gzip(&encoded)?,
+            )),
+            Compression::Zstd { level } => {
+                p2p::message::Message::CompressedZstd(prost::bytes::Bytes::from(
+                    message::compress::pack_zstd_with_level(&encoded, level)?,
+                ))
+            }
+        };
+
+        let compressed_msg = p2p::Message {
+            message: Some(compressed_variant),
+        };
+        let compressed_len = compressed_msg.encoded_len();
+        let use_compressed = match policy {
+            CompressionPolicy::Always => true,
+            CompressionPolicy::IfSmaller | CompressionPolicy::IfLargerThan(_) => {
+                compressed_len < uncompressed_len
+            }
+        };
+
+        if uncompressed_len > compressed_len {
+            log::debug!(
+                "compression saved {} byte(s)",
+                uncompressed_len - compressed_len
+            );
+        } else {
+            log::debug!(
+                "compression added {} byte(s){}",
+                compressed_len - uncompressed_len,
+                if use_compressed {
+                    ""
+                } else {
+                    ", falling back to uncompressed"
+                }
+            );
+        }
+
+        if use_compressed {
+            Ok(ProstMessage::encode_to_vec(&compressed_msg))
+        } else {
+            Ok(encoded)
+        }
+    }
+}
+
+/// Decodes bytes produced by [`P2pMessage::serialize`], transparently
+/// decompressing the "Compressed*" arms and rejecting any inner message type
+/// other than "M"'s.
+pub fn deserialize<M: P2pMessage>(d: impl AsRef<[u8]>) -> io::Result<M> {
+    let buf = bytes::Bytes::from(d.as_ref().to_vec());
+    let p2p_msg: p2p::Message = ProstMessage::decode(buf).map_err(|e| {
+        Error::new(
+            ErrorKind::InvalidData,
+            format!("failed prost::Message::decode '{}'", e),
+        )
+    })?;
+
+    match p2p_msg.message.unwrap() {
+        // was compressed, so need decompress first
+        p2p::message::Message::CompressedGzip(msg) => {
+            let decompressed = message::compress::unpack_gzip_bounded(
+                msg.as_ref(),
+                message::compress::DEFAULT_MAX_DECOMPRESSED_LEN,
+            )?;
+            decode_inner(decompressed)
+        }
+        p2p::message::Message::CompressedZstd(msg) => {
+            let decompressed = message::compress::unpack_zstd_bounded(
+                msg.as_ref(),
+                message::compress::DEFAULT_MAX_DECOMPRESSED_LEN,
+            )?;
+            decode_inner(decompressed)
+        }
+
+        // was not compressed
+        inner => M::from_inner(inner)
+            .ok_or_else(|| Error::new(ErrorKind::InvalidInput, "unknown message type")),
+    }
+}
+
+fn decode_inner<M: P2pMessage>(decompressed: Vec<u8>) -> io::Result<M> {
+    let decompressed_msg: p2p::Message =
+        ProstMessage::decode(prost::bytes::Bytes::from(decompressed)).map_err(|e| {
+            Error::new(
+                ErrorKind::InvalidData,
+                format!("failed prost::Message::decode '{}'", e),
+            )
+        })?;
+    M::from_inner(decompressed_msg.message.unwrap()).ok_or_else(|| {
+        Error::new(
+            ErrorKind::InvalidInput,
+            "unknown message type after decompress",
+        )
+    })
+}
@@ -5,6 +5,46 @@ use flate2::{
     Compression,
 };
 
+/// Default zstd compression level, matching "golang/klauspost/compress/zstd"
+/// default (3).
+pub const ZSTD_DEFAULT_LEVEL: i32 = 3;
+
+/// Maximum size (in bytes) allowed for a single decompressed message, matching
+/// AvalancheGo's per-message maximum ("constants.DefaultMaxMessageSize", 2 MiB).
+/// Used as the default bound so a hostile peer cannot amplify memory usage
+/// through the compression path.
+/// ref. <https://github.com/ava-labs/avalanchego/blob/master/network/peer/msg_length.go>
+pub const DEFAULT_MAX_DECOMPRESSED_LEN: usize = 2 * 1024 * 1024;
+
+/// Size of the chunk used while streaming a bounded inflate.
+const DECOMPRESS_CHUNK_LEN: usize = 64 * 1024;
+
+/// Streams `r` into a buffer in fixed-size chunks, aborting with
+/// `ErrorKind::InvalidData` once the accumulated output exceeds
+/// `max_decompressed_len`. Guards the decompression path against
+/// "decompression bomb" inputs that inflate to arbitrary sizes.
+fn read_bounded<R: Read>(mut r: R, max_decompressed_len: usize) -> io::Result<Vec<u8>> {
+    let mut decoded: Vec<u8> = Vec::new();
+    let mut chunk = [0u8; DECOMPRESS_CHUNK_LEN];
+    loop {
+        let n = r.read(&mut chunk)?;
+        if n == 0 {
+            break;
+        }
+        if decoded.len() + n > max_decompressed_len {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!(
+                    "decompressed size exceeds limit of {} byte(s)",
+                    max_decompressed_len
+                ),
+            ));
+        }
+        decoded.extend_from_slice(&chunk[..n]);
+    }
+    Ok(decoded)
+}
+
 /// Compress the input bytes.
 
 pub fn pack_gzip<S>(d: S) -> io::Result<Vec<u8>>
@@ -30,3 +70,48 @@ where
     gz.read_to_end(&mut decoded)?;
     Ok(decoded)
 }
+
+/// Decompress the gzip-compressed input bytes, aborting once the decompressed
+/// output would exceed `max_decompressed_len` bytes.
+pub fn unpack_gzip_bounded<S>(d: S, max_decompressed_len: usize) -> io::Result<Vec<u8>>
+where
+    S: AsRef<[u8]>,
+{
+    let gz = GzDecoder::new(Cursor::new(d));
+    read_bounded(gz, max_decompressed_len)
+}
+
+/// Compress the input bytes with zstd, at [`ZSTD_DEFAULT_LEVEL`].
+pub fn pack_zstd<S>(d: S) -> io::Result<Vec<u8>>
+where
+    S: AsRef<[u8]>,
+{
+    pack_zstd_with_level(d, ZSTD_DEFAULT_LEVEL)
+}
+
+/// Compress the input bytes with zstd at the given level (1-22, higher
+/// trades more CPU for a smaller output).
+pub fn pack_zstd_with_level<S>(d: S, level: i32) -> io::Result<Vec<u8>>
+where
+    S: AsRef<[u8]>,
+{
+    zstd::stream::encode_all(Cursor::new(d), level)
+}
+
+/// Decompress the zstd-compressed input bytes.
+pub fn unpack_zstd<S>(d: S) -> io::Result<Vec<u8>>
+where
+    S: AsRef<[u8]>,
+{
+    zstd::stream::decode_all(Cursor::new(d))
+}
+
+/// Decompress the zstd-compressed input bytes, aborting once the decompressed
+/// output would exceed `max_decompressed_len` bytes.
+pub fn unpack_zstd_bounded<S>(d: S, max_decompressed_len: usize) -> io::Result<Vec<u8>>
+where
+    S: AsRef<[u8]>,
+{
+    let dec = zstd::stream::read::Decoder::new(Cursor::new(d))?;
+    read_bounded(dec, max_decompressed_len)
+}
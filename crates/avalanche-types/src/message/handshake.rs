@@ -0,0 +1,286 @@
+//! Version/capability handshake negotiation on top of [`message::version`].
+//!
+//! Two peers each send a [`version::Message`] when a connection is
+//! established, but nothing in this crate checks that the exchange actually
+//! leaves both sides in agreement: the protocol versions might not be
+//! compatible, the clocks might have drifted too far apart, or the peers
+//! might not share a compression codec. [`Handshake`] turns that check into
+//! a small, pure state machine -- feed it the local [`version::Message`]
+//! plus the codecs this node supports, consume the peer's decoded version
+//! and the codecs it advertises, and get back a negotiated [`Session`] or a
+//! typed [`RejectionReason`] explaining why the handshake failed.
+
+use std::{cmp::Ordering, collections::HashSet, fmt};
+
+use crate::{ids, message::codec::Compression, message::version};
+
+/// Why a handshake was rejected.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RejectionReason {
+    /// The peer's `my_version` is older than the configured minimum.
+    IncompatibleVersion {
+        peer_version: String,
+        min_compatible_version: String,
+    },
+    /// `|local.my_time - peer.my_time|` exceeds the configured maximum.
+    ClockSkewTooLarge { skew_secs: u64, max_skew_secs: u64 },
+    /// Neither side advertises a compression codec the other one also
+    /// advertises.
+    NoCommonCompression,
+}
+
+impl fmt::Display for RejectionReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RejectionReason::IncompatibleVersion {
+                peer_version,
+                min_compatible_version,
+            } => write!(
+                f,
+                "peer version '{peer_version}' is older than the minimum compatible version '{min_compatible_version}'"
+            ),
+            RejectionReason::ClockSkewTooLarge {
+                skew_secs,
+                max_skew_secs,
+            } => write!(
+                f,
+                "peer clock skew {skew_secs}s exceeds the maximum allowed {max_skew_secs}s"
+            ),
+            RejectionReason::NoCommonCompression => {
+                write!(f, "local and peer advertise no common compression codec")
+            }
+        }
+    }
+}
+
+impl std::error::Error for RejectionReason {}
+
+/// Dotted "major.minor.patch" version, e.g. the `v1.2.3` carried in
+/// [`version::Message::my_version`]. Anything outside that shape sorts
+/// lower than any parsed version, so a malformed string is rejected by any
+/// parseable [`Handshake::min_compatible_version`], the default "v0.0.0"
+/// included.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+struct ParsedVersion(Option<(u64, u64, u64)>);
+
+impl ParsedVersion {
+    fn parse(s: &str) -> Self {
+        let trimmed = s.strip_prefix('v').unwrap_or(s);
+        let mut parts = trimmed.split('.');
+        let parsed = (|| {
+            let major = parts.next()?.parse().ok()?;
+            let minor = parts.next()?.parse().ok()?;
+            let patch = parts.next()?.parse().ok()?;
+            if parts.next().is_some() {
+                return None;
+            }
+            Some((major, minor, patch))
+        })();
+        ParsedVersion(parsed)
+    }
+}
+
+/// Negotiates a [`Session`] between this node's [`version::Message`] and a
+/// peer's, per the policy configured here.
+#[derive(Debug, Clone)]
+pub struct Handshake {
+    local_version: version::Message,
+    min_compatible_version: String,
+    max_clock_skew_secs: u64,
+    /// Compression codecs this node is willing to use, in preference order
+    /// (most preferred first).
+    supported_compressions: Vec<Compression>,
+}
+
+impl Handshake {
+    #[must_use]
+    pub fn new(local_version: version::Message) -> Self {
+        Self {
+            local_version,
+            min_compatible_version: String::from("v0.0.0"),
+            max_clock_skew_secs: 60,
+            supported_compressions: vec![Compression::None],
+        }
+    }
+
+    /// Sets the oldest `my_version` string this node will accept from a
+    /// peer (default "v0.0.0", i.e. any well-formed version is accepted).
+    #[must_use]
+    pub fn min_compatible_version(mut self, min_compatible_version: impl Into<String>) -> Self {
+        self.min_compatible_version = min_compatible_version.into();
+        self
+    }
+
+    /// Sets the maximum tolerated `|local.my_time - peer.my_time|`, in
+    /// seconds (default 60).
+    #[must_use]
+    pub fn max_clock_skew_secs(mut self, max_clock_skew_secs: u64) -> Self {
+        self.max_clock_skew_secs = max_clock_skew_secs;
+        self
+    }
+
+    /// Sets the compression codecs this node is willing to use, in
+    /// preference order (most preferred first). Default is `[Compression::None]`.
+    #[must_use]
+    pub fn supported_compressions(mut self, supported_compressions: Vec<Compression>) -> Self {
+        self.supported_compressions = supported_compressions;
+        self
+    }
+
+    /// Consumes a peer's decoded [`version::Message`] and the compression
+    /// codecs it advertises support for, and either negotiates a [`Session`]
+    /// or returns why negotiation failed.
+    pub fn negotiate(
+        &self,
+        peer_version: &version::Message,
+        peer_supported_compressions: &[Compression],
+    ) -> Result<Session, RejectionReason> {
+        let peer = ParsedVersion::parse(&peer_version.msg.my_version);
+        let min = ParsedVersion::parse(&self.min_compatible_version);
+        if peer.cmp(&min) == Ordering::Less {
+            return Err(RejectionReason::IncompatibleVersion {
+                peer_version: peer_version.msg.my_version.clone(),
+                min_compatible_version: self.min_compatible_version.clone(),
+            });
+        }
+
+        let local_time = self.local_version.msg.my_time;
+        let peer_time = peer_version.msg.my_time;
+        let skew_secs = local_time.abs_diff(peer_time);
+        if skew_secs > self.max_clock_skew_secs {
+            return Err(RejectionReason::ClockSkewTooLarge {
+                skew_secs,
+                max_skew_secs: self.max_clock_skew_secs,
+            });
+        }
+
+        let agreed_compression = self
+            .supported_compressions
+            .iter()
+            .find(|c| {
+                peer_supported_compressions
+                    .iter()
+                    .any(|p| compression_kind_eq(c, p))
+            })
+            .copied()
+            .ok_or(RejectionReason::NoCommonCompression)?;
+
+        let local_subnets: HashSet<ids::Id> = parse_subnets(&self.local_version.msg);
+        let peer_subnets: HashSet<ids::Id> = parse_subnets(&peer_version.msg);
+        let shared_tracked_subnets: HashSet<ids::Id> =
+            local_subnets.intersection(&peer_subnets).copied().collect();
+
+        Ok(Session {
+            agreed_compression,
+            shared_tracked_subnets,
+            peer_clock_skew_secs: skew_secs,
+        })
+    }
+}
+
+fn parse_subnets(msg: &crate::proto::pb::p2p::Version) -> HashSet<ids::Id> {
+    msg.tracked_subnets
+        .iter()
+        .map(|b| ids::Id::from_slice(b))
+        .collect()
+}
+
+/// Two [`Compression`] values agree on a common codec if they're the same
+/// variant, ignoring the `Zstd` compression level (the level is a local CPU
+/// vs. size tradeoff, not a wire-compatibility concern).
+fn compression_kind_eq(a: &Compression, b: &Compression) -> bool {
+    matches!(
+        (a, b),
+        (Compression::None, Compression::None)
+            | (Compression::Gzip, Compression::Gzip)
+            | (Compression::Zstd { .. }, Compression::Zstd { .. })
+    )
+}
+
+/// A successfully negotiated handshake session.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Session {
+    /// The compression codec both peers agreed on, preferring this node's
+    /// most-preferred codec among the ones the peer also supports.
+    pub agreed_compression: Compression,
+    /// Subnet IDs tracked by both peers.
+    pub shared_tracked_subnets: HashSet<ids::Id>,
+    /// `|local.my_time - peer.my_time|`, in seconds.
+    pub peer_clock_skew_secs: u64,
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- message::handshake::test_negotiate_succeeds --exact --show-output
+#[test]
+fn test_negotiate_succeeds() {
+    let local = version::Message::default()
+        .my_time(1_000)
+        .my_version(String::from("v1.2.0"))
+        .tracked_subnets(vec![ids::Id::empty(), ids::Id::from_slice(&[1u8; 32])]);
+    let peer = version::Message::default()
+        .my_time(1_030)
+        .my_version(String::from("v1.3.0"))
+        .tracked_subnets(vec![ids::Id::empty(), ids::Id::from_slice(&[2u8; 32])]);
+
+    let handshake = Handshake::new(local)
+        .min_compatible_version("v1.0.0")
+        .max_clock_skew_secs(60)
+        .supported_compressions(vec![Compression::Zstd { level: 3 }, Compression::None]);
+
+    let session = handshake
+        .negotiate(&peer, &[Compression::Gzip, Compression::None])
+        .unwrap();
+    assert_eq!(session.agreed_compression, Compression::None);
+    assert_eq!(session.peer_clock_skew_secs, 30);
+    assert_eq!(
+        session.shared_tracked_subnets,
+        [ids::Id::empty()].into_iter().collect()
+    );
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- message::handshake::test_negotiate_rejects --exact --show-output
+#[test]
+fn test_negotiate_rejects() {
+    let local = version::Message::default()
+        .my_time(1_000)
+        .my_version(String::from("v1.2.0"));
+
+    let too_old = version::Message::default()
+        .my_time(1_000)
+        .my_version(String::from("v0.9.0"));
+    let handshake = Handshake::new(local.clone()).min_compatible_version("v1.0.0");
+    assert_eq!(
+        handshake
+            .negotiate(&too_old, &[Compression::None])
+            .unwrap_err(),
+        RejectionReason::IncompatibleVersion {
+            peer_version: String::from("v0.9.0"),
+            min_compatible_version: String::from("v1.0.0"),
+        }
+    );
+
+    let skewed = version::Message::default()
+        .my_time(1_200)
+        .my_version(String::from("v1.2.0"));
+    let handshake = Handshake::new(local.clone()).max_clock_skew_secs(30);
+    assert_eq!(
+        handshake
+            .negotiate(&skewed, &[Compression::None])
+            .unwrap_err(),
+        RejectionReason::ClockSkewTooLarge {
+            skew_secs: 200,
+            max_skew_secs: 30,
+        }
+    );
+
+    let incompatible_compression = version::Message::default()
+        .my_time(1_000)
+        .my_version(String::from("v1.2.0"));
+    let handshake =
+        Handshake::new(local).supported_compressions(vec![Compression::Zstd { level: 3 }]);
+    assert_eq!(
+        handshake
+            .negotiate(&incompatible_compression, &[Compression::Gzip])
+            .unwrap_err(),
+        RejectionReason::NoCommonCompression
+    );
+}
@@ -2,11 +2,15 @@
 pub mod accepted;
 pub mod accepted_frontier;
 pub mod accepted_state_summary;
+#[cfg(feature = "app_bytes_aead")]
+#[cfg_attr(docsrs, doc(cfg(feature = "app_bytes_aead")))]
+pub mod aead;
 pub mod ancestors;
 pub mod app_gossip;
 pub mod app_request;
 pub mod app_response;
 pub mod chits;
+pub mod codec;
 pub mod compress;
 pub mod get;
 pub mod get_accepted;
@@ -14,6 +18,7 @@ pub mod get_accepted_frontier;
 pub mod get_accepted_state_summary;
 pub mod get_ancestors;
 pub mod get_state_summary_frontier;
+pub mod handshake;
 pub mod peerlist;
 pub mod ping;
 pub mod pong;
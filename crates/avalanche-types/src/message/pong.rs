@@ -0,0 +1,131 @@
+use std::io;
+
+use crate::{message, message::codec::P2pMessage, proto::pb::p2p};
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Message {
+    pub msg: p2p::Pong,
+    pub gzip_compress: bool,
+    pub zstd_compress: bool,
+    pub zstd_compression_level: i32,
+    pub compression_policy: message::codec::CompressionPolicy,
+}
+
+impl Default for Message {
+    fn default() -> Self {
+        Self::default()
+    }
+}
+
+impl Message {
+    pub fn default() -> Self {
+        Message {
+            msg: p2p::Pong { uptime: 0 },
+            gzip_compress: false,
+            zstd_compress: false,
+            zstd_compression_level: message::compress::ZSTD_DEFAULT_LEVEL,
+            compression_policy: message::codec::CompressionPolicy::default(),
+        }
+    }
+
+    #[must_use]
+    pub fn uptime(mut self, uptime: u32) -> Self {
+        self.msg.uptime = uptime;
+        self
+    }
+
+    #[must_use]
+    pub fn gzip_compress(mut self, gzip_compress: bool) -> Self {
+        self.gzip_compress = gzip_compress;
+        self
+    }
+
+    #[must_use]
+    pub fn zstd_compress(mut self, zstd_compress: bool) -> Self {
+        self.zstd_compress = zstd_compress;
+        self
+    }
+
+    /// Sets the zstd compression level (1-22, default [`message::compress::ZSTD_DEFAULT_LEVEL`]).
+    #[must_use]
+    pub fn zstd_compression_level(mut self, zstd_compression_level: i32) -> Self {
+        self.zstd_compression_level = zstd_compression_level;
+        self
+    }
+
+    /// Sets the policy gating whether a requested compression codec is
+    /// actually applied (default [`message::codec::CompressionPolicy::Always`]).
+    #[must_use]
+    pub fn compression_policy(
+        mut self,
+        compression_policy: message::codec::CompressionPolicy,
+    ) -> Self {
+        self.compression_policy = compression_policy;
+        self
+    }
+
+    /// Compression codec this message is currently configured to use.
+    fn compression(&self) -> message::codec::Compression {
+        if self.zstd_compress {
+            message::codec::Compression::Zstd {
+                level: self.zstd_compression_level,
+            }
+        } else if self.gzip_compress {
+            message::codec::Compression::Gzip
+        } else {
+            message::codec::Compression::None
+        }
+    }
+
+    pub fn serialize(&self) -> io::Result<Vec<u8>> {
+        P2pMessage::serialize(self, self.compression(), self.compression_policy)
+    }
+
+    pub fn deserialize(d: impl AsRef<[u8]>) -> io::Result<Self> {
+        message::codec::deserialize(d)
+    }
+}
+
+impl P2pMessage for Message {
+    fn into_inner(self) -> p2p::message::Message {
+        p2p::message::Message::Pong(self.msg)
+    }
+
+    fn from_inner(inner: p2p::message::Message) -> Option<Self> {
+        match inner {
+            p2p::message::Message::Pong(msg) => Some(Message {
+                msg,
+                gzip_compress: false,
+                zstd_compress: false,
+                zstd_compression_level: message::compress::ZSTD_DEFAULT_LEVEL,
+                compression_policy: message::codec::CompressionPolicy::default(),
+            }),
+            _ => None,
+        }
+    }
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- message::pong::test_message --exact --show-output
+#[test]
+fn test_message() {
+    let _ = env_logger::builder()
+        .filter_level(log::LevelFilter::Debug)
+        .is_test(true)
+        .try_init();
+
+    let msg1_with_no_compression = Message::default().uptime(99);
+
+    let data1 = msg1_with_no_compression.serialize().unwrap();
+    let msg1_with_no_compression_deserialized = Message::deserialize(&data1).unwrap();
+    assert_eq!(
+        msg1_with_no_compression,
+        msg1_with_no_compression_deserialized
+    );
+
+    let msg2_with_compression = msg1_with_no_compression.clone().gzip_compress(true);
+    assert_ne!(msg1_with_no_compression, msg2_with_compression);
+
+    let data2 = msg2_with_compression.serialize().unwrap();
+    let msg2_with_compression_deserialized = Message::deserialize(&data2).unwrap();
+    assert_eq!(msg1_with_no_compression, msg2_with_compression_deserialized);
+}
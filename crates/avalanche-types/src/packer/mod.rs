@@ -621,6 +621,39 @@ impl Packer {
         };
         Ok(s)
     }
+
+    /// Reads a 4-byte big-endian integer without advancing the cursor, so a
+    /// driver can dispatch on an interface type ID before delegating to the
+    /// concrete component's [`Unpackable`] implementation.
+    pub fn unpack_peek_u32(&self) -> Result<u32> {
+        let offset = self.offset.get();
+        let v = self.unpack_u32()?;
+        self.offset.set(offset);
+        Ok(v)
+    }
+
+    /// Packs a value that knows how to serialize itself.
+    pub fn pack<T: Packable>(&self, v: &T) -> Result<()> {
+        v.pack(self)
+    }
+
+    /// Unpacks a value that knows how to deserialize itself.
+    pub fn unpack<T: Unpackable>(&self) -> Result<T> {
+        T::unpack(self)
+    }
+}
+
+/// A type that serializes itself into a [`Packer`] using the AvalancheGo codec
+/// byte layout. Implementing it keeps each tx component's byte layout local to
+/// the component instead of hand-inlined in the driver.
+/// ref. "avalanchego/codec/reflectcodec.genericCodec.marshal"
+pub trait Packable {
+    fn pack(&self, packer: &Packer) -> Result<()>;
+}
+
+/// The inverse of [`Packable`]: reconstructs a value from a [`Packer`] cursor.
+pub trait Unpackable: Sized {
+    fn unpack(packer: &Packer) -> Result<Self>;
 }
 
 /// RUST_LOG=debug cargo test --package avalanche-types --lib -- packer::test_expand --exact --show-output
@@ -1,4 +1,9 @@
-use crate::{codec, errors::Result, hash, ids, key, platformvm, txs};
+use crate::{
+    codec,
+    errors::{Error, Result},
+    hash, ids, key, packer, platformvm,
+    txs::{self, Signable},
+};
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
@@ -82,23 +87,163 @@ impl Tx {
         *(codec::P_TYPES.get(&Self::type_name()).unwrap()) as u32
     }
 
-    /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm/txs#Tx.Sign>
-    /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/utils/crypto#PrivateKeyED25519.SignHash>
-    pub async fn sign<T: key::secp256k1::SignOnly>(&mut self, signers: Vec<Vec<T>>) -> Result<()> {
-        // marshal "unsigned tx" with the codec version
-        let type_id = Self::type_id();
-        let packer = self.base_tx.pack(codec::VERSION, type_id)?;
-
-        // "avalanchego" marshals the whole struct again for signed bytes
-        // even when the underlying "unsigned_tx" is already once marshaled
-        // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm#Tx.Sign
-        //
-        // reuse the underlying packer to avoid marshaling the unsigned tx twice
-        // just marshal the next fields in the struct and pack them all together
-        // in the existing packer
+    /// Packs the unsigned portion of the tx (base tx, validator, and subnet
+    /// auth) -- everything `sign` hashes, up to but not including the
+    /// credentials -- so `unpack` can recompute the unsigned bytes for the
+    /// rebuilt [`txs::Metadata`].
+    fn pack_unsigned(&self) -> Result<Vec<u8>> {
+        let packer = self.base_tx.pack(codec::VERSION, Self::type_id())?;
         let unsigned_tx_bytes = packer.take_bytes();
         packer.set_bytes(&unsigned_tx_bytes);
+        <Self as txs::Signable>::pack_unsigned_fields(self, &packer)?;
+        Ok(packer.take_bytes().to_vec())
+    }
+
+    /// Inverse of the signing marshaling: parses raw signed bytes back into a
+    /// typed `AddSubnetValidatorTx`, rebuilding `base_tx`, `validator`,
+    /// `subnet_auth`, and `creds`, and repopulating [`txs::Metadata`].
+    ///
+    /// The leading 2-byte codec version and 4-byte type id are read and
+    /// validated first -- an unsupported version or a type id other than
+    /// "platformvm.AddSubnetValidatorTx" is rejected rather than silently
+    /// misparsed. `unpack(sign(..)) == input` holds byte-for-byte.
+    pub fn unpack(bytes: &[u8]) -> Result<Self> {
+        let packer = packer::Packer::load_bytes_for_unpack((1 << 31) - 1, bytes);
+
+        let codec_version = packer.unpack_u16()?;
+        if !txs::SUPPORTED_CODEC_VERSIONS.contains(&codec_version) {
+            return Err(Error::Other {
+                message: format!("unsupported codec version {codec_version}"),
+                retryable: false,
+            });
+        }
+        let type_id = packer.unpack_u32()?;
+        if type_id != Self::type_id() {
+            return Err(Error::Other {
+                message: format!(
+                    "unexpected type ID {type_id} for AddSubnetValidatorTx (expected {})",
+                    Self::type_id()
+                ),
+                retryable: false,
+            });
+        }
+
+        // "avax.BaseTx" body (the codec header was already consumed above)
+        let network_id = packer.unpack_u32()?;
+        let blockchain_id = ids::Id::from_slice(&packer.unpack_bytes(ids::LEN)?);
+
+        let outs_len = packer.unpack_u32()? as usize;
+        let mut transferable_outputs = Vec::with_capacity(outs_len);
+        for _ in 0..outs_len {
+            transferable_outputs.push(packer.unpack()?);
+        }
+
+        let ins_len = packer.unpack_u32()? as usize;
+        let mut transferable_inputs = Vec::with_capacity(ins_len);
+        for _ in 0..ins_len {
+            transferable_inputs.push(packer.unpack()?);
+        }
+
+        let memo_len = packer.unpack_u32()? as usize;
+        let memo = if memo_len > 0 {
+            Some(packer.unpack_bytes(memo_len)?)
+        } else {
+            None
+        };
+
+        let base_tx = txs::Tx {
+            network_id,
+            blockchain_id,
+            transferable_outputs: (outs_len > 0).then_some(transferable_outputs),
+            transferable_inputs: (ins_len > 0).then_some(transferable_inputs),
+            memo,
+            ..txs::Tx::default()
+        };
+
+        // "validator" field
+        let node_id = ids::node::Id::from_slice(&packer.unpack_bytes(ids::node::LEN)?);
+        let start = packer.unpack_u64()?;
+        let end = packer.unpack_u64()?;
+        let weight = packer.unpack_u64()?;
+        let subnet_id = ids::Id::from_slice(&packer.unpack_bytes(ids::LEN)?);
+        let validator = Validator {
+            validator: platformvm::txs::Validator {
+                node_id,
+                start,
+                end,
+                weight,
+            },
+            subnet_id,
+        };
+
+        // "subnet_auth" field (secp256k1fx.Input)
+        let subnet_auth_type_id = packer.unpack_u32()?;
+        if subnet_auth_type_id != key::secp256k1::txs::Input::type_id() {
+            return Err(Error::Other {
+                message: format!("unexpected type ID {subnet_auth_type_id} for subnet auth Input"),
+                retryable: false,
+            });
+        }
+        let sig_len = packer.unpack_u32()? as usize;
+        let mut sig_indices = Vec::with_capacity(sig_len);
+        for _ in 0..sig_len {
+            sig_indices.push(packer.unpack_u32()?);
+        }
+        let subnet_auth = key::secp256k1::txs::Input { sig_indices };
 
+        // "creds" field
+        let creds_len = packer.unpack_u32()? as usize;
+        let mut creds = Vec::with_capacity(creds_len);
+        for _ in 0..creds_len {
+            let cred_type_id = packer.unpack_u32()?;
+            if cred_type_id != key::secp256k1::txs::Credential::type_id() {
+                return Err(Error::Other {
+                    message: format!("unexpected type ID {cred_type_id} for Credential"),
+                    retryable: false,
+                });
+            }
+            let sigs_len = packer.unpack_u32()? as usize;
+            let mut signatures = Vec::with_capacity(sigs_len);
+            for _ in 0..sigs_len {
+                signatures.push(packer.unpack_bytes(key::secp256k1::signature::LEN)?);
+            }
+            creds.push(key::secp256k1::txs::Credential::new(signatures));
+        }
+
+        let mut tx = Self {
+            base_tx,
+            validator,
+            subnet_auth,
+            creds,
+        };
+
+        // repopulate "avax.BaseTx.Metadata" from the decoded bytes
+        let tx_bytes_with_no_signature = tx.pack_unsigned()?;
+        let tx_id = hash::sha256(bytes);
+        tx.base_tx.metadata = Some(txs::Metadata {
+            id: ids::Id::from_slice(&tx_id),
+            tx_bytes_with_no_signature,
+            tx_bytes_with_signatures: bytes.to_vec(),
+        });
+
+        Ok(tx)
+    }
+}
+
+impl txs::Signable for Tx {
+    fn type_id() -> u32 {
+        Self::type_id()
+    }
+
+    fn base_tx(&self) -> &txs::Tx {
+        &self.base_tx
+    }
+
+    fn base_tx_mut(&mut self) -> &mut txs::Tx {
+        &mut self.base_tx
+    }
+
+    fn pack_unsigned_fields(&self, packer: &packer::Packer) -> Result<()> {
         // pack the second field "validator" in the struct
         packer.pack_bytes(self.validator.validator.node_id.as_ref())?;
         packer.pack_u64(self.validator.validator.start)?;
@@ -113,67 +258,12 @@ impl Tx {
         for sig_idx in self.subnet_auth.sig_indices.iter() {
             packer.pack_u32(*sig_idx)?;
         }
-
-        // take bytes just for hashing computation
-        let tx_bytes_with_no_signature = packer.take_bytes();
-        packer.set_bytes(&tx_bytes_with_no_signature);
-
-        // compute sha256 for marshaled "unsigned tx" bytes
-        // IMPORTANT: take the hash only for the type "platformvm.AddValidatorTx" unsigned tx
-        // not other fields -- only hash "platformvm.AddValidatorTx.*" but not "platformvm.Tx.Creds"
-        // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm#UnsignedAddValidatorTx
-        let tx_bytes_hash = hash::sha256(&tx_bytes_with_no_signature);
-
-        // number of of credentials
-        let creds_len = signers.len() as u32;
-        // pack the fourth field in the struct
-        packer.pack_u32(creds_len)?;
-
-        // sign the hash with the signers (in case of multi-sig)
-        // and combine all signatures into a secp256k1fx credential
-        self.creds = Vec::new();
-        for keys in signers.iter() {
-            let mut sigs: Vec<Vec<u8>> = Vec::new();
-            for k in keys.iter() {
-                let sig = k.sign_digest(&tx_bytes_hash).await?;
-                sigs.push(Vec::from(sig));
-            }
-
-            let mut cred = key::secp256k1::txs::Credential::default();
-            cred.signatures = sigs;
-
-            // add a new credential to "Tx"
-            self.creds.push(cred);
-        }
-        if creds_len > 0 {
-            // pack each "cred" which is "secp256k1fx.Credential"
-            // marshal type ID for "secp256k1fx.Credential"
-            let cred_type_id = key::secp256k1::txs::Credential::type_id();
-            for cred in self.creds.iter() {
-                // marshal type ID for "secp256k1fx.Credential"
-                packer.pack_u32(cred_type_id)?;
-
-                // marshal fields for "secp256k1fx.Credential"
-                packer.pack_u32(cred.signatures.len() as u32)?;
-                for sig in cred.signatures.iter() {
-                    packer.pack_bytes(sig)?;
-                }
-            }
-        }
-        let tx_bytes_with_signatures = packer.take_bytes();
-        let tx_id = hash::sha256(&tx_bytes_with_signatures);
-
-        // update "BaseTx.Metadata" with id/unsigned bytes/bytes
-        // ref. "avalanchego/vms/platformvm.Tx.Sign"
-        // ref. "avalanchego/vms/components/avax.BaseTx.Metadata.Initialize"
-        self.base_tx.metadata = Some(txs::Metadata {
-            id: ids::Id::from_slice(&tx_id),
-            tx_bytes_with_no_signature: tx_bytes_with_no_signature.to_vec(),
-            tx_bytes_with_signatures: tx_bytes_with_signatures.to_vec(),
-        });
-
         Ok(())
     }
+
+    fn set_credentials(&mut self, creds: Vec<key::secp256k1::txs::Credential>) {
+        self.creds = creds;
+    }
 }
 
 /// RUST_LOG=debug cargo test --package avalanche-types --lib -- platformvm::txs::add_subnet_validator::test_add_subnet_validator_tx_serialization_with_one_signer --exact --show-output
@@ -427,3 +517,103 @@ fn test_add_subnet_validator_tx_serialization_with_one_signer() {
         &tx_bytes_with_signatures
     ));
 }
+
+macro_rules! ab {
+    ($e:expr) => {
+        tokio_test::block_on($e)
+    };
+}
+
+/// A small validator tx with no inputs/outputs, used to exercise the decode
+/// path without depending on the output/input type-id registry.
+fn round_trip_tx() -> Tx {
+    use crate::ids::node;
+    Tx {
+        base_tx: txs::Tx {
+            network_id: 1000000,
+            blockchain_id: ids::Id::from_slice(&[0x05; ids::LEN]),
+            memo: Some(vec![0x01, 0x02, 0x03]),
+            ..txs::Tx::default()
+        },
+        validator: Validator {
+            validator: platformvm::txs::Validator {
+                node_id: node::Id::from_slice(&[0x11; node::LEN]),
+                start: 0x623d424b,
+                end: 0x641e6651,
+                weight: 0x3e8,
+            },
+            subnet_id: ids::Id::from_slice(&[0x22; ids::LEN]),
+        },
+        subnet_auth: key::secp256k1::txs::Input {
+            sig_indices: vec![0_u32, 1_u32],
+        },
+        ..Tx::default()
+    }
+}
+
+fn round_trip_key() -> key::secp256k1::private_key::Key {
+    key::secp256k1::private_key::Key::from_cb58(
+        "PrivateKey-2kqWNDaqUKQyE4ZsV5GLCGeizE6sHAJVyjnfjXoXrtcZpK9M67",
+    )
+    .expect("failed to load private key")
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- platformvm::txs::add_subnet_validator::test_add_subnet_validator_tx_unpack_round_trip --exact --show-output
+#[test]
+fn test_add_subnet_validator_tx_unpack_round_trip() {
+    let mut tx = round_trip_tx();
+    let k = round_trip_key();
+    let signers = vec![vec![k.clone()], vec![k]];
+    ab!(tx.sign(signers)).expect("failed to sign");
+
+    let signed = tx.base_tx.metadata.clone().unwrap().tx_bytes_with_signatures;
+    let decoded = Tx::unpack(&signed).expect("failed to unpack");
+
+    // fully reconstructed, metadata included (ids are sha256 of the same bytes)
+    assert_eq!(decoded, tx);
+    assert_eq!(decoded.creds.len(), 2);
+
+    // pack -> unpack -> pack is byte-identical
+    let re_signed = decoded
+        .base_tx
+        .metadata
+        .clone()
+        .unwrap()
+        .tx_bytes_with_signatures;
+    assert!(cmp_manager::eq_vectors(&signed, &re_signed));
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- platformvm::txs::add_subnet_validator::test_add_subnet_validator_tx_unpack_zero_credentials --exact --show-output
+#[test]
+fn test_add_subnet_validator_tx_unpack_zero_credentials() {
+    // signing with no signers leaves the unsigned tx with zero credentials
+    let mut tx = round_trip_tx();
+    ab!(tx.sign::<key::secp256k1::private_key::Key>(Vec::new())).expect("failed to sign");
+
+    let signed = tx.base_tx.metadata.clone().unwrap().tx_bytes_with_signatures;
+    let decoded = Tx::unpack(&signed).expect("failed to unpack");
+    assert!(decoded.creds.is_empty());
+    assert_eq!(decoded, tx);
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- platformvm::txs::add_subnet_validator::test_add_subnet_validator_tx_unpack_rejects_malformed --exact --show-output
+#[test]
+fn test_add_subnet_validator_tx_unpack_rejects_malformed() {
+    let mut tx = round_trip_tx();
+    let k = round_trip_key();
+    ab!(tx.sign(vec![vec![k]])).expect("failed to sign");
+    let signed = tx.base_tx.metadata.clone().unwrap().tx_bytes_with_signatures;
+
+    // truncated input stops mid-field
+    assert!(Tx::unpack(&signed[..signed.len() - 3]).is_err());
+
+    // unsupported codec version in the leading 2 bytes
+    let mut bad_version = signed.clone();
+    bad_version[1] = 0xff;
+    assert!(Tx::unpack(&bad_version).is_err());
+
+    // wrong type id in the following 4 bytes
+    let mut bad_type = signed.clone();
+    bad_type[5] = 0xff;
+    assert!(Tx::unpack(&bad_type).is_err());
+}
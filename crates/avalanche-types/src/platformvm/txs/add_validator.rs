@@ -126,6 +126,133 @@ impl Tx {
 
         Ok(())
     }
+
+    /// Returns the SHA-256 hash of the marshaled unsigned transaction, i.e. the
+    /// digest an external signer (e.g. a hardware wallet) must sign. This is the
+    /// same value [`Tx::sign`] signs internally, exposed so callers holding keys
+    /// outside this process can produce the signatures out-of-band and then call
+    /// [`Tx::attach_credentials`].
+    pub fn unsigned_tx_bytes_hash(&self) -> Result<Vec<u8>> {
+        let packer = Packer::new();
+        packer.pack(self)?;
+        let tx_bytes_with_no_signature = packer.take_bytes();
+        Ok(hash::sha256(&tx_bytes_with_no_signature).to_vec())
+    }
+
+    /// Attaches credentials produced by an external signer and finalizes the
+    /// transaction, mirroring the packing [`Tx::sign`] performs after signing.
+    ///
+    /// `signatures` is ordered the same way the `signers` argument of
+    /// [`Tx::sign`] is: one inner `Vec` per credential, each holding the raw
+    /// 65-byte signatures for that credential's signers. The signatures must be
+    /// over [`Tx::unsigned_tx_bytes_hash`].
+    pub fn attach_credentials(&mut self, signatures: Vec<Vec<Vec<u8>>>) -> Result<()> {
+        let packer = Packer::new();
+        packer.pack(self)?;
+        let tx_bytes_with_no_signature = packer.take_bytes();
+        packer.set_bytes(&tx_bytes_with_no_signature);
+
+        let creds_len = signatures.len() as u32;
+        packer.pack_u32(creds_len)?;
+
+        self.creds = Vec::new();
+        for sigs in signatures.into_iter() {
+            self.creds
+                .push(key::secp256k1::txs::Credential { signatures: sigs });
+        }
+        if creds_len > 0 {
+            let cred_type_id = key::secp256k1::txs::Credential::type_id();
+            for cred in self.creds.iter() {
+                packer.pack_u32(cred_type_id)?;
+                packer.pack_u32(cred.signatures.len() as u32)?;
+                for sig in cred.signatures.iter() {
+                    packer.pack_bytes(sig)?;
+                }
+            }
+        }
+        let tx_bytes_with_signatures = packer.take_bytes();
+        let tx_id = hash::sha256(&tx_bytes_with_signatures);
+
+        self.base_tx.metadata = Some(txs::Metadata {
+            id: ids::Id::from_slice(&tx_id),
+            tx_bytes_with_no_signature: tx_bytes_with_no_signature.to_vec(),
+            tx_bytes_with_signatures: tx_bytes_with_signatures.to_vec(),
+        });
+
+        Ok(())
+    }
+}
+
+/// The denominator of the `shares` field; `shares` is a fraction of
+/// `1_000_000`, so a delegation fee of 100% is `1_000_000`.
+/// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm/reward#PercentDenominator>
+pub const PERCENT_DENOMINATOR: u32 = 1_000_000;
+
+/// A structured reason an [`Tx`] failed client-side validation, so callers can
+/// react to the specific problem instead of parsing a string. Catching these
+/// before submission avoids a wasted round-trip and a generic node error.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ValidationError {
+    /// `end` is not strictly after `start`.
+    EndNotAfterStart { start: u64, end: u64 },
+    /// The staked weight is zero.
+    ZeroWeight,
+    /// The delegation fee `shares` exceeds [`PERCENT_DENOMINATOR`].
+    SharesTooLarge { shares: u32 },
+    /// The rewards owner threshold exceeds the number of owner addresses.
+    ThresholdExceedsAddresses { threshold: u32, addresses: usize },
+}
+
+impl std::fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::EndNotAfterStart { start, end } => {
+                write!(f, "staking end {end} is not after start {start}")
+            }
+            Self::ZeroWeight => write!(f, "staked weight must be non-zero"),
+            Self::SharesTooLarge { shares } => {
+                write!(f, "shares {shares} exceeds denominator {PERCENT_DENOMINATOR}")
+            }
+            Self::ThresholdExceedsAddresses {
+                threshold,
+                addresses,
+            } => write!(
+                f,
+                "rewards owner threshold {threshold} exceeds addresses {addresses}"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ValidationError {}
+
+impl Tx {
+    /// Validates the staking parameters client-side before submission,
+    /// returning the first [`ValidationError`] encountered.
+    pub fn validate(&self) -> std::result::Result<(), ValidationError> {
+        if self.validator.end <= self.validator.start {
+            return Err(ValidationError::EndNotAfterStart {
+                start: self.validator.start,
+                end: self.validator.end,
+            });
+        }
+        if self.validator.weight == 0 {
+            return Err(ValidationError::ZeroWeight);
+        }
+        if self.shares > PERCENT_DENOMINATOR {
+            return Err(ValidationError::SharesTooLarge {
+                shares: self.shares,
+            });
+        }
+        let addresses = self.rewards_owner.addresses.len();
+        if (self.rewards_owner.threshold as usize) > addresses {
+            return Err(ValidationError::ThresholdExceedsAddresses {
+                threshold: self.rewards_owner.threshold,
+                addresses,
+            });
+        }
+        Ok(())
+    }
 }
 
 impl packer::Packable for Tx {
@@ -1,7 +1,8 @@
 use crate::{
     codec,
     errors::{Error, Result},
-    hash, ids, key, platformvm, txs,
+    ids, key, packer, platformvm,
+    txs::{self, Signable},
 };
 use serde::{Deserialize, Serialize};
 
@@ -64,24 +65,22 @@ impl Tx {
     pub fn type_id() -> u32 {
         *(codec::P_TYPES.get(&Self::type_name()).unwrap()) as u32
     }
+}
 
-    /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm/txs#Tx.Sign>
-    /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/utils/crypto#PrivateKeyED25519.SignHash>
-    pub async fn sign<T: key::secp256k1::SignOnly>(&mut self, signers: Vec<Vec<T>>) -> Result<()> {
-        // marshal "unsigned tx" with the codec version
-        let type_id = Self::type_id();
-        let packer = self.base_tx.pack(codec::VERSION, type_id)?;
+impl txs::Signable for Tx {
+    fn type_id() -> u32 {
+        Self::type_id()
+    }
 
-        // "avalanchego" marshals the whole struct again for signed bytes
-        // even when the underlying "unsigned_tx" is already once marshaled
-        // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm#Tx.Sign
-        //
-        // reuse the underlying packer to avoid marshaling the unsigned tx twice
-        // just marshal the next fields in the struct and pack them all together
-        // in the existing packer
-        let base = packer.take_bytes();
-        packer.set_bytes(&base);
+    fn base_tx(&self) -> &txs::Tx {
+        &self.base_tx
+    }
 
+    fn base_tx_mut(&mut self) -> &mut txs::Tx {
+        &mut self.base_tx
+    }
+
+    fn pack_unsigned_fields(&self, packer: &packer::Packer) -> Result<()> {
         // pack the second field in the struct
         packer.pack_bytes(self.destination_chain_id.as_ref())?;
 
@@ -199,66 +198,232 @@ impl Tx {
         } else {
             packer.pack_u32(0_u32)?;
         }
+        Ok(())
+    }
 
-        // take bytes just for hashing computation
-        let tx_bytes_with_no_signature = packer.take_bytes();
-        packer.set_bytes(&tx_bytes_with_no_signature);
-
-        // compute sha256 for marshaled "unsigned tx" bytes
-        // IMPORTANT: take the hash only for the type "platformvm.ExportTx" unsigned tx
-        // not other fields -- only hash "platformvm.ExportTx.*" but not "platformvm.Tx.Creds"
-        // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm#UnsignedExportTx
-        let tx_bytes_hash = hash::sha256(&tx_bytes_with_no_signature);
-
-        // number of of credentials
-        let creds_len = signers.len() as u32;
-        // pack the fourth field in the struct
-        packer.pack_u32(creds_len)?;
-
-        // sign the hash with the signers (in case of multi-sig)
-        // and combine all signatures into a secp256k1fx credential
-        self.creds = Vec::new();
-        for keys in signers.iter() {
-            let mut sigs: Vec<Vec<u8>> = Vec::new();
-            for k in keys.iter() {
-                let sig = k.sign_digest(&tx_bytes_hash).await?;
-                sigs.push(Vec::from(sig));
-            }
+    fn set_credentials(&mut self, creds: Vec<key::secp256k1::txs::Credential>) {
+        self.creds = creds;
+    }
+}
+
+impl txs::Decodable for Tx {
+    fn type_id() -> u32 {
+        Self::type_id()
+    }
 
-            let mut cred = key::secp256k1::txs::Credential::default();
-            cred.signatures = sigs;
+    fn base_tx_mut(&mut self) -> &mut txs::Tx {
+        &mut self.base_tx
+    }
 
-            // add a new credential to "Tx"
-            self.creds.push(cred);
+    fn unpack_unsigned_fields(packer: &packer::Packer, base_tx: txs::Tx) -> Result<Self> {
+        // inverse of the second field in "pack_unsigned_fields"
+        let destination_chain_id = ids::Id::from_slice(&packer.unpack_bytes(ids::LEN)?);
+
+        // inverse of the third field; each transferable output decodes itself
+        // through its own [`packer::Unpackable`] impl (type IDs 7 and 22),
+        // matching the switch in the pack path
+        let outs_len = packer.unpack_u32()? as usize;
+        let mut destination_chain_transferable_outputs = Vec::with_capacity(outs_len);
+        for _ in 0..outs_len {
+            destination_chain_transferable_outputs.push(packer.unpack()?);
         }
-        if creds_len > 0 {
-            // pack each "cred" which is "secp256k1fx.Credential"
-            // marshal type ID for "secp256k1fx.Credential"
-            let cred_type_id = key::secp256k1::txs::Credential::type_id();
-            for cred in self.creds.iter() {
-                // marshal type ID for "secp256k1fx.Credential"
-                packer.pack_u32(cred_type_id)?;
-
-                // marshal fields for "secp256k1fx.Credential"
-                packer.pack_u32(cred.signatures.len() as u32)?;
-                for sig in cred.signatures.iter() {
-                    packer.pack_bytes(sig)?;
-                }
+
+        Ok(Self {
+            base_tx,
+            destination_chain_id,
+            destination_chain_transferable_outputs: (outs_len > 0)
+                .then_some(destination_chain_transferable_outputs),
+            creds: Vec::new(),
+        })
+    }
+
+    fn set_credentials(&mut self, creds: Vec<key::secp256k1::txs::Credential>) {
+        <Self as txs::Signable>::set_credentials(self, creds);
+    }
+}
+
+/// Builds a signed [`Tx`] from an explicit set of spendable UTXOs, rather
+/// than fetching them over RPC like [`crate::wallet::p::export::Tx`] does.
+///
+/// Selects unlocked AVAX UTXOs to cover "amount + fee", sorts the consumed
+/// inputs and the resulting outputs into the canonical order the codec
+/// requires, returns any unspent remainder to "change_owner", and signs the
+/// transaction via the keychain.
+/// ref. <https://github.com/ava-labs/avalanchego/blob/v1.9.4/wallet/chain/p/builder.go> "NewExportTx"
+pub struct ExportTxBuilder<T>
+where
+    T: key::secp256k1::ReadOnly + key::secp256k1::SignOnly + Clone,
+{
+    pub keychain: key::secp256k1::keychain::Keychain<T>,
+    pub utxos: Vec<txs::utxo::Utxo>,
+
+    pub network_id: u32,
+    pub blockchain_id: ids::Id,
+    pub avax_asset_id: ids::Id,
+
+    pub destination_chain_id: ids::Id,
+    pub amount: u64,
+    pub destination_owner: key::secp256k1::txs::OutputOwners,
+
+    /// Owner of any unspent remainder, returned as a change output on this chain.
+    pub change_owner: key::secp256k1::txs::OutputOwners,
+    pub fee: u64,
+}
+
+impl<T> ExportTxBuilder<T>
+where
+    T: key::secp256k1::ReadOnly + key::secp256k1::SignOnly + Clone,
+{
+    pub fn new(
+        keychain: key::secp256k1::keychain::Keychain<T>,
+        utxos: Vec<txs::utxo::Utxo>,
+        network_id: u32,
+        blockchain_id: ids::Id,
+        avax_asset_id: ids::Id,
+    ) -> Self {
+        Self {
+            keychain,
+            utxos,
+            network_id,
+            blockchain_id,
+            avax_asset_id,
+            destination_chain_id: ids::Id::empty(),
+            amount: 0,
+            destination_owner: key::secp256k1::txs::OutputOwners::default(),
+            change_owner: key::secp256k1::txs::OutputOwners::default(),
+            fee: 0,
+        }
+    }
+
+    /// Sets the destination blockchain Id.
+    #[must_use]
+    pub fn destination_chain_id(mut self, destination_chain_id: ids::Id) -> Self {
+        self.destination_chain_id = destination_chain_id;
+        self
+    }
+
+    /// Sets the amount to export.
+    #[must_use]
+    pub fn amount(mut self, amount: u64) -> Self {
+        self.amount = amount;
+        self
+    }
+
+    /// Sets the owner of the exported amount on the destination chain.
+    #[must_use]
+    pub fn destination_owner(
+        mut self,
+        destination_owner: key::secp256k1::txs::OutputOwners,
+    ) -> Self {
+        self.destination_owner = destination_owner;
+        self
+    }
+
+    /// Sets the owner of the change output returned on this chain.
+    #[must_use]
+    pub fn change_owner(mut self, change_owner: key::secp256k1::txs::OutputOwners) -> Self {
+        self.change_owner = change_owner;
+        self
+    }
+
+    /// Sets the network fee.
+    #[must_use]
+    pub fn fee(mut self, fee: u64) -> Self {
+        self.fee = fee;
+        self
+    }
+
+    /// Builds and signs the export transaction.
+    pub async fn build(&self) -> Result<Tx> {
+        let now_unix = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .expect("unexpected None duration_since")
+            .as_secs();
+
+        let target = self.amount.checked_add(self.fee).ok_or(Error::Other {
+            message: "amount + fee overflowed u64".to_string(),
+            retryable: false,
+        })?;
+
+        let mut ins: Vec<txs::transferable::Input> = Vec::new();
+        let mut amount_consumed = 0_u64;
+
+        for utxo in self.utxos.iter() {
+            if amount_consumed >= target {
+                break;
             }
+            if utxo.asset_id != self.avax_asset_id {
+                continue;
+            }
+
+            let out = match &utxo.transfer_output {
+                // locked ("StakeableLockOut") UTXOs are not spendable for a plain export
+                Some(out) if out.output_owners.locktime <= now_unix => out,
+                _ => continue,
+            };
+            let (transfer_input, _) = match self.keychain.spend(out, now_unix) {
+                Some(spent) => spent,
+                None => continue,
+            };
+
+            amount_consumed += transfer_input.amount;
+            ins.push(txs::transferable::Input {
+                utxo_id: utxo.utxo_id.clone(),
+                asset_id: utxo.asset_id,
+                transfer_input: Some(transfer_input),
+                ..txs::transferable::Input::default()
+            });
         }
-        let tx_bytes_with_signatures = packer.take_bytes();
-        let tx_id = hash::sha256(&tx_bytes_with_signatures);
-
-        // update "BaseTx.Metadata" with id/unsigned bytes/bytes
-        // ref. "avalanchego/vms/platformvm.Tx.Sign"
-        // ref. "avalanchego/vms/components/avax.BaseTx.Metadata.Initialize"
-        self.base_tx.metadata = Some(txs::Metadata {
-            id: ids::Id::from_slice(&tx_id),
-            tx_bytes_with_no_signature: tx_bytes_with_no_signature.to_vec(),
-            tx_bytes_with_signatures: tx_bytes_with_signatures.to_vec(),
-        });
 
-        Ok(())
+        if amount_consumed < target {
+            return Err(Error::Other {
+                message: format!(
+                    "insufficient funds: need {} but only {} spendable",
+                    target, amount_consumed
+                ),
+                retryable: false,
+            });
+        }
+        ins.sort();
+
+        let destination_outputs = vec![txs::transferable::Output {
+            asset_id: self.avax_asset_id,
+            transfer_output: Some(key::secp256k1::txs::transfer::Output {
+                amount: self.amount,
+                output_owners: self.destination_owner.clone(),
+            }),
+            ..txs::transferable::Output::default()
+        }];
+
+        let change = amount_consumed - target;
+        let mut change_outputs: Vec<txs::transferable::Output> = Vec::new();
+        if change > 0 {
+            change_outputs.push(txs::transferable::Output {
+                asset_id: self.avax_asset_id,
+                transfer_output: Some(key::secp256k1::txs::transfer::Output {
+                    amount: change,
+                    output_owners: self.change_owner.clone(),
+                }),
+                ..txs::transferable::Output::default()
+            });
+        }
+        change_outputs.sort();
+
+        let mut tx = Tx {
+            base_tx: txs::Tx {
+                network_id: self.network_id,
+                blockchain_id: self.blockchain_id,
+                transferable_inputs: Some(ins),
+                transferable_outputs: (!change_outputs.is_empty()).then_some(change_outputs),
+                ..txs::Tx::default()
+            },
+            destination_chain_id: self.destination_chain_id,
+            destination_chain_transferable_outputs: Some(destination_outputs),
+            ..Tx::default()
+        };
+
+        self.keychain.sign(&mut tx, &self.utxos, now_unix).await?;
+        Ok(tx)
     }
 }
 
@@ -459,3 +624,222 @@ fn test_export_tx_serialization_with_one_signer() {
         &tx_bytes_with_signatures
     ));
 }
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- platformvm::txs::export::test_export_tx_unpack_round_trip --exact --show-output
+#[test]
+fn test_export_tx_unpack_round_trip() {
+    use crate::{ids::short, txs::Decodable};
+
+    macro_rules! ab {
+        ($e:expr) => {
+            tokio_test::block_on($e)
+        };
+    }
+
+    let target_short_addr = short::Id::from_slice(&[0x55; 20]);
+
+    let mut tx = Tx {
+        base_tx: txs::Tx {
+            network_id: 10,
+            transferable_inputs: Some(vec![txs::transferable::Input {
+                utxo_id: txs::utxo::Id {
+                    id: ids::Id::from_slice(&[0x11; ids::LEN]),
+                    ..txs::utxo::Id::default()
+                },
+                asset_id: ids::Id::from_slice(&[0x22; ids::LEN]),
+                transfer_input: Some(key::secp256k1::txs::transfer::Input {
+                    amount: 500000000,
+                    sig_indices: vec![0],
+                }),
+                ..txs::transferable::Input::default()
+            }]),
+            memo: Some(vec![0x00, 0x01, 0x02, 0x03]),
+            ..txs::Tx::default()
+        },
+        destination_chain_id: ids::Id::from_slice(&[0x33; ids::LEN]),
+        destination_chain_transferable_outputs: Some(vec![txs::transferable::Output {
+            asset_id: ids::Id::from_slice(&[0x22; ids::LEN]),
+            transfer_output: Some(key::secp256k1::txs::transfer::Output {
+                amount: 499999900,
+                output_owners: key::secp256k1::txs::OutputOwners {
+                    locktime: 0,
+                    threshold: 1,
+                    addresses: vec![target_short_addr],
+                },
+            }),
+            ..txs::transferable::Output::default()
+        }]),
+        ..Tx::default()
+    };
+
+    let test_key = key::secp256k1::private_key::Key::from_cb58(
+        "PrivateKey-24jUJ9vZexUM6expyMcT48LBx27k1m7xpraoV62oSQAHdziao5",
+    )
+    .expect("failed to load private key");
+    let signers: Vec<Vec<key::secp256k1::private_key::Key>> = vec![vec![test_key]];
+    ab!(tx.sign(signers)).expect("failed to sign");
+
+    let signed = tx
+        .base_tx
+        .metadata
+        .clone()
+        .unwrap()
+        .tx_bytes_with_signatures;
+    let decoded = Tx::from_signed_bytes(&signed).expect("failed to decode");
+
+    // fully reconstructed, metadata included
+    assert_eq!(decoded, tx);
+    assert_eq!(decoded.creds.len(), 1);
+
+    // decode -> encode is byte-identical
+    let re_signed = decoded
+        .base_tx
+        .metadata
+        .clone()
+        .unwrap()
+        .tx_bytes_with_signatures;
+    assert!(cmp_manager::eq_vectors(&signed, &re_signed));
+
+    // a wrong type ID in the header is rejected
+    let mut bad_type = signed.clone();
+    bad_type[5] = bad_type[5].wrapping_add(1);
+    assert!(Tx::from_signed_bytes(&bad_type).is_err());
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- platformvm::txs::export::test_export_tx_builder_selects_inputs_and_signs --exact --show-output
+#[test]
+fn test_export_tx_builder_selects_inputs_and_signs() {
+    use crate::key::secp256k1::{keychain::Keychain, private_key, txs::OutputOwners};
+
+    let test_key = private_key::Key::from_cb58(
+        "PrivateKey-24jUJ9vZexUM6expyMcT48LBx27k1m7xpraoV62oSQAHdziao5",
+    )
+    .expect("failed to load private key");
+    let owner_addr = test_key
+        .to_public_key()
+        .to_short_id()
+        .expect("failed to_short_id");
+    let dest_addr = crate::ids::short::Id::from_slice(&[0x77; 20]);
+    let avax_asset_id = ids::Id::from_slice(&[0x22; ids::LEN]);
+
+    let spendable_owners = OutputOwners {
+        locktime: 0,
+        threshold: 1,
+        addresses: vec![owner_addr],
+    };
+    let utxos = vec![
+        txs::utxo::Utxo {
+            utxo_id: txs::utxo::Id {
+                tx_id: ids::Id::from_slice(&[0x11; ids::LEN]),
+                output_index: 0,
+                ..txs::utxo::Id::default()
+            },
+            asset_id: avax_asset_id,
+            transfer_output: Some(key::secp256k1::txs::transfer::Output {
+                amount: 300_000_000,
+                output_owners: spendable_owners.clone(),
+            }),
+            ..txs::utxo::Utxo::default()
+        },
+        txs::utxo::Utxo {
+            utxo_id: txs::utxo::Id {
+                tx_id: ids::Id::from_slice(&[0x11; ids::LEN]),
+                output_index: 1,
+                ..txs::utxo::Id::default()
+            },
+            asset_id: avax_asset_id,
+            transfer_output: Some(key::secp256k1::txs::transfer::Output {
+                amount: 300_000_000,
+                output_owners: spendable_owners,
+            }),
+            ..txs::utxo::Utxo::default()
+        },
+    ];
+
+    let keychain = Keychain::new(vec![test_key]);
+    let builder = ExportTxBuilder::new(
+        keychain,
+        utxos,
+        10,
+        ids::Id::from_slice(&[0x01; ids::LEN]),
+        avax_asset_id,
+    )
+    .destination_chain_id(ids::Id::from_slice(&[0x33; ids::LEN]))
+    .amount(400_000_000)
+    .destination_owner(OutputOwners {
+        locktime: 0,
+        threshold: 1,
+        addresses: vec![dest_addr],
+    })
+    .fee(100_000);
+
+    let tx = tokio_test::block_on(builder.build()).expect("failed to build export tx");
+
+    // both UTXOs were needed to cover "amount + fee"
+    assert_eq!(tx.base_tx.transferable_inputs.as_ref().unwrap().len(), 2);
+
+    // change is returned on this chain, not exported
+    let change_outs = tx.base_tx.transferable_outputs.as_ref().unwrap();
+    assert_eq!(change_outs.len(), 1);
+    assert_eq!(
+        change_outs[0].transfer_output.as_ref().unwrap().amount,
+        199_900_000
+    );
+
+    let dest_outs = tx.destination_chain_transferable_outputs.as_ref().unwrap();
+    assert_eq!(dest_outs.len(), 1);
+    assert_eq!(
+        dest_outs[0].transfer_output.as_ref().unwrap().amount,
+        400_000_000
+    );
+
+    // signed: one credential per consumed input
+    assert_eq!(tx.creds.len(), 2);
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- platformvm::txs::export::test_export_tx_builder_insufficient_funds --exact --show-output
+#[test]
+fn test_export_tx_builder_insufficient_funds() {
+    use crate::key::secp256k1::{keychain::Keychain, private_key, txs::OutputOwners};
+
+    let test_key = private_key::Key::from_cb58(
+        "PrivateKey-24jUJ9vZexUM6expyMcT48LBx27k1m7xpraoV62oSQAHdziao5",
+    )
+    .expect("failed to load private key");
+    let owner_addr = test_key
+        .to_public_key()
+        .to_short_id()
+        .expect("failed to_short_id");
+    let avax_asset_id = ids::Id::from_slice(&[0x22; ids::LEN]);
+
+    let utxos = vec![txs::utxo::Utxo {
+        utxo_id: txs::utxo::Id {
+            tx_id: ids::Id::from_slice(&[0x11; ids::LEN]),
+            output_index: 0,
+            ..txs::utxo::Id::default()
+        },
+        asset_id: avax_asset_id,
+        transfer_output: Some(key::secp256k1::txs::transfer::Output {
+            amount: 100,
+            output_owners: OutputOwners {
+                locktime: 0,
+                threshold: 1,
+                addresses: vec![owner_addr],
+            },
+        }),
+        ..txs::utxo::Utxo::default()
+    }];
+
+    let keychain = Keychain::new(vec![test_key]);
+    let builder = ExportTxBuilder::new(
+        keychain,
+        utxos,
+        10,
+        ids::Id::from_slice(&[0x01; ids::LEN]),
+        avax_asset_id,
+    )
+    .amount(1_000_000)
+    .fee(100);
+
+    assert!(tokio_test::block_on(builder.build()).is_err());
+}
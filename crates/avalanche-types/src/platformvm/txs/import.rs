@@ -1,7 +1,8 @@
 use crate::{
     codec,
     errors::{Error, Result},
-    hash, ids, key, platformvm, txs,
+    ids, key, packer, platformvm,
+    txs::{self, Signable},
 };
 use serde::{Deserialize, Serialize};
 
@@ -64,24 +65,22 @@ impl Tx {
     pub fn type_id() -> u32 {
         *(codec::P_TYPES.get(&Self::type_name()).unwrap()) as u32
     }
+}
 
-    /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm/txs#Tx.Sign>
-    /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/utils/crypto#PrivateKeyED25519.SignHash>
-    pub async fn sign<T: key::secp256k1::SignOnly>(&mut self, signers: Vec<Vec<T>>) -> Result<()> {
-        // marshal "unsigned tx" with the codec version
-        let type_id = Self::type_id();
-        let packer = self.base_tx.pack(codec::VERSION, type_id)?;
+impl txs::Signable for Tx {
+    fn type_id() -> u32 {
+        Self::type_id()
+    }
 
-        // "avalanchego" marshals the whole struct again for signed bytes
-        // even when the underlying "unsigned_tx" is already once marshaled
-        // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm#Tx.Sign
-        //
-        // reuse the underlying packer to avoid marshaling the unsigned tx twice
-        // just marshal the next fields in the struct and pack them all together
-        // in the existing packer
-        let base = packer.take_bytes();
-        packer.set_bytes(&base);
+    fn base_tx(&self) -> &txs::Tx {
+        &self.base_tx
+    }
 
+    fn base_tx_mut(&mut self) -> &mut txs::Tx {
+        &mut self.base_tx
+    }
+
+    fn pack_unsigned_fields(&self, packer: &packer::Packer) -> Result<()> {
         // pack the second field in the struct
         packer.pack_bytes(self.source_chain_id.as_ref())?;
 
@@ -191,66 +190,47 @@ impl Tx {
         } else {
             packer.pack_u32(0_u32)?;
         }
+        Ok(())
+    }
 
-        // take bytes just for hashing computation
-        let tx_bytes_with_no_signature = packer.take_bytes();
-        packer.set_bytes(&tx_bytes_with_no_signature);
-
-        // compute sha256 for marshaled "unsigned tx" bytes
-        // IMPORTANT: take the hash only for the type "platformvm.ImportTx" unsigned tx
-        // not other fields -- only hash "platformvm.ImportTx.*" but not "platformvm.Tx.Creds"
-        // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm#UnsignedImportTx
-        let tx_bytes_hash = hash::sha256(&tx_bytes_with_no_signature);
-
-        // number of of credentials
-        let creds_len = signers.len() as u32;
-        // pack the fourth field in the struct
-        packer.pack_u32(creds_len)?;
-
-        // sign the hash with the signers (in case of multi-sig)
-        // and combine all signatures into a secp256k1fx credential
-        self.creds = Vec::new();
-        for keys in signers.iter() {
-            let mut sigs: Vec<Vec<u8>> = Vec::new();
-            for k in keys.iter() {
-                let sig = k.sign_digest(&tx_bytes_hash).await?;
-                sigs.push(Vec::from(sig));
-            }
+    fn set_credentials(&mut self, creds: Vec<key::secp256k1::txs::Credential>) {
+        self.creds = creds;
+    }
+}
 
-            let mut cred = key::secp256k1::txs::Credential::default();
-            cred.signatures = sigs;
+impl txs::Decodable for Tx {
+    fn type_id() -> u32 {
+        Self::type_id()
+    }
 
-            // add a new credential to "Tx"
-            self.creds.push(cred);
-        }
-        if creds_len > 0 {
-            // pack each "cred" which is "secp256k1fx.Credential"
-            // marshal type ID for "secp256k1fx.Credential"
-            let cred_type_id = key::secp256k1::txs::Credential::type_id();
-            for cred in self.creds.iter() {
-                // marshal type ID for "secp256k1fx.Credential"
-                packer.pack_u32(cred_type_id)?;
-
-                // marshal fields for "secp256k1fx.Credential"
-                packer.pack_u32(cred.signatures.len() as u32)?;
-                for sig in cred.signatures.iter() {
-                    packer.pack_bytes(sig)?;
-                }
-            }
+    fn base_tx_mut(&mut self) -> &mut txs::Tx {
+        &mut self.base_tx
+    }
+
+    fn unpack_unsigned_fields(packer: &packer::Packer, base_tx: txs::Tx) -> Result<Self> {
+        // inverse of the second field in "pack_unsigned_fields"
+        let source_chain_id = ids::Id::from_slice(&packer.unpack_bytes(ids::LEN)?);
+
+        // inverse of the third field; each transferable input decodes itself
+        // through its own [`packer::Unpackable`] impl (type IDs 5 and 21),
+        // matching the switch in the pack path
+        let ins_len = packer.unpack_u32()? as usize;
+        let mut source_chain_transferable_inputs = Vec::with_capacity(ins_len);
+        for _ in 0..ins_len {
+            source_chain_transferable_inputs.push(packer.unpack()?);
         }
-        let tx_bytes_with_signatures = packer.take_bytes();
-        let tx_id = hash::sha256(&tx_bytes_with_signatures);
-
-        // update "BaseTx.Metadata" with id/unsigned bytes/bytes
-        // ref. "avalanchego/vms/platformvm.Tx.SignSECP256K1Fx"
-        // ref. "avalanchego/vms/components/avax.BaseTx.Metadata.Initialize"
-        self.base_tx.metadata = Some(txs::Metadata {
-            id: ids::Id::from_slice(&tx_id),
-            tx_bytes_with_no_signature: tx_bytes_with_no_signature.to_vec(),
-            tx_bytes_with_signatures: tx_bytes_with_signatures.to_vec(),
-        });
 
-        Ok(())
+        Ok(Self {
+            base_tx,
+            source_chain_id,
+            source_chain_transferable_inputs: (ins_len > 0)
+                .then_some(source_chain_transferable_inputs),
+            creds: Vec::new(),
+        })
+    }
+
+    fn set_credentials(&mut self, creds: Vec<key::secp256k1::txs::Credential>) {
+        <Self as txs::Signable>::set_credentials(self, creds);
     }
 }
 
@@ -410,3 +390,71 @@ fn test_import_tx_serialization_with_one_signer() {
         &tx_bytes_with_signatures
     ));
 }
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- platformvm::txs::import::test_import_tx_unpack_round_trip --exact --show-output
+#[test]
+fn test_import_tx_unpack_round_trip() {
+    use crate::txs::Decodable;
+
+    macro_rules! ab {
+        ($e:expr) => {
+            tokio_test::block_on($e)
+        };
+    }
+
+    let mut tx = Tx {
+        base_tx: txs::Tx {
+            network_id: 10,
+            memo: Some(vec![0x00, 0x01, 0x02, 0x03]),
+            ..txs::Tx::default()
+        },
+        source_chain_id: ids::Id::from_slice(&[0x33; ids::LEN]),
+        source_chain_transferable_inputs: Some(vec![txs::transferable::Input {
+            utxo_id: txs::utxo::Id {
+                tx_id: ids::Id::from_slice(&[0x11; ids::LEN]),
+                output_index: 1,
+                ..txs::utxo::Id::default()
+            },
+            asset_id: ids::Id::from_slice(&[0x22; ids::LEN]),
+            transfer_input: Some(key::secp256k1::txs::transfer::Input {
+                amount: 500000000,
+                sig_indices: vec![0],
+            }),
+            ..txs::transferable::Input::default()
+        }]),
+        ..Tx::default()
+    };
+
+    let test_key = key::secp256k1::private_key::Key::from_cb58(
+        "PrivateKey-24jUJ9vZexUM6expyMcT48LBx27k1m7xpraoV62oSQAHdziao5",
+    )
+    .expect("failed to load private key");
+    let signers: Vec<Vec<key::secp256k1::private_key::Key>> = vec![vec![test_key]];
+    ab!(tx.sign(signers)).expect("failed to sign");
+
+    let signed = tx
+        .base_tx
+        .metadata
+        .clone()
+        .unwrap()
+        .tx_bytes_with_signatures;
+    let decoded = Tx::from_signed_bytes(&signed).expect("failed to decode");
+
+    // fully reconstructed, metadata included
+    assert_eq!(decoded, tx);
+    assert_eq!(decoded.creds.len(), 1);
+
+    // decode -> encode is byte-identical
+    let re_signed = decoded
+        .base_tx
+        .metadata
+        .clone()
+        .unwrap()
+        .tx_bytes_with_signatures;
+    assert!(cmp_manager::eq_vectors(&signed, &re_signed));
+
+    // a wrong type ID in the header is rejected
+    let mut bad_type = signed.clone();
+    bad_type[5] = bad_type[5].wrapping_add(1);
+    assert!(Tx::from_signed_bytes(&bad_type).is_err());
+}
@@ -195,6 +195,34 @@ impl StakeableLockIn {
     }
 }
 
+impl crate::packer::Packable for StakeableLockIn {
+    /// Emits the "platformvm.StakeableLockIn" type-ID prefix, its locktime, and
+    /// the embedded "secp256k1fx.TransferInput" (which carries its own type ID).
+    fn pack(&self, packer: &crate::packer::Packer) -> crate::errors::Result<()> {
+        packer.pack_u32(Self::type_id())?;
+        packer.pack_u64(self.locktime)?;
+        packer.pack(&self.transfer_input)
+    }
+}
+
+impl crate::packer::Unpackable for StakeableLockIn {
+    fn unpack(packer: &crate::packer::Packer) -> crate::errors::Result<Self> {
+        let type_id = packer.unpack_u32()?;
+        if type_id != Self::type_id() {
+            return Err(crate::errors::Error::Other {
+                message: format!("unexpected type ID {type_id} for platformvm.StakeableLockIn"),
+                retryable: false,
+            });
+        }
+        let locktime = packer.unpack_u64()?;
+        let transfer_input = packer.unpack()?;
+        Ok(Self {
+            locktime,
+            transfer_input,
+        })
+    }
+}
+
 impl Ord for StakeableLockIn {
     fn cmp(&self, other: &StakeableLockIn) -> Ordering {
         self.locktime
@@ -319,6 +347,34 @@ impl StakeableLockOut {
     }
 }
 
+impl crate::packer::Packable for StakeableLockOut {
+    /// Emits the "platformvm.StakeableLockOut" type-ID prefix, its locktime, and
+    /// the embedded "secp256k1fx.TransferOutput" (which carries its own type ID).
+    fn pack(&self, packer: &crate::packer::Packer) -> crate::errors::Result<()> {
+        packer.pack_u32(Self::type_id())?;
+        packer.pack_u64(self.locktime)?;
+        packer.pack(&self.transfer_output)
+    }
+}
+
+impl crate::packer::Unpackable for StakeableLockOut {
+    fn unpack(packer: &crate::packer::Packer) -> crate::errors::Result<Self> {
+        let type_id = packer.unpack_u32()?;
+        if type_id != Self::type_id() {
+            return Err(crate::errors::Error::Other {
+                message: format!("unexpected type ID {type_id} for platformvm.StakeableLockOut"),
+                retryable: false,
+            });
+        }
+        let locktime = packer.unpack_u64()?;
+        let transfer_output = packer.unpack()?;
+        Ok(Self {
+            locktime,
+            transfer_output,
+        })
+    }
+}
+
 impl Ord for StakeableLockOut {
     fn cmp(&self, other: &StakeableLockOut) -> Ordering {
         self.locktime
@@ -1,3 +1,5 @@
+use std::io::{self, Error, ErrorKind};
+
 use serde::{Deserialize, Serialize};
 
 /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/snow/consensus/snowball#Parameters>
@@ -53,6 +55,93 @@ impl SnowballParameters {
             mixed_query_num_push_non_vdr: 0,
         }
     }
+
+    /// Validates the Snowball parameters against avalanchego's documented
+    /// invariants.
+    ///
+    /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/snow/consensus/snowball#Parameters.Verify>
+    pub fn validate(&self) -> io::Result<()> {
+        if self.k <= 0 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                format!("k must be positive, got {}", self.k),
+            ));
+        }
+        if self.alpha <= self.k / 2 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                format!("alpha ({}) must be > k/2 ({})", self.alpha, self.k / 2),
+            ));
+        }
+        if self.alpha > self.k {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                format!("alpha ({}) must be <= k ({})", self.alpha, self.k),
+            ));
+        }
+        if self.beta_virtuous <= 0 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                format!("beta_virtuous must be positive, got {}", self.beta_virtuous),
+            ));
+        }
+        if self.beta_rogue < self.beta_virtuous {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                format!(
+                    "beta_rogue ({}) must be >= beta_virtuous ({})",
+                    self.beta_rogue, self.beta_virtuous
+                ),
+            ));
+        }
+        if self.concurrent_repolls <= 0 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                format!(
+                    "concurrent_repolls must be positive, got {}",
+                    self.concurrent_repolls
+                ),
+            ));
+        }
+        if self.concurrent_repolls > self.beta_rogue {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                format!(
+                    "concurrent_repolls ({}) must be <= beta_rogue ({})",
+                    self.concurrent_repolls, self.beta_rogue
+                ),
+            ));
+        }
+        if self.optimal_processing <= 0 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                format!(
+                    "optimal_processing must be positive, got {}",
+                    self.optimal_processing
+                ),
+            ));
+        }
+        if self.max_outstanding_items <= 0 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                format!(
+                    "max_outstanding_items must be positive, got {}",
+                    self.max_outstanding_items
+                ),
+            ));
+        }
+        if self.max_item_processing_time <= 0 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                format!(
+                    "max_item_processing_time must be positive, got {}",
+                    self.max_item_processing_time
+                ),
+            ));
+        }
+
+        Ok(())
+    }
 }
 
 #[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
@@ -81,4 +170,27 @@ impl Parameters {
             batch_size: 30,
         }
     }
+
+    /// Validates the embedded Snowball parameters plus the Avalanche-specific
+    /// `parents`/`batch_size` invariants.
+    ///
+    /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/snow/consensus/avalanche#Parameters.Verify>
+    pub fn validate(&self) -> io::Result<()> {
+        self.snowball_parameters.validate()?;
+
+        if self.parents <= 1 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                format!("parents must be > 1, got {}", self.parents),
+            ));
+        }
+        if self.batch_size <= 0 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                format!("batch_size must be positive, got {}", self.batch_size),
+            ));
+        }
+
+        Ok(())
+    }
 }
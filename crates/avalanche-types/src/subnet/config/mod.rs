@@ -73,6 +73,72 @@ impl Config {
 
         Ok(())
     }
+
+    /// Loads the subnet config from disk.
+    pub fn load(file_path: &str) -> io::Result<Self> {
+        log::info!("loading subnet config from {}", file_path);
+
+        if !Path::new(file_path).exists() {
+            return Err(Error::new(
+                ErrorKind::NotFound,
+                format!("file {} does not exists", file_path),
+            ));
+        }
+
+        let f = File::open(file_path).map_err(|e| {
+            Error::new(
+                ErrorKind::Other,
+                format!("failed to open {} ({})", file_path, e),
+            )
+        })?;
+        serde_json::from_reader(f)
+            .map_err(|e| Error::new(ErrorKind::InvalidInput, format!("invalid JSON: {}", e)))
+    }
+
+    /// Validates the subnet config against avalanchego's documented
+    /// consensus invariants, plus gossip settings that would otherwise
+    /// silently contradict `validator_only`.
+    pub fn validate(&self) -> io::Result<()> {
+        log::info!("validating the subnet configuration");
+
+        self.consensus_parameters.validate()?;
+
+        if self.proposer_min_block_delay == 0 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "proposer_min_block_delay must be non-zero",
+            ));
+        }
+
+        if self.validator_only {
+            let non_validator_sizes = [
+                (
+                    "gossipAcceptedFrontierNonValidatorSize",
+                    self.gossip_sender_config
+                        .gossip_accepted_frontier_non_validator_size,
+                ),
+                (
+                    "gossipOnAcceptNonValidatorSize",
+                    self.gossip_sender_config
+                        .gossip_on_accept_non_validator_size,
+                ),
+                (
+                    "appGossipNonValidatorSize",
+                    self.gossip_sender_config.app_gossip_non_validator_size,
+                ),
+            ];
+            for (name, size) in non_validator_sizes {
+                if size != 0 {
+                    return Err(Error::new(
+                        ErrorKind::InvalidInput,
+                        format!("validator_only is true but '{name}' is {size}, not 0"),
+                    ));
+                }
+            }
+        }
+
+        Ok(())
+    }
 }
 
 /// RUST_LOG=debug cargo test --package avalanche-types --lib -- subnet::config::test_config --exact --show-output
@@ -83,4 +149,32 @@ fn test_config() {
     let tmp_path = random_manager::tmp_path(10, Some(".json")).unwrap();
     let cfg = Config::default();
     cfg.sync(&tmp_path).unwrap();
+
+    let loaded = Config::load(&tmp_path).unwrap();
+    assert_eq!(cfg, loaded);
+    assert!(loaded.validate().is_ok());
+
+    fs::remove_file(&tmp_path).unwrap();
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- subnet::config::test_validate --exact --show-output
+#[test]
+fn test_validate() {
+    let _ = env_logger::builder().is_test(true).try_init();
+
+    let mut cfg = Config::default();
+    assert!(cfg.validate().is_ok());
+
+    cfg.consensus_parameters.snowball_parameters.alpha =
+        cfg.consensus_parameters.snowball_parameters.k + 1;
+    assert!(cfg.validate().is_err());
+
+    let mut cfg = Config::default();
+    cfg.proposer_min_block_delay = 0;
+    assert!(cfg.validate().is_err());
+
+    let mut cfg = Config::default();
+    cfg.validator_only = true;
+    cfg.gossip_sender_config.app_gossip_non_validator_size = 1;
+    assert!(cfg.validate().is_err());
 }
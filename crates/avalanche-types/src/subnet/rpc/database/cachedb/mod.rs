@@ -0,0 +1,393 @@
+//! Read-through cache wrapping another [`BoxedDatabase`].
+//!
+//! Repeated `get`/iterator reads against the rpcdb backend each cost a gRPC
+//! round-trip. This wrapper keeps a bounded in-process cache of recently read
+//! and written values so hot keys stay local, trading memory for latency.
+//!
+//! The cache-coherence rules follow OpenEthereum's `Writable`/cache design: a
+//! [`CacheUpdatePolicy`] decides, per write, whether the new value is installed
+//! into the cache ([`CacheUpdatePolicy::Overwrite`]) or merely evicted from it
+//! ([`CacheUpdatePolicy::Remove`]) after the underlying database is updated, so
+//! a stale value can never outlive the write that invalidated it.
+use std::{collections::VecDeque, io, sync::Arc};
+
+use super::{batch::BoxedBatch, iterator::BoxedIterator, BoxedDatabase};
+
+use tokio::sync::RwLock;
+
+/// Default number of entries retained by [`Cache`] before the least-recently
+/// used key is evicted.
+pub const DEFAULT_CACHE_CAPACITY: usize = 4096;
+
+/// Decides how a write propagates into the cache once it has been committed to
+/// the underlying database.
+///
+/// ref. OpenEthereum `ethcore-db::CacheUpdatePolicy`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum CacheUpdatePolicy {
+    /// Install the written value into the cache.
+    Overwrite,
+    /// Evict the key from the cache, leaving the next read to re-populate it.
+    Remove,
+}
+
+/// Bounded, least-recently-used key/value cache.
+///
+/// Reads and writes both count as "use" and move a key to the most-recently
+/// used end; once `capacity` is exceeded the least-recently used key is
+/// dropped.
+pub struct Cache {
+    capacity: usize,
+    entries: std::collections::HashMap<Vec<u8>, Vec<u8>>,
+    /// Recency queue, front = least-recently used.
+    order: VecDeque<Vec<u8>>,
+}
+
+impl Cache {
+    #[must_use]
+    pub fn new(capacity: usize) -> Self {
+        let capacity = capacity.max(1);
+        Self {
+            capacity,
+            entries: std::collections::HashMap::with_capacity(capacity),
+            order: VecDeque::with_capacity(capacity),
+        }
+    }
+
+    fn touch(&mut self, key: &[u8]) {
+        if let Some(pos) = self.order.iter().position(|k| k == key) {
+            self.order.remove(pos);
+        }
+        self.order.push_back(key.to_vec());
+    }
+
+    /// Returns a clone of the cached value, marking the key most-recently used.
+    pub fn get(&mut self, key: &[u8]) -> Option<Vec<u8>> {
+        let value = self.entries.get(key).cloned()?;
+        self.touch(key);
+        Some(value)
+    }
+
+    pub fn contains_key(&self, key: &[u8]) -> bool {
+        self.entries.contains_key(key)
+    }
+
+    /// Inserts or updates a value, evicting the least-recently used key when the
+    /// capacity is exceeded.
+    pub fn insert(&mut self, key: Vec<u8>, value: Vec<u8>) {
+        self.touch(&key);
+        self.entries.insert(key, value);
+        while self.entries.len() > self.capacity {
+            if let Some(oldest) = self.order.pop_front() {
+                self.entries.remove(&oldest);
+            } else {
+                break;
+            }
+        }
+    }
+
+    pub fn remove(&mut self, key: &[u8]) {
+        self.entries.remove(key);
+        if let Some(pos) = self.order.iter().position(|k| k == key) {
+            self.order.remove(pos);
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Applies a single committed write to the cache per `policy`.
+    pub fn write_with_cache(&mut self, key: Vec<u8>, value: Vec<u8>, policy: CacheUpdatePolicy) {
+        match policy {
+            CacheUpdatePolicy::Overwrite => self.insert(key, value),
+            CacheUpdatePolicy::Remove => self.remove(&key),
+        }
+    }
+
+    /// Applies a batch of committed writes to the cache per `policy`.
+    pub fn extend_with_cache<I>(&mut self, values: I, policy: CacheUpdatePolicy)
+    where
+        I: IntoIterator<Item = (Vec<u8>, Vec<u8>)>,
+    {
+        match policy {
+            CacheUpdatePolicy::Overwrite => {
+                for (key, value) in values {
+                    self.insert(key, value);
+                }
+            }
+            CacheUpdatePolicy::Remove => {
+                for (key, _) in values {
+                    self.remove(&key);
+                }
+            }
+        }
+    }
+}
+
+/// A [`BoxedDatabase`] fronted by a shared [`Cache`].
+#[derive(Clone)]
+pub struct Database {
+    db: BoxedDatabase,
+    cache: Arc<RwLock<Cache>>,
+    /// Policy applied to the cache after each successful write.
+    policy: CacheUpdatePolicy,
+}
+
+impl Database {
+    /// Wraps `db` with a cache of [`DEFAULT_CACHE_CAPACITY`] entries using the
+    /// [`CacheUpdatePolicy::Overwrite`] policy.
+    pub fn new(db: BoxedDatabase) -> BoxedDatabase {
+        Self::new_with_capacity(db, DEFAULT_CACHE_CAPACITY, CacheUpdatePolicy::Overwrite)
+    }
+
+    /// Wraps `db` with a cache of `capacity` entries and the given write policy.
+    pub fn new_with_capacity(
+        db: BoxedDatabase,
+        capacity: usize,
+        policy: CacheUpdatePolicy,
+    ) -> BoxedDatabase {
+        Box::new(Self {
+            db,
+            cache: Arc::new(RwLock::new(Cache::new(capacity))),
+            policy,
+        })
+    }
+}
+
+#[tonic::async_trait]
+impl crate::subnet::rpc::database::KeyValueReaderWriterDeleter for Database {
+    /// Attempts to return if the database has a key with the provided value.
+    async fn has(&self, key: &[u8]) -> io::Result<bool> {
+        if self.cache.read().await.contains_key(key) {
+            return Ok(true);
+        }
+        self.db.has(key).await
+    }
+
+    /// Attempts to return the value that was mapped to the key that was provided.
+    async fn get(&self, key: &[u8]) -> io::Result<Vec<u8>> {
+        if let Some(value) = self.cache.write().await.get(key) {
+            return Ok(value);
+        }
+        let value = self.db.get(key).await?;
+        self.cache.write().await.insert(key.to_vec(), value.clone());
+        Ok(value)
+    }
+
+    /// Attempts to set the value this key maps to.
+    async fn put(&mut self, key: &[u8], value: &[u8]) -> io::Result<()> {
+        self.db.put(key, value).await?;
+        self.cache
+            .write()
+            .await
+            .write_with_cache(key.to_vec(), value.to_vec(), self.policy);
+        Ok(())
+    }
+
+    /// Attempts to remove any mapping from the key.
+    async fn delete(&mut self, key: &[u8]) -> io::Result<()> {
+        self.db.delete(key).await?;
+        // a delete always evicts, regardless of the write policy.
+        self.cache.write().await.remove(key);
+        Ok(())
+    }
+}
+
+#[tonic::async_trait]
+impl crate::subnet::rpc::database::Closer for Database {
+    /// Attempts to close the database.
+    async fn close(&self) -> io::Result<()> {
+        self.db.close().await
+    }
+}
+
+#[tonic::async_trait]
+impl crate::subnet::rpc::health::Checkable for Database {
+    /// Attempts to perform a health check against the underlying database.
+    async fn health_check(&self) -> io::Result<Vec<u8>> {
+        self.db.health_check().await
+    }
+}
+
+#[tonic::async_trait]
+impl crate::subnet::rpc::database::iterator::Iteratee for Database {
+    /// Implements the [`crate::subnet::rpc::database::iterator::Iteratee`] trait.
+    async fn new_iterator(&self) -> io::Result<BoxedIterator> {
+        self.new_iterator_with_start_and_prefix(&[], &[]).await
+    }
+
+    /// Implements the [`crate::subnet::rpc::database::iterator::Iteratee`] trait.
+    async fn new_iterator_with_start(&self, start: &[u8]) -> io::Result<BoxedIterator> {
+        self.new_iterator_with_start_and_prefix(start, &[]).await
+    }
+
+    /// Implements the [`crate::subnet::rpc::database::iterator::Iteratee`] trait.
+    async fn new_iterator_with_prefix(&self, prefix: &[u8]) -> io::Result<BoxedIterator> {
+        self.new_iterator_with_start_and_prefix(&[], prefix).await
+    }
+
+    /// Implements the [`crate::subnet::rpc::database::iterator::Iteratee`] trait.
+    async fn new_iterator_with_start_and_prefix(
+        &self,
+        start: &[u8],
+        prefix: &[u8],
+    ) -> io::Result<BoxedIterator> {
+        let inner = self
+            .db
+            .new_iterator_with_start_and_prefix(start, prefix)
+            .await?;
+        Ok(Box::new(Iterator {
+            inner,
+            cache: Arc::clone(&self.cache),
+        }))
+    }
+}
+
+#[tonic::async_trait]
+impl crate::subnet::rpc::database::batch::Batcher for Database {
+    /// Implements the [`crate::subnet::rpc::database::batch::Batcher`] trait.
+    async fn new_batch(&self) -> io::Result<BoxedBatch> {
+        let inner = self.db.new_batch().await?;
+        Ok(Box::new(CachedBatch {
+            inner,
+            cache: Arc::clone(&self.cache),
+            policy: self.policy,
+            pending: Vec::new(),
+        }))
+    }
+}
+
+impl crate::subnet::rpc::database::Database for Database {}
+
+/// Batch wrapper that applies its writes to the shared [`Cache`] once
+/// [`Batch::write`] commits them to the underlying database, so batched
+/// `put`/`delete` calls keep the cache coherent the same way the
+/// single-key [`Database::put`]/[`Database::delete`] paths do.
+#[derive(Clone)]
+struct CachedBatch {
+    inner: BoxedBatch,
+    cache: Arc<RwLock<Cache>>,
+    policy: CacheUpdatePolicy,
+    /// Pending writes, in insertion order; `None` marks a delete.
+    pending: Vec<(Vec<u8>, Option<Vec<u8>>)>,
+}
+
+#[tonic::async_trait]
+impl crate::subnet::rpc::database::batch::Batch for CachedBatch {
+    async fn put(&mut self, key: &[u8], value: &[u8]) -> io::Result<()> {
+        self.inner.put(key, value).await?;
+        self.pending.push((key.to_vec(), Some(value.to_vec())));
+        Ok(())
+    }
+
+    async fn delete(&mut self, key: &[u8]) -> io::Result<()> {
+        self.inner.delete(key).await?;
+        self.pending.push((key.to_vec(), None));
+        Ok(())
+    }
+
+    async fn size(&self) -> io::Result<usize> {
+        self.inner.size().await
+    }
+
+    async fn write(&self) -> io::Result<()> {
+        self.inner.write().await?;
+
+        let mut cache = self.cache.write().await;
+        let puts = self
+            .pending
+            .iter()
+            .filter_map(|(k, v)| v.as_ref().map(|v| (k.clone(), v.clone())));
+        cache.extend_with_cache(puts, self.policy);
+        // a delete always evicts, regardless of the write policy.
+        for (key, value) in &self.pending {
+            if value.is_none() {
+                cache.remove(key);
+            }
+        }
+        Ok(())
+    }
+
+    async fn reset(&mut self) {
+        self.inner.reset().await;
+        self.pending.clear();
+    }
+
+    async fn replay(&self, k: Arc<tokio::sync::Mutex<BoxedDatabase>>) -> io::Result<()> {
+        self.inner.replay(k).await
+    }
+}
+
+/// Iterator wrapper that populates the shared [`Cache`] as pairs are read.
+struct Iterator {
+    inner: BoxedIterator,
+    cache: Arc<RwLock<Cache>>,
+}
+
+#[tonic::async_trait]
+impl crate::subnet::rpc::database::iterator::Iterator for Iterator {
+    async fn next(&mut self) -> io::Result<bool> {
+        let has_next = self.inner.next().await?;
+        if has_next {
+            let key = self.inner.key().await?.to_vec();
+            let value = self.inner.value().await?.to_vec();
+            self.cache.write().await.insert(key, value);
+        }
+        Ok(has_next)
+    }
+
+    async fn error(&mut self) -> io::Result<()> {
+        self.inner.error().await
+    }
+
+    async fn key(&self) -> io::Result<&[u8]> {
+        self.inner.key().await
+    }
+
+    async fn value(&self) -> io::Result<&[u8]> {
+        self.inner.value().await
+    }
+
+    async fn release(&mut self) {
+        self.inner.release().await;
+    }
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib --features="subnet" -- subnet::rpc::database::cachedb::test_cache_update_policy --exact --show-output
+#[tokio::test]
+async fn test_cache_update_policy() {
+    let mut cache = Cache::new(2);
+
+    // Overwrite installs the value; a subsequent get is a cache hit.
+    cache.write_with_cache(
+        b"foo".to_vec(),
+        b"bar".to_vec(),
+        CacheUpdatePolicy::Overwrite,
+    );
+    assert_eq!(cache.get(b"foo"), Some(b"bar".to_vec()));
+
+    // Remove evicts the key even though the value was supplied.
+    cache.write_with_cache(b"foo".to_vec(), b"bar".to_vec(), CacheUpdatePolicy::Remove);
+    assert!(!cache.contains_key(b"foo"));
+
+    // LRU eviction keeps the two most-recently used keys.
+    cache.extend_with_cache(
+        vec![
+            (b"a".to_vec(), b"1".to_vec()),
+            (b"b".to_vec(), b"2".to_vec()),
+        ],
+        CacheUpdatePolicy::Overwrite,
+    );
+    // touch "a" so "b" becomes the least-recently used.
+    assert_eq!(cache.get(b"a"), Some(b"1".to_vec()));
+    cache.insert(b"c".to_vec(), b"3".to_vec());
+    assert!(cache.contains_key(b"a"));
+    assert!(cache.contains_key(b"c"));
+    assert!(!cache.contains_key(b"b"));
+    assert_eq!(cache.len(), 2);
+}
@@ -1,4 +1,5 @@
 pub mod batch;
+pub mod cachedb;
 pub mod corruptabledb;
 pub mod iterator;
 pub mod manager;
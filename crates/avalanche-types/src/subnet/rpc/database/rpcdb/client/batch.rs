@@ -1,10 +1,11 @@
 //! Database Batch management implementation for rpcdb client.
 use crate::{
-    proto::rpcdb::{self, database_client::DatabaseClient},
+    proto::rpcdb,
     subnet::rpc::{
         database::{
             self,
             batch::{CAPACITY_REDUCTION_FACTOR, MAX_EXCESS_CAPACITY_FACTOR},
+            rpcdb::client::pool::ConnectionPool,
             BoxedDatabase,
         },
         errors,
@@ -18,7 +19,6 @@ use std::{
 
 use bytes::Bytes;
 use tokio::sync::{Mutex, RwLock};
-use tonic::transport::Channel;
 
 pub const BASE_ELEMENT_SIZE: usize = 8;
 
@@ -33,15 +33,15 @@ struct KeyValue {
 /// should not be used concurrently.
 #[derive(Clone)]
 pub struct Batch {
-    db: DatabaseClient<Channel>,
+    pool: ConnectionPool,
     writes: Arc<RwLock<Vec<KeyValue>>>,
     size: usize,
 }
 
 impl Batch {
-    pub fn new(db: DatabaseClient<Channel>) -> Self {
+    pub fn new(pool: ConnectionPool) -> Self {
         Self {
-            db,
+            pool,
             writes: Arc::new(RwLock::new(Vec::new())),
             size: 0,
         }
@@ -90,7 +90,7 @@ impl database::batch::Batch for Batch {
         let writes = self.writes.read().await;
         let mut key_set: HashSet<Vec<u8>> = HashSet::with_capacity(writes.len());
 
-        let mut db = self.db.clone();
+        let mut db = self.pool.get();
         for kv in writes.iter() {
             // continue if the key already existed
             if key_set.contains(&kv.key) {
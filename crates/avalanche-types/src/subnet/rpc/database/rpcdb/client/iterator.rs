@@ -1,14 +1,15 @@
 //! Database Iterator management implementation for rpcdb client.
 use crate::{
-    proto::rpcdb::{self, database_client::DatabaseClient},
+    proto::rpcdb,
     subnet::rpc::{
-        database,
+        database::{self, rpcdb::client::pool::ConnectionPool},
         errors::{self, Error},
         utils,
     },
 };
 
 use std::{
+    collections::VecDeque,
     io::Result,
     sync::{
         atomic::{AtomicBool, Ordering},
@@ -16,68 +17,273 @@ use std::{
     },
 };
 
-use tokio::sync::RwLock;
-use tonic::transport::Channel;
+use tokio::{sync::RwLock, task::JoinHandle};
 
 use crate::subnet::rpc::database::iterator::BoxedIterator;
 
+/// Tuning knobs for [`Iterator`], controlling how many key/value pairs are
+/// pulled per `iterator_next` round-trip and whether the next page is fetched
+/// in the background while the caller drains the current buffer.
+///
+/// Inspired by the batched key/value access pattern in Garage's K2V layer,
+/// larger batches and prefetch dramatically cut the number of gRPC round-trips
+/// when walking large key spaces (e.g. state sync over millions of keys).
+#[derive(Clone, Copy, Debug)]
+pub struct IteratorOptions {
+    /// Maximum number of key/value pairs requested per `iterator_next` call.
+    pub batch_size: usize,
+    /// Optional soft cap on the number of bytes requested per page; `None`
+    /// leaves the ceiling to the server.
+    pub byte_budget: Option<usize>,
+    /// When `true`, the next page is requested in the background as soon as the
+    /// current one is handed to the caller.
+    pub prefetch: bool,
+}
+
+impl Default for IteratorOptions {
+    fn default() -> Self {
+        // a batch of one with no prefetch preserves the historical behavior.
+        Self {
+            batch_size: 1,
+            byte_budget: None,
+            prefetch: false,
+        }
+    }
+}
+
+impl IteratorOptions {
+    #[must_use]
+    pub fn with_batch_size(mut self, batch_size: usize) -> Self {
+        self.batch_size = batch_size.max(1);
+        self
+    }
+
+    #[must_use]
+    pub fn with_byte_budget(mut self, byte_budget: usize) -> Self {
+        self.byte_budget = Some(byte_budget);
+        self
+    }
+
+    #[must_use]
+    pub fn with_prefetch(mut self, prefetch: bool) -> Self {
+        self.prefetch = prefetch;
+        self
+    }
+}
+
 /// Iterator iterates over a database's key/value pairs.
 ///
 /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/database#Iterator>
 pub struct Iterator {
     id: u64,
-    /// List of PutRequests.
-    data: Vec<rpcdb::PutRequest>,
+    /// Buffered PutRequests for the current page.
+    data: VecDeque<rpcdb::PutRequest>,
     /// Collects first error reported by iterator.
     error: Arc<RwLock<utils::Errors>>,
-    db: DatabaseClient<Channel>,
+    /// Pool of gRPC connections; each `iterator_*` RPC checks one out.
+    pool: ConnectionPool,
     /// True if the underlying database is closed.
     closed: Arc<AtomicBool>,
+    /// Page-size / prefetch tuning.
+    opts: IteratorOptions,
+    /// In-flight background fetch of the next page, if prefetch is enabled.
+    prefetch: Option<JoinHandle<std::result::Result<Vec<rpcdb::PutRequest>, tonic::Status>>>,
+    /// When non-empty, traversal stops as soon as a key stops matching this
+    /// prefix (inclusive-start, prefix-bounded range semantics).
+    prefix: Vec<u8>,
+    #[cfg(feature = "subnet_metrics")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "subnet_metrics")))]
+    /// Optional Prometheus metrics recorded around every `iterator_*` call.
+    metrics: Option<Arc<super::metrics::ClientMetrics>>,
 }
 
 impl Iterator {
-    pub fn new(db: DatabaseClient<Channel>, id: u64, closed: Arc<AtomicBool>) -> BoxedIterator {
+    pub fn new(pool: ConnectionPool, id: u64, closed: Arc<AtomicBool>) -> BoxedIterator {
+        Self::new_with_options(pool, id, closed, IteratorOptions::default())
+    }
+
+    /// Builds an iterator with explicit page-size / prefetch [`IteratorOptions`].
+    pub fn new_with_options(
+        pool: ConnectionPool,
+        id: u64,
+        closed: Arc<AtomicBool>,
+        opts: IteratorOptions,
+    ) -> BoxedIterator {
+        Self::new_with_start_and_prefix(pool, id, closed, opts, &[])
+    }
+
+    /// Builds an iterator that terminates as soon as keys leave `prefix`.
+    ///
+    /// The server already bounds the scan via the creation RPC
+    /// (`new_iterator_with_start_and_prefix`); keeping the prefix here lets the
+    /// client stop early and avoid buffering pairs past the prefix boundary.
+    pub fn new_with_start_and_prefix(
+        pool: ConnectionPool,
+        id: u64,
+        closed: Arc<AtomicBool>,
+        opts: IteratorOptions,
+        prefix: &[u8],
+    ) -> BoxedIterator {
         Box::new(Self {
             id,
-            data: vec![],
+            data: VecDeque::new(),
             error: Arc::new(RwLock::new(utils::Errors::new())),
-            db,
+            pool,
             closed,
+            opts,
+            prefetch: None,
+            prefix: prefix.to_vec(),
+            #[cfg(feature = "subnet_metrics")]
+            metrics: None,
+        })
+    }
+
+    /// Builds an iterator like [`Self::new_with_start_and_prefix`] that
+    /// additionally records every `iterator_*` call into `metrics`.
+    #[cfg(feature = "subnet_metrics")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "subnet_metrics")))]
+    pub fn new_with_start_and_prefix_and_metrics(
+        pool: ConnectionPool,
+        id: u64,
+        closed: Arc<AtomicBool>,
+        opts: IteratorOptions,
+        prefix: &[u8],
+        metrics: Option<Arc<super::metrics::ClientMetrics>>,
+    ) -> BoxedIterator {
+        Box::new(Self {
+            id,
+            data: VecDeque::new(),
+            error: Arc::new(RwLock::new(utils::Errors::new())),
+            pool,
+            closed,
+            opts,
+            prefetch: None,
+            prefix: prefix.to_vec(),
+            metrics,
+        })
+    }
+
+    /// Records a completed call's outcome; a no-op when the `subnet_metrics`
+    /// feature is disabled.
+    #[allow(unused_variables)]
+    fn record(&self, op: &'static str, started: std::time::Instant, ok: bool) {
+        #[cfg(feature = "subnet_metrics")]
+        if let Some(metrics) = &self.metrics {
+            metrics.observe(op, started.elapsed(), ok);
+        }
+    }
+
+    /// Records that a call short-circuited locally because the database was
+    /// already closed; a no-op when the `subnet_metrics` feature is disabled.
+    #[allow(unused_variables)]
+    fn record_closed_short_circuit(&self, op: &'static str) {
+        #[cfg(feature = "subnet_metrics")]
+        if let Some(metrics) = &self.metrics {
+            metrics.observe_closed_short_circuit(op);
+        }
+    }
+
+    /// Returns whether the current front key is still within the configured
+    /// prefix (always true when no prefix was set).
+    fn front_in_prefix(&self) -> bool {
+        if self.prefix.is_empty() {
+            return true;
+        }
+        match self.data.front() {
+            Some(pair) => pair.key.starts_with(&self.prefix),
+            None => false,
+        }
+    }
+
+    /// Issues a single `iterator_next` RPC, requesting up to `batch_size` pairs.
+    fn fetch_page(
+        pool: ConnectionPool,
+        id: u64,
+        opts: IteratorOptions,
+    ) -> JoinHandle<std::result::Result<Vec<rpcdb::PutRequest>, tonic::Status>> {
+        tokio::spawn(async move {
+            let mut db = pool.get();
+            db.iterator_next(rpcdb::IteratorNextRequest {
+                id,
+                batch_size: opts.batch_size as u32,
+                byte_budget: opts.byte_budget.unwrap_or(0) as u64,
+            })
+            .await
+            .map(|resp| resp.into_inner().data)
         })
     }
+
+    /// Kicks off a background fetch of the next page when prefetch is enabled
+    /// and no fetch is already in flight.
+    fn maybe_prefetch(&mut self) {
+        if self.opts.prefetch && self.prefetch.is_none() {
+            self.prefetch = Some(Self::fetch_page(self.pool.clone(), self.id, self.opts));
+        }
+    }
 }
 
 #[tonic::async_trait]
 impl database::iterator::Iterator for Iterator {
     /// Implements the [`crate::subnet::rpc::database::iterator::Iterator`] trait.
     async fn next(&mut self) -> Result<bool> {
-        // Short-circuit and set an error if the underlying database has been closed
-        let mut db = self.db.clone();
         let mut errs = self.error.write().await;
+        // Short-circuit and set an error if the underlying database has been closed
         if self.closed.load(Ordering::Relaxed) {
+            self.record_closed_short_circuit("iterator_next");
             errs.add(&Error::DatabaseClosed.to_err());
             return Ok(false);
         }
+        let started = std::time::Instant::now();
 
+        // Still buffered: advance within the current page and, once it is
+        // running low, make sure the next page is already on its way.
         if self.data.len() > 1 {
-            self.data.drain(0..1);
+            self.data.pop_front();
+            // early-terminate if the scan has walked past the prefix
+            if !self.front_in_prefix() {
+                self.data.clear();
+                self.record("iterator_next", started, true);
+                return Ok(false);
+            }
+            self.maybe_prefetch();
+            self.record("iterator_next", started, true);
             return Ok(true);
         }
 
-        match db
-            .iterator_next(rpcdb::IteratorNextRequest { id: self.id })
-            .await
-        {
-            Ok(resp) => {
-                self.data = resp.into_inner().data;
-                return Ok(!self.data.is_empty());
+        // Buffer drained: consume a prefetched page if one is in flight,
+        // otherwise fetch synchronously.
+        let handle = self
+            .prefetch
+            .take()
+            .unwrap_or_else(|| Self::fetch_page(self.pool.clone(), self.id, self.opts));
+
+        let result = match handle.await {
+            Ok(Ok(data)) => {
+                self.data = VecDeque::from(data);
+                if !self.front_in_prefix() {
+                    self.data.clear();
+                    Ok(false)
+                } else {
+                    let has_more = !self.data.is_empty();
+                    if has_more {
+                        self.maybe_prefetch();
+                    }
+                    Ok(has_more)
+                }
             }
-            Err(s) => {
+            Ok(Err(s)) => {
                 log::error!("iterator next request failed: {:?}", s);
                 errs.add(&errors::from_status(s));
-                return Ok(false);
+                Ok(false)
             }
-        }
+            Err(e) => {
+                log::error!("iterator next task failed: {:?}", e);
+                errs.add(&Error::DatabaseClosed.to_err());
+                Ok(false)
+            }
+        };
+        self.record("iterator_next", started, result.is_ok());
+        result
     }
 
     /// Implements the [`crate::subnet::rpc::database::iterator::Iterator`] trait.
@@ -85,8 +291,9 @@ impl database::iterator::Iterator for Iterator {
         let mut errs = self.error.write().await;
         errs.err()?;
 
-        let mut db = self.db.clone();
-        match db
+        let started = std::time::Instant::now();
+        let mut db = self.pool.get();
+        let result = match db
             .iterator_error(rpcdb::IteratorErrorRequest { id: self.id })
             .await
         {
@@ -94,53 +301,67 @@ impl database::iterator::Iterator for Iterator {
                 // check response for error
                 if let Err(err) = errors::from_i32(resp.into_inner().err) {
                     errs.add(&err);
-                    return Err(err);
+                    Err(err)
+                } else {
+                    Ok(())
                 }
-                return Ok(());
             }
             Err(s) => {
                 log::error!("iterator error request failed: {:?}", s);
                 let err = errors::from_status(s);
                 errs.add(&err);
-                return Err(err);
+                Err(err)
             }
-        }
+        };
+        self.record("iterator_error", started, result.is_ok());
+        result
     }
 
     /// Implements the [`crate::subnet::rpc::database::iterator::Iterator`] trait.
     async fn key(&self) -> Result<&[u8]> {
-        if self.data.is_empty() {
-            return Ok(&[]);
+        match self.data.front() {
+            Some(pair) => Ok(&pair.key),
+            None => Ok(&[]),
         }
-        Ok(&self.data[0].key)
     }
 
     /// Implements the [`crate::subnet::rpc::database::iterator::Iterator`] trait.
     async fn value(&self) -> Result<&[u8]> {
-        if self.data.is_empty() {
-            return Ok(&[]);
+        match self.data.front() {
+            Some(pair) => Ok(&pair.value),
+            None => Ok(&[]),
         }
-        Ok(&self.data[0].value)
     }
 
     /// Implements the [`crate::subnet::rpc::database::iterator::Iterator`] trait.
     async fn release(&mut self) {
+        // drop any outstanding prefetch so it cannot resurrect a released iterator
+        if let Some(handle) = self.prefetch.take() {
+            handle.abort();
+        }
         let mut errs = self.error.write().await;
-        let mut db = self.db.clone();
-        match db
+        let started = std::time::Instant::now();
+        let mut db = self.pool.get();
+        let ok = match db
             .iterator_release(rpcdb::IteratorReleaseRequest { id: self.id })
             .await
         {
             Ok(resp) => {
                 // check response for error
-                if let Err(err) = errors::from_i32(resp.into_inner().err) {
-                    errs.add(&err);
+                match errors::from_i32(resp.into_inner().err) {
+                    Ok(()) => true,
+                    Err(err) => {
+                        errs.add(&err);
+                        false
+                    }
                 }
             }
             Err(s) => {
                 log::error!("iterator release request failed: {:?}", s);
                 errs.add(&errors::from_status(s));
+                false
             }
-        }
+        };
+        self.record("iterator_release", started, ok);
     }
 }
@@ -0,0 +1,136 @@
+//! Prometheus metrics for the rpcdb gRPC client.
+//!
+//! The rpcdb `DatabaseClient` and its [`Iterator`](super::iterator::Iterator)
+//! make an unbounded number of gRPC calls on behalf of a VM, with nothing
+//! exposed about how often they run, how long they take, or how often the
+//! database was already closed when a call was attempted. [`ClientMetrics`]
+//! records per-operation request counts, error counts, and latency
+//! histograms, plus a dedicated counter for calls that short-circuited
+//! locally because [`DatabaseClient`](super::DatabaseClient) had already been
+//! closed, into a caller-supplied [`Registry`] that can be scraped directly
+//! or bridged to an OTLP collector. Gated behind the `subnet_metrics` feature
+//! alongside the rest of the crate's metrics surface.
+
+use std::time::Duration;
+
+use prometheus::{HistogramOpts, HistogramVec, IntCounterVec, Opts, Registry};
+
+/// Records `DatabaseClient`/`Iterator` activity into a Prometheus registry.
+#[derive(Clone)]
+pub struct ClientMetrics {
+    requests: IntCounterVec,
+    errors: IntCounterVec,
+    closed_short_circuits: IntCounterVec,
+    latency: HistogramVec,
+}
+
+impl ClientMetrics {
+    /// Registers the metric families in `registry` under the `rpcdb_client_`
+    /// prefix.
+    pub fn new(registry: &Registry) -> prometheus::Result<Self> {
+        let requests = IntCounterVec::new(
+            Opts::new(
+                "rpcdb_client_requests_total",
+                "rpcdb client calls by operation",
+            ),
+            &["op"],
+        )?;
+        let errors = IntCounterVec::new(
+            Opts::new(
+                "rpcdb_client_errors_total",
+                "rpcdb client calls that returned an error, by operation",
+            ),
+            &["op"],
+        )?;
+        let closed_short_circuits = IntCounterVec::new(
+            Opts::new(
+                "rpcdb_client_closed_short_circuits_total",
+                "rpcdb client calls rejected locally because the database was already closed, by operation",
+            ),
+            &["op"],
+        )?;
+        let latency = HistogramVec::new(
+            HistogramOpts::new(
+                "rpcdb_client_latency_seconds",
+                "rpcdb client call latency by operation",
+            ),
+            &["op"],
+        )?;
+
+        registry.register(Box::new(requests.clone()))?;
+        registry.register(Box::new(errors.clone()))?;
+        registry.register(Box::new(closed_short_circuits.clone()))?;
+        registry.register(Box::new(latency.clone()))?;
+
+        Ok(Self {
+            requests,
+            errors,
+            closed_short_circuits,
+            latency,
+        })
+    }
+
+    /// Records one completed call that actually reached the gRPC transport.
+    pub fn observe(&self, op: &str, elapsed: Duration, ok: bool) {
+        self.requests.with_label_values(&[op]).inc();
+        self.latency
+            .with_label_values(&[op])
+            .observe(elapsed.as_secs_f64());
+        if !ok {
+            self.errors.with_label_values(&[op]).inc();
+        }
+    }
+
+    /// Records a call rejected locally because the database was already
+    /// closed; no RPC was made, so latency is not observed.
+    pub fn observe_closed_short_circuit(&self, op: &str) {
+        self.requests.with_label_values(&[op]).inc();
+        self.closed_short_circuits.with_label_values(&[op]).inc();
+    }
+}
+
+/// Times `fut` and records the outcome against `metrics` under `op`, if a
+/// registry was configured; the inner result is returned unchanged.
+pub(super) async fn timed<F, T>(
+    metrics: Option<&ClientMetrics>,
+    op: &str,
+    fut: F,
+) -> std::io::Result<T>
+where
+    F: std::future::Future<Output = std::io::Result<T>>,
+{
+    let Some(metrics) = metrics else {
+        return fut.await;
+    };
+    let start = std::time::Instant::now();
+    let result = fut.await;
+    metrics.observe(op, start.elapsed(), result.is_ok());
+    result
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --features subnet_metrics --lib -- subnet::rpc::database::rpcdb::client::metrics::test_client_metrics --exact --show-output
+#[test]
+fn test_client_metrics() {
+    let registry = Registry::new();
+    let m = ClientMetrics::new(&registry).unwrap();
+
+    m.observe("get", Duration::from_millis(2), true);
+    m.observe("put", Duration::from_millis(3), false);
+    m.observe_closed_short_circuit("iterator_next");
+
+    let families = registry.gather();
+    let names: Vec<_> = families.iter().map(|f| f.get_name().to_string()).collect();
+    assert!(names.iter().any(|n| n == "rpcdb_client_requests_total"));
+    assert!(names.iter().any(|n| n == "rpcdb_client_latency_seconds"));
+
+    assert_eq!(m.errors.with_label_values(&["put"]).get(), 1);
+    assert_eq!(m.errors.with_label_values(&["get"]).get(), 0);
+    assert_eq!(
+        m.closed_short_circuits
+            .with_label_values(&["iterator_next"])
+            .get(),
+        1
+    );
+    // the short-circuited call still counts as a request.
+    assert_eq!(m.requests.with_label_values(&["iterator_next"]).get(), 1);
+}
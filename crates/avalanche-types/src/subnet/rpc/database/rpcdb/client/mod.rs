@@ -1,6 +1,10 @@
 //! RPC Database Client
 pub mod batch;
 pub mod iterator;
+#[cfg(any(doc, feature = "subnet_metrics"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "subnet_metrics")))]
+pub mod metrics;
+pub mod pool;
 
 use std::{
     io,
@@ -14,10 +18,7 @@ use crate::{
     proto::{
         pb::{
             google::protobuf::Empty,
-            rpcdb::{
-                database_client::DatabaseClient as RpcDbDatabaseClient, CloseRequest,
-                DeleteRequest, GetRequest, PutRequest,
-            },
+            rpcdb::{CloseRequest, DeleteRequest, GetRequest, PutRequest},
         },
         rpcdb::{HasRequest, NewIteratorWithStartAndPrefixRequest},
     },
@@ -30,96 +31,177 @@ use crate::{
 use prost::bytes::Bytes;
 use tonic::transport::Channel;
 
+use self::pool::ConnectionPool;
+
 /// DatabaseClient is an implementation of [`crate::subnet::rpc::database::Database`] that talks over RPC.
 ///
 /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/database/rpcdb#DatabaseClient>
 #[derive(Clone)]
 pub struct DatabaseClient {
-    inner: RpcDbDatabaseClient<Channel>,
+    /// Pool of gRPC connections; each operation checks one out round-robin.
+    pool: ConnectionPool,
     /// True if the underlying database is closed.
     closed: Arc<AtomicBool>,
+    /// Page-size / prefetch tuning applied to every iterator this client creates.
+    iterator_opts: iterator::IteratorOptions,
+    #[cfg(feature = "subnet_metrics")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "subnet_metrics")))]
+    /// Optional Prometheus metrics recorded around every call.
+    metrics: Option<Arc<metrics::ClientMetrics>>,
 }
 
 impl DatabaseClient {
+    /// Builds a client backed by a single `client_conn`.
     pub fn new(client_conn: Channel) -> BoxedDatabase {
+        Self::new_with_pool(ConnectionPool::from_channel(client_conn))
+    }
+
+    /// Builds a client whose operations are spread round-robin across `pool`.
+    /// Use this (with a [`ConnectionPool::new`] built from independently
+    /// established channels) to get real parallelism across connections;
+    /// see [`ConnectionPool`]'s docs for why [`Self::new`] alone does not.
+    pub fn new_with_pool(pool: ConnectionPool) -> BoxedDatabase {
+        Box::new(Self {
+            pool,
+            closed: Arc::new(AtomicBool::new(false)),
+            iterator_opts: iterator::IteratorOptions::default(),
+            #[cfg(feature = "subnet_metrics")]
+            metrics: None,
+        })
+    }
+
+    /// Builds a client like [`Self::new`] whose iterators use `iterator_opts`
+    /// instead of [`iterator::IteratorOptions::default`].
+    pub fn new_with_iterator_options(
+        client_conn: Channel,
+        iterator_opts: iterator::IteratorOptions,
+    ) -> BoxedDatabase {
+        Box::new(Self {
+            pool: ConnectionPool::from_channel(client_conn),
+            closed: Arc::new(AtomicBool::new(false)),
+            iterator_opts,
+            #[cfg(feature = "subnet_metrics")]
+            metrics: None,
+        })
+    }
+
+    /// Builds a client like [`Self::new`] that additionally records every
+    /// call into `metrics`.
+    #[cfg(feature = "subnet_metrics")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "subnet_metrics")))]
+    pub fn new_with_metrics(
+        client_conn: Channel,
+        metrics: Arc<metrics::ClientMetrics>,
+    ) -> BoxedDatabase {
         Box::new(Self {
-            inner: RpcDbDatabaseClient::new(client_conn),
+            pool: ConnectionPool::from_channel(client_conn),
             closed: Arc::new(AtomicBool::new(false)),
+            iterator_opts: iterator::IteratorOptions::default(),
+            metrics: Some(metrics),
         })
     }
+
+    /// Times `fut` and records it under `op` against [`Self::metrics`], if
+    /// configured; a no-op pass-through when the `subnet_metrics` feature is
+    /// disabled.
+    async fn record<F, T>(&self, op: &'static str, fut: F) -> io::Result<T>
+    where
+        F: std::future::Future<Output = io::Result<T>>,
+    {
+        #[cfg(feature = "subnet_metrics")]
+        {
+            metrics::timed(self.metrics.as_deref(), op, fut).await
+        }
+        #[cfg(not(feature = "subnet_metrics"))]
+        {
+            fut.await
+        }
+    }
 }
 
 #[tonic::async_trait]
 impl database::KeyValueReaderWriterDeleter for DatabaseClient {
     /// Attempts to return if the database has a key with the provided value.
     async fn has(&self, key: &[u8]) -> io::Result<bool> {
-        let mut db = self.inner.clone();
-        let resp = db
-            .has(HasRequest {
-                key: Bytes::from(key.to_owned()),
-            })
-            .await
-            .map_err(|s| {
-                log::error!("has request failed: {:?}", s);
-                errors::from_status(s)
-            })?
-            .into_inner();
+        self.record("has", async {
+            let mut db = self.pool.get();
+            let resp = db
+                .has(HasRequest {
+                    key: Bytes::from(key.to_owned()),
+                })
+                .await
+                .map_err(|s| {
+                    log::error!("has request failed: {:?}", s);
+                    errors::from_status(s)
+                })?
+                .into_inner();
 
-        Ok(resp.has)
+            Ok(resp.has)
+        })
+        .await
     }
 
     /// Attempts to return the value that was mapped to the key that was provided.
     async fn get(&self, key: &[u8]) -> io::Result<Vec<u8>> {
-        let mut db = self.inner.clone();
-        let resp = db
-            .get(GetRequest {
-                key: Bytes::from(key.to_owned()),
-            })
-            .await
-            .map_err(|s| {
-                log::error!("get request failed: {:?}", s);
-                errors::from_status(s)
-            })?;
+        self.record("get", async {
+            let mut db = self.pool.get();
+            let resp = db
+                .get(GetRequest {
+                    key: Bytes::from(key.to_owned()),
+                })
+                .await
+                .map_err(|s| {
+                    log::error!("get request failed: {:?}", s);
+                    errors::from_status(s)
+                })?;
 
-        log::debug!("get response: {:?}", resp);
+            log::debug!("get response: {:?}", resp);
 
-        let resp = resp.into_inner();
-        errors::from_i32(resp.err)?;
+            let resp = resp.into_inner();
+            errors::from_i32(resp.err)?;
 
-        Ok(resp.value.to_vec())
+            Ok(resp.value.to_vec())
+        })
+        .await
     }
 
     /// Attempts to set the value this key maps to.
     async fn put(&mut self, key: &[u8], value: &[u8]) -> io::Result<()> {
-        let mut db = self.inner.clone();
-        let resp = db
-            .put(PutRequest {
-                key: Bytes::from(key.to_owned()),
-                value: Bytes::from(value.to_owned()),
-            })
-            .await
-            .map_err(|s| {
-                log::error!("put request failed: {:?}", s);
-                errors::from_status(s)
-            })?;
+        self.record("put", async {
+            let mut db = self.pool.get();
+            let resp = db
+                .put(PutRequest {
+                    key: Bytes::from(key.to_owned()),
+                    value: Bytes::from(value.to_owned()),
+                })
+                .await
+                .map_err(|s| {
+                    log::error!("put request failed: {:?}", s);
+                    errors::from_status(s)
+                })?;
 
-        errors::from_i32(resp.into_inner().err)
+            errors::from_i32(resp.into_inner().err)
+        })
+        .await
     }
 
     /// Attempts to remove any mapping from the key.
     async fn delete(&mut self, key: &[u8]) -> io::Result<()> {
-        let mut client = self.inner.clone();
-        let resp = client
-            .delete(DeleteRequest {
-                key: Bytes::from(key.to_owned()),
-            })
-            .await
-            .map_err(|s| {
-                log::error!("delete request failed: {:?}", s);
-                errors::from_status(s)
-            })?;
+        self.record("delete", async {
+            let mut client = self.pool.get();
+            let resp = client
+                .delete(DeleteRequest {
+                    key: Bytes::from(key.to_owned()),
+                })
+                .await
+                .map_err(|s| {
+                    log::error!("delete request failed: {:?}", s);
+                    errors::from_status(s)
+                })?;
 
-        errors::from_i32(resp.into_inner().err)
+            errors::from_i32(resp.into_inner().err)
+        })
+        .await
     }
 }
 
@@ -127,15 +209,18 @@ impl database::KeyValueReaderWriterDeleter for DatabaseClient {
 impl database::Closer for DatabaseClient {
     /// Attempts to close the database.
     async fn close(&self) -> io::Result<()> {
-        let mut db = self.inner.clone();
-        self.closed.store(true, Ordering::Relaxed);
+        self.record("close", async {
+            let mut db = self.pool.get();
+            self.closed.store(true, Ordering::Relaxed);
 
-        let resp = db.close(CloseRequest {}).await.map_err(|s| {
-            log::error!("close request failed: {:?}", s);
-            errors::from_status(s)
-        })?;
+            let resp = db.close(CloseRequest {}).await.map_err(|s| {
+                log::error!("close request failed: {:?}", s);
+                errors::from_status(s)
+            })?;
 
-        errors::from_i32(resp.into_inner().err)
+            errors::from_i32(resp.into_inner().err)
+        })
+        .await
     }
 }
 
@@ -143,13 +228,16 @@ impl database::Closer for DatabaseClient {
 impl crate::subnet::rpc::health::Checkable for DatabaseClient {
     /// Attempts to perform a health check against the underlying database.
     async fn health_check(&self) -> io::Result<Vec<u8>> {
-        let mut db = self.inner.clone();
-        let resp = db.health_check(Empty {}).await.map_err(|s| {
-            log::error!("health check failed: {:?}", s);
-            errors::from_status(s)
-        })?;
+        self.record("health_check", async {
+            let mut db = self.pool.get();
+            let resp = db.health_check(Empty {}).await.map_err(|s| {
+                log::error!("health check failed: {:?}", s);
+                errors::from_status(s)
+            })?;
 
-        Ok(resp.into_inner().details.to_vec())
+            Ok(resp.into_inner().details.to_vec())
+        })
+        .await
     }
 }
 
@@ -176,23 +264,44 @@ impl database::iterator::Iteratee for DatabaseClient {
         start: &[u8],
         prefix: &[u8],
     ) -> io::Result<BoxedIterator> {
-        let mut db = self.inner.clone();
-        match db
-            .new_iterator_with_start_and_prefix(NewIteratorWithStartAndPrefixRequest {
-                start: Bytes::from(start.to_owned()),
-                prefix: Bytes::from(prefix.to_owned()),
-            })
-            .await
-        {
-            Ok(resp) => Ok(iterator::Iterator::new(
-                self.inner.clone(),
-                resp.into_inner().id,
-                Arc::clone(&self.closed),
-            )),
-            Err(s) => Ok(crate::subnet::rpc::database::nodb::Iterator::new(Some(
-                errors::from_status(s),
-            ))),
-        }
+        self.record("new_iterator", async {
+            let mut db = self.pool.get();
+            match db
+                .new_iterator_with_start_and_prefix(NewIteratorWithStartAndPrefixRequest {
+                    start: Bytes::from(start.to_owned()),
+                    prefix: Bytes::from(prefix.to_owned()),
+                })
+                .await
+            {
+                Ok(resp) => {
+                    #[cfg(feature = "subnet_metrics")]
+                    {
+                        Ok(iterator::Iterator::new_with_start_and_prefix_and_metrics(
+                            self.pool.clone(),
+                            resp.into_inner().id,
+                            Arc::clone(&self.closed),
+                            self.iterator_opts,
+                            prefix,
+                            self.metrics.clone(),
+                        ))
+                    }
+                    #[cfg(not(feature = "subnet_metrics"))]
+                    {
+                        Ok(iterator::Iterator::new_with_start_and_prefix(
+                            self.pool.clone(),
+                            resp.into_inner().id,
+                            Arc::clone(&self.closed),
+                            self.iterator_opts,
+                            prefix,
+                        ))
+                    }
+                }
+                Err(s) => Ok(crate::subnet::rpc::database::nodb::Iterator::new(Some(
+                    errors::from_status(s),
+                ))),
+            }
+        })
+        .await
     }
 }
 
@@ -200,7 +309,7 @@ impl database::iterator::Iteratee for DatabaseClient {
 impl database::batch::Batcher for DatabaseClient {
     /// Implements the [`crate::subnet::rpc::database::batch::Batcher`] trait.
     async fn new_batch(&self) -> io::Result<BoxedBatch> {
-        Ok(Box::new(batch::Batch::new(self.inner.clone())))
+        Ok(Box::new(batch::Batch::new(self.pool.clone())))
     }
 }
 
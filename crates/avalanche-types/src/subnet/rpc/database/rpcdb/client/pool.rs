@@ -0,0 +1,61 @@
+//! gRPC connection pool for the rpcdb `DatabaseClient`.
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc,
+};
+
+use tonic::transport::Channel;
+
+use crate::proto::rpcdb::database_client::DatabaseClient;
+
+/// A pool of `DatabaseClient<Channel>` connections handed out round-robin,
+/// analogous to a `bb8` connection pool.
+///
+/// Note that `tonic::transport::Channel` already multiplexes concurrent RPCs
+/// over HTTP/2 streams on one connection, so cloning a single `Channel` N
+/// times buys nothing: every clone is a handle to the same connection, and
+/// [`Self::from_channel`] builds a pool of just that one client. Real parallelism across independent
+/// connections only comes from [`Self::new`] with channels that were each
+/// established with their own `Endpoint::connect`.
+#[derive(Clone)]
+pub struct ConnectionPool {
+    clients: Arc<Vec<DatabaseClient<Channel>>>,
+    cursor: Arc<AtomicUsize>,
+}
+
+impl ConnectionPool {
+    /// Builds a pool from a set of independently established channels.
+    pub fn new(channels: Vec<Channel>) -> Self {
+        assert!(
+            !channels.is_empty(),
+            "connection pool requires at least one channel"
+        );
+        let clients = channels.into_iter().map(DatabaseClient::new).collect();
+        Self {
+            clients: Arc::new(clients),
+            cursor: Arc::new(AtomicUsize::new(0)),
+        }
+    }
+
+    /// Wraps a single already-connected `Channel`, for callers that don't
+    /// have (or don't need) a pool of independent connections -- see
+    /// [`Self`]'s docs on why this is not the same as real connection
+    /// pooling.
+    pub fn from_channel(channel: Channel) -> Self {
+        Self::new(vec![channel])
+    }
+
+    /// Checks out the next client in round-robin order.
+    pub fn get(&self) -> DatabaseClient<Channel> {
+        let idx = self.cursor.fetch_add(1, Ordering::Relaxed) % self.clients.len();
+        self.clients[idx].clone()
+    }
+
+    pub fn len(&self) -> usize {
+        self.clients.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.clients.is_empty()
+    }
+}
@@ -1,5 +1,12 @@
 //! Provides health checking.
-use std::io::Result;
+use std::io::{Error, ErrorKind, Result};
+
+use futures::{Stream, StreamExt};
+use tonic_health::pb::{
+    health_check_response::ServingStatus, health_client::HealthClient, HealthCheckRequest,
+};
+
+use crate::subnet::rpc::utils;
 
 /// Checkable can have its health checked
 ///
@@ -8,3 +15,42 @@ use std::io::Result;
 pub trait Checkable {
     async fn health_check(&self) -> Result<Vec<u8>>;
 }
+
+/// Opens the gRPC Health `Watch` stream against `grpc_addr` (in `<ip>:<port>`
+/// format) and yields [`ServingStatus`] transitions as the server pushes them,
+/// instead of polling `/health` every 15 seconds.
+///
+/// ref. <https://github.com/grpc/grpc/blob/master/doc/health-checking.md>
+pub async fn watch(grpc_addr: &str) -> Result<impl Stream<Item = Result<ServingStatus>>> {
+    let client_conn = utils::grpc::default_client(grpc_addr)?
+        .connect()
+        .await
+        .map_err(|e| {
+            Error::new(
+                ErrorKind::Other,
+                format!("failed to connect health client to {grpc_addr}: {e}"),
+            )
+        })?;
+
+    let mut client = HealthClient::new(client_conn);
+    let stream = client
+        .watch(HealthCheckRequest {
+            // empty service name watches overall server serving status
+            service: String::new(),
+        })
+        .await
+        .map_err(|e| Error::new(ErrorKind::Other, format!("health watch failed: {e}")))?
+        .into_inner();
+
+    Ok(stream.map(|res| {
+        res.map_err(|e| Error::new(ErrorKind::Other, format!("health watch stream error: {e}")))
+            .and_then(|resp| {
+                ServingStatus::from_i32(resp.status).ok_or_else(|| {
+                    Error::new(
+                        ErrorKind::InvalidData,
+                        format!("unknown serving status {}", resp.status),
+                    )
+                })
+            })
+    }))
+}
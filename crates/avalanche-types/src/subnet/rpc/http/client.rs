@@ -21,7 +21,7 @@ impl Client {
 impl subnet::rpc::http::Handler for Client {
     async fn serve_http(
         &mut self,
-        _req: http::Request<Vec<u8>>,
+        _req: subnet::rpc::http::Request<Vec<u8>>,
     ) -> io::Result<http::Response<Vec<u8>>> {
         Err(Error::new(ErrorKind::Other, "not implemented"))
     }
@@ -29,9 +29,9 @@ impl subnet::rpc::http::Handler for Client {
     /// http client takes an http request and sends to server.  Does not support websockets.
     async fn serve_http_simple(
         &mut self,
-        req: http::Request<Vec<u8>>,
+        req: subnet::rpc::http::Request<Vec<u8>>,
     ) -> io::Result<http::Response<Vec<u8>>> {
-        let req = get_http_simple_request(req)?;
+        let req = get_http_simple_request(req.into_inner())?;
 
         let resp = self.inner.handle_simple(req).await.map_err(|e| {
             Error::new(
@@ -0,0 +1,132 @@
+//! Per-request type-map for passing state between composed HTTP handlers.
+use std::{
+    any::{Any, TypeId},
+    collections::HashMap,
+    ops::{Deref, DerefMut},
+};
+
+/// A type-map keyed on [`TypeId`], modeled on actix-web's `Extensions`.
+///
+/// Handlers use it to attach decoded auth context, chain-alias resolution, or
+/// timing data that downstream logic can read without serializing it into
+/// headers.
+#[derive(Default)]
+pub struct Extensions {
+    map: HashMap<TypeId, Box<dyn Any + Send>>,
+}
+
+impl Extensions {
+    /// Creates an empty type-map.
+    pub fn new() -> Self {
+        Self {
+            map: HashMap::new(),
+        }
+    }
+
+    /// Inserts a value, returning the previous value of the same type if any.
+    pub fn insert<T: Send + 'static>(&mut self, val: T) -> Option<T> {
+        self.map
+            .insert(TypeId::of::<T>(), Box::new(val))
+            .and_then(|boxed| boxed.downcast().ok().map(|b| *b))
+    }
+
+    /// Returns a reference to the stored value of type `T`, if present.
+    pub fn get<T: Send + 'static>(&self) -> Option<&T> {
+        self.map
+            .get(&TypeId::of::<T>())
+            .and_then(|boxed| boxed.downcast_ref())
+    }
+
+    /// Returns a mutable reference to the stored value of type `T`, if present.
+    pub fn get_mut<T: Send + 'static>(&mut self) -> Option<&mut T> {
+        self.map
+            .get_mut(&TypeId::of::<T>())
+            .and_then(|boxed| boxed.downcast_mut())
+    }
+
+    /// Removes and returns the stored value of type `T`, if present.
+    pub fn remove<T: Send + 'static>(&mut self) -> Option<T> {
+        self.map
+            .remove(&TypeId::of::<T>())
+            .and_then(|boxed| boxed.downcast().ok().map(|b| *b))
+    }
+
+    /// Returns `true` when the type-map holds no values.
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+}
+
+/// Wraps an [`http::Request`] with an owned [`Extensions`] type-map so
+/// middleware-style handlers can pass state between composed handlers.
+///
+/// Derefs to the inner [`http::Request`] so existing accessors (`method`,
+/// `uri`, `headers`, `body`) keep working unchanged.
+pub struct Request<T = Vec<u8>> {
+    inner: http::Request<T>,
+    extensions: Extensions,
+}
+
+impl<T> Request<T> {
+    /// Wraps `inner` with an empty type-map.
+    pub fn new(inner: http::Request<T>) -> Self {
+        Self {
+            inner,
+            extensions: Extensions::new(),
+        }
+    }
+
+    /// Returns a reference to the request's type-map.
+    pub fn extensions(&self) -> &Extensions {
+        &self.extensions
+    }
+
+    /// Returns a mutable reference to the request's type-map.
+    pub fn extensions_mut(&mut self) -> &mut Extensions {
+        &mut self.extensions
+    }
+
+    /// Consumes the wrapper, returning the inner [`http::Request`].
+    pub fn into_inner(self) -> http::Request<T> {
+        self.inner
+    }
+}
+
+impl<T> From<http::Request<T>> for Request<T> {
+    fn from(inner: http::Request<T>) -> Self {
+        Self::new(inner)
+    }
+}
+
+impl<T> Deref for Request<T> {
+    type Target = http::Request<T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl<T> DerefMut for Request<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.inner
+    }
+}
+
+#[test]
+fn test_extensions() {
+    let mut ext = Extensions::new();
+    assert!(ext.is_empty());
+
+    assert_eq!(ext.insert(42u32), None);
+    assert_eq!(ext.insert(7u32), Some(42u32));
+    assert_eq!(ext.get::<u32>(), Some(&7u32));
+
+    *ext.get_mut::<u32>().unwrap() += 1;
+    assert_eq!(ext.get::<u32>(), Some(&8u32));
+
+    ext.insert(String::from("alias"));
+    assert_eq!(ext.get::<String>().map(String::as_str), Some("alias"));
+
+    assert_eq!(ext.remove::<u32>(), Some(8u32));
+    assert_eq!(ext.get::<u32>(), None);
+}
@@ -1,17 +1,20 @@
 pub mod client;
+pub mod extensions;
 pub mod handle;
 pub mod server;
 
+pub use extensions::{Extensions, Request};
+
 /// ref: <https://pkg.go.dev/net/http#Handler>
 #[tonic::async_trait]
 pub trait Handler {
     async fn serve_http(
         &mut self,
-        req: http::Request<Vec<u8>>,
+        req: Request<Vec<u8>>,
     ) -> std::io::Result<http::Response<Vec<u8>>>;
 
     async fn serve_http_simple(
         &mut self,
-        req: http::Request<Vec<u8>>,
+        req: Request<Vec<u8>>,
     ) -> std::io::Result<http::Response<Vec<u8>>>;
 }
@@ -7,6 +7,7 @@ pub mod http;
 pub mod runtime;
 pub mod snow;
 pub mod snowman;
+pub mod sync;
 pub mod utils;
 pub mod vm;
 
@@ -0,0 +1,185 @@
+//! Pluggable authorization for the `sync.DB` server.
+//!
+//! A node serving `sync.DB` exposes the read-only proof methods and the
+//! `Commit*` methods to anyone who can reach the endpoint. [`Authorizer`] gates
+//! each method before it is dispatched: it receives the gRPC method name and the
+//! peer identity extracted from request metadata, and may reject the call with
+//! `tonic::Code::PermissionDenied`. [`AuthorizingDb`] wraps any [`Db`]
+//! implementation with an authorizer and is served through the generated
+//! [`DbServer`], alongside the existing compression and message-size config.
+
+use std::collections::HashSet;
+
+use tonic::{Request, Response, Status};
+
+use crate::proto::pb::{
+    google::protobuf::Empty,
+    sync::{
+        db_server::{Db, DbServer},
+        CommitChangeProofRequest, CommitRangeProofRequest, GetChangeProofRequest,
+        GetChangeProofResponse, GetMerkleRootResponse, GetProofRequest, GetProofResponse,
+        GetRangeProofRequest, GetRangeProofResponse, VerifyChangeProofRequest,
+        VerifyChangeProofResponse,
+    },
+};
+
+/// Metadata key carrying the caller's identity when TLS client auth is not used.
+pub const PEER_ID_METADATA_KEY: &str = "peer-id";
+
+/// Consulted before each `sync.DB` method is dispatched.
+pub trait Authorizer: Send + Sync + 'static {
+    /// Returns `Ok(())` to admit the call, or `Err` (typically
+    /// `Status::permission_denied`) to reject it. `peer` is the caller identity
+    /// when one could be determined.
+    fn authorize(&self, method: &str, peer: Option<&str>) -> Result<(), Status>;
+}
+
+/// Wraps a [`Db`] so every method is authorized before it reaches the inner
+/// implementation.
+#[derive(Debug, Clone)]
+pub struct AuthorizingDb<T, A> {
+    inner: T,
+    authorizer: A,
+}
+
+impl<T, A> AuthorizingDb<T, A> {
+    pub fn new(inner: T, authorizer: A) -> Self {
+        Self { inner, authorizer }
+    }
+
+    fn check<M>(&self, method: &str, req: &Request<M>) -> Result<(), Status> {
+        let peer = req
+            .metadata()
+            .get(PEER_ID_METADATA_KEY)
+            .and_then(|v| v.to_str().ok());
+        self.authorizer.authorize(method, peer)
+    }
+}
+
+/// Builds a [`DbServer`] that authorizes every method through `authorizer`.
+pub fn with_authorizer<T, A>(inner: T, authorizer: A) -> DbServer<AuthorizingDb<T, A>>
+where
+    T: Db,
+    A: Authorizer,
+{
+    DbServer::new(AuthorizingDb::new(inner, authorizer))
+}
+
+#[tonic::async_trait]
+impl<T, A> Db for AuthorizingDb<T, A>
+where
+    T: Db,
+    A: Authorizer,
+{
+    async fn get_merkle_root(
+        &self,
+        request: Request<Empty>,
+    ) -> Result<Response<GetMerkleRootResponse>, Status> {
+        self.check("GetMerkleRoot", &request)?;
+        self.inner.get_merkle_root(request).await
+    }
+
+    async fn get_proof(
+        &self,
+        request: Request<GetProofRequest>,
+    ) -> Result<Response<GetProofResponse>, Status> {
+        self.check("GetProof", &request)?;
+        self.inner.get_proof(request).await
+    }
+
+    async fn get_change_proof(
+        &self,
+        request: Request<GetChangeProofRequest>,
+    ) -> Result<Response<GetChangeProofResponse>, Status> {
+        self.check("GetChangeProof", &request)?;
+        self.inner.get_change_proof(request).await
+    }
+
+    async fn verify_change_proof(
+        &self,
+        request: Request<VerifyChangeProofRequest>,
+    ) -> Result<Response<VerifyChangeProofResponse>, Status> {
+        self.check("VerifyChangeProof", &request)?;
+        self.inner.verify_change_proof(request).await
+    }
+
+    async fn commit_change_proof(
+        &self,
+        request: Request<CommitChangeProofRequest>,
+    ) -> Result<Response<Empty>, Status> {
+        self.check("CommitChangeProof", &request)?;
+        self.inner.commit_change_proof(request).await
+    }
+
+    async fn get_range_proof(
+        &self,
+        request: Request<GetRangeProofRequest>,
+    ) -> Result<Response<GetRangeProofResponse>, Status> {
+        self.check("GetRangeProof", &request)?;
+        self.inner.get_range_proof(request).await
+    }
+
+    async fn commit_range_proof(
+        &self,
+        request: Request<CommitRangeProofRequest>,
+    ) -> Result<Response<Empty>, Status> {
+        self.check("CommitRangeProof", &request)?;
+        self.inner.commit_range_proof(request).await
+    }
+}
+
+/// Admits the read-only proof methods for every peer while restricting the
+/// state-mutating `Commit*` methods to an explicit allowlist of peer identities.
+#[derive(Debug, Clone, Default)]
+pub struct AllowlistAuthorizer {
+    commit_allowlist: HashSet<String>,
+}
+
+impl AllowlistAuthorizer {
+    pub fn new<I, S>(commit_allowlist: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        Self {
+            commit_allowlist: commit_allowlist.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl Authorizer for AllowlistAuthorizer {
+    fn authorize(&self, method: &str, peer: Option<&str>) -> Result<(), Status> {
+        if !method.starts_with("Commit") {
+            return Ok(());
+        }
+        match peer {
+            Some(id) if self.commit_allowlist.contains(id) => Ok(()),
+            _ => Err(Status::permission_denied(format!(
+                "{method} is restricted to trusted peers"
+            ))),
+        }
+    }
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- subnet::rpc::sync::auth::test_allowlist --exact --show-output
+#[test]
+fn test_allowlist() {
+    let auth = AllowlistAuthorizer::new(["trusted-node"]);
+
+    // read methods are open to everyone, including unidentified peers.
+    assert!(auth.authorize("GetRangeProof", None).is_ok());
+    assert!(auth.authorize("GetProof", Some("anyone")).is_ok());
+
+    // commit methods require an allowlisted identity.
+    assert!(auth.authorize("CommitRangeProof", Some("trusted-node")).is_ok());
+    assert_eq!(
+        auth.authorize("CommitChangeProof", Some("stranger"))
+            .unwrap_err()
+            .code(),
+        tonic::Code::PermissionDenied
+    );
+    assert_eq!(
+        auth.authorize("CommitRangeProof", None).unwrap_err().code(),
+        tonic::Code::PermissionDenied
+    );
+}
@@ -0,0 +1,104 @@
+//! Serde-serializable representations of `sync.DB` proof payloads.
+//!
+//! The generated proof messages are prost-only, so a fetched range or change
+//! proof cannot be persisted or inspected outside the gRPC wire path. These
+//! mirror types carry the same information with byte fields encoded as `0x`-hex,
+//! so a caller can dump a proof to JSON for debugging, snapshot-test a proof
+//! payload, or cache it to disk between sync sessions.
+
+use serde::{Deserialize, Serialize};
+use serde_with::serde_as;
+
+use crate::codec::serde::hex_0x_bytes::Hex0xBytes;
+
+/// A single node on a proof path: the nibble key reached so far, the node's
+/// value (when present), and the child hashes by nibble index.
+#[serde_as]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProofNodeJson {
+    #[serde_as(as = "Hex0xBytes")]
+    pub key: Vec<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde_as(as = "Option<Hex0xBytes>")]
+    #[serde(default)]
+    pub value: Option<Vec<u8>>,
+    pub children: Vec<ChildHashJson>,
+}
+
+/// A child pointer within a [`ProofNodeJson`].
+#[serde_as]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ChildHashJson {
+    pub index: u32,
+    #[serde_as(as = "Hex0xBytes")]
+    pub hash: Vec<u8>,
+}
+
+/// A key-value pair carried in a range or change proof.
+#[serde_as]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct KeyValueJson {
+    #[serde_as(as = "Hex0xBytes")]
+    pub key: Vec<u8>,
+    #[serde_as(as = "Hex0xBytes")]
+    pub value: Vec<u8>,
+}
+
+/// A single key change between two roots: `value` is absent when the key was
+/// deleted.
+#[serde_as]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct KeyChangeJson {
+    #[serde_as(as = "Hex0xBytes")]
+    pub key: Vec<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde_as(as = "Option<Hex0xBytes>")]
+    #[serde(default)]
+    pub value: Option<Vec<u8>>,
+}
+
+/// A range proof: the start- and end-boundary proof paths plus the key-values
+/// they bound.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RangeProofJson {
+    pub start_proof: Vec<ProofNodeJson>,
+    pub end_proof: Vec<ProofNodeJson>,
+    pub key_values: Vec<KeyValueJson>,
+}
+
+/// A change proof describing how the DB transitioned between two roots.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ChangeProofJson {
+    pub start_proof: Vec<ProofNodeJson>,
+    pub end_proof: Vec<ProofNodeJson>,
+    pub key_changes: Vec<KeyChangeJson>,
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- subnet::rpc::sync::json::test_range_proof_json --exact --show-output
+#[test]
+fn test_range_proof_json() {
+    let proof = RangeProofJson {
+        start_proof: vec![ProofNodeJson {
+            key: vec![0x01],
+            value: None,
+            children: vec![ChildHashJson {
+                index: 3,
+                hash: vec![0xaa, 0xbb],
+            }],
+        }],
+        end_proof: vec![],
+        key_values: vec![KeyValueJson {
+            key: vec![0x01, 0x02],
+            value: vec![0xff],
+        }],
+    };
+
+    let encoded = serde_json::to_string(&proof).unwrap();
+    assert!(encoded.contains("\"0x0102\""));
+    assert!(encoded.contains("\"0xaabb\""));
+
+    let decoded: RangeProofJson = serde_json::from_str(&encoded).unwrap();
+    assert_eq!(proof, decoded);
+    // a proof node with no value round-trips through the optional hex field.
+    assert!(decoded.start_proof[0].value.is_none());
+}
@@ -0,0 +1,90 @@
+//! Encoding-size guards for proof responses.
+//!
+//! The generated `DbServer` already threads `max_decoding_message_size` /
+//! `max_encoding_message_size` into every handler, and exposes builder methods
+//! to set them. What it cannot do on its own is fail gracefully when a range or
+//! change proof legitimately exceeds the configured encoding limit: tonic would
+//! otherwise surface an opaque frame error. A handler can call
+//! [`guard_encoding_size`] before returning a proof so the caller sees a
+//! structured `ResourceExhausted` carrying the offending size and can lower its
+//! requested key limit.
+
+use tonic::Status;
+
+use super::json::{ChangeProofJson, RangeProofJson};
+
+/// Per-entry framing overhead (protobuf field tags and length prefixes) folded
+/// into the size estimate so the guard trips a little early rather than late.
+const ENTRY_OVERHEAD: usize = 8;
+
+/// Estimates the serialized size, in bytes, of a range proof.
+pub fn estimate_range_proof_size(proof: &RangeProofJson) -> usize {
+    proof_nodes_size(&proof.start_proof)
+        + proof_nodes_size(&proof.end_proof)
+        + proof
+            .key_values
+            .iter()
+            .map(|kv| kv.key.len() + kv.value.len() + ENTRY_OVERHEAD)
+            .sum::<usize>()
+}
+
+/// Estimates the serialized size, in bytes, of a change proof.
+pub fn estimate_change_proof_size(proof: &ChangeProofJson) -> usize {
+    proof_nodes_size(&proof.start_proof)
+        + proof_nodes_size(&proof.end_proof)
+        + proof
+            .key_changes
+            .iter()
+            .map(|kc| kc.key.len() + kc.value.as_ref().map_or(0, |v| v.len()) + ENTRY_OVERHEAD)
+            .sum::<usize>()
+}
+
+fn proof_nodes_size(nodes: &[super::json::ProofNodeJson]) -> usize {
+    nodes
+        .iter()
+        .map(|n| {
+            n.key.len()
+                + n.value.as_ref().map_or(0, |v| v.len())
+                + n.children.iter().map(|c| c.hash.len() + 4).sum::<usize>()
+                + ENTRY_OVERHEAD
+        })
+        .sum()
+}
+
+/// Returns `Err(Status::resource_exhausted(..))` when `size` exceeds `max`,
+/// naming the offending size so the caller can retry with a smaller key limit.
+/// A `max` of 0 means "no limit".
+pub fn guard_encoding_size(size: usize, max: usize) -> Result<(), Status> {
+    if max != 0 && size > max {
+        return Err(Status::resource_exhausted(format!(
+            "proof response is {size} bytes, exceeds encoding limit of {max} bytes; lower the requested key limit"
+        )));
+    }
+    Ok(())
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- subnet::rpc::sync::limits::test_guard --exact --show-output
+#[test]
+fn test_guard() {
+    use super::json::KeyValueJson;
+
+    let proof = RangeProofJson {
+        start_proof: vec![],
+        end_proof: vec![],
+        key_values: vec![KeyValueJson {
+            key: vec![0u8; 100],
+            value: vec![0u8; 100],
+        }],
+    };
+    let size = estimate_range_proof_size(&proof);
+    assert!(size >= 200);
+
+    // within budget
+    assert!(guard_encoding_size(size, size + 1).is_ok());
+    // no limit
+    assert!(guard_encoding_size(size, 0).is_ok());
+    // over budget yields ResourceExhausted naming the size
+    let err = guard_encoding_size(size, size - 1).unwrap_err();
+    assert_eq!(err.code(), tonic::Code::ResourceExhausted);
+    assert!(err.message().contains(&size.to_string()));
+}
@@ -0,0 +1,30 @@
+//! Client-side helpers for the `sync.DB` MerkleDB state-sync service.
+//!
+//! These hand-written wrappers sit on top of the generated
+//! [`crate::proto::pb::sync`] bindings, mirroring the layout of
+//! [`crate::subnet::rpc::database::rpcdb`]: the generated code carries the wire
+//! types and the `DbClient`/`DbServer` stubs, while this module adds the Rust
+//! surface operators actually drive — JSON-dumpable proof payloads, an
+//! incremental range-proof stream, a state-sync orchestrator, and a per-method
+//! authorization layer for the server.
+//!
+//! The client-facing helpers are always compiled. The server-side surface — the
+//! authorization wrapper that builds a `DbServer`, the proof-size and pagination
+//! guards a handler applies when answering requests, and the request telemetry —
+//! is gated behind the `grpc-server` feature, matching the generated `DbServer`
+//! gating: a node that only syncs *from* peers never links the server stack. The
+//! request telemetry additionally needs the `subnet_metrics` feature.
+pub mod compression;
+pub mod json;
+pub mod manager;
+pub mod stream;
+
+#[cfg(any(doc, feature = "grpc-server"))]
+pub mod auth;
+#[cfg(any(doc, feature = "grpc-server"))]
+pub mod limits;
+#[cfg(any(doc, feature = "grpc-server"))]
+pub mod pagination;
+
+#[cfg(any(doc, all(feature = "grpc-server", feature = "subnet_metrics")))]
+pub mod telemetry;
@@ -0,0 +1,141 @@
+//! Size-bounded, resumable range-proof pagination.
+//!
+//! A syncer that asks for `[start, end)` over a large subtree cannot take the
+//! whole range in one unary response without tripping the encoding limit (see
+//! [`super::limits`]). This module lets the server walk the range in ascending
+//! key order, packing key-values until a caller-supplied byte budget would be
+//! exceeded, and hand back a `next_start_key` cursor so the caller can resume
+//! exactly where the page ended. Each page is a standalone range proof over
+//! `[page_start, next_start_key)` and so is independently verifiable against the
+//! target root.
+//!
+//! Two edge cases are handled explicitly: an empty `next_start_key` means the
+//! range is exhausted, and a single key-value larger than the whole budget is
+//! still emitted on its own page (flagged via [`RangeProofPage::oversized`]) so
+//! the walk cannot stall.
+
+use super::json::KeyValueJson;
+
+/// One page of a paginated range-proof walk.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RangeProofPage {
+    /// Key-values in this page, in strictly ascending key order.
+    pub key_values: Vec<KeyValueJson>,
+    /// Cursor to resume from: `Some(key)` to request the next page starting at
+    /// `key`, or `None` when the range has been fully walked.
+    pub next_start_key: Option<Vec<u8>>,
+    /// Set when the page holds a single key-value that on its own exceeds the
+    /// byte budget; the entry is returned anyway so the walk makes progress.
+    pub oversized: bool,
+}
+
+/// Per-key-value framing overhead folded into the budget accounting, matching
+/// [`super::limits`] so the two estimates agree.
+const ENTRY_OVERHEAD: usize = 8;
+
+fn entry_size(kv: &KeyValueJson) -> usize {
+    kv.key.len() + kv.value.len() + ENTRY_OVERHEAD
+}
+
+/// Packs the next page from `sorted` key-values, which must be in ascending key
+/// order and already filtered to the requested range. `max_response_bytes` is
+/// the per-page key-value budget (0 means "no budget" — take everything).
+///
+/// The returned page covers a prefix of `sorted`; its `next_start_key` is the
+/// first key not included, or `None` when all of `sorted` fit.
+pub fn paginate(sorted: &[KeyValueJson], max_response_bytes: usize) -> RangeProofPage {
+    if sorted.is_empty() {
+        return RangeProofPage {
+            key_values: Vec::new(),
+            next_start_key: None,
+            oversized: false,
+        };
+    }
+
+    if max_response_bytes == 0 {
+        return RangeProofPage {
+            key_values: sorted.to_vec(),
+            next_start_key: None,
+            oversized: false,
+        };
+    }
+
+    let mut used = 0usize;
+    let mut taken = 0usize;
+    for kv in sorted {
+        let size = entry_size(kv);
+        // always admit the first entry so an oversized key-value cannot stall
+        // the walk; stop before exceeding the budget thereafter.
+        if taken > 0 && used + size > max_response_bytes {
+            break;
+        }
+        used += size;
+        taken += 1;
+    }
+
+    let oversized = taken == 1 && used > max_response_bytes;
+    let next_start_key = sorted.get(taken).map(|kv| kv.key.clone());
+    RangeProofPage {
+        key_values: sorted[..taken].to_vec(),
+        next_start_key,
+        oversized,
+    }
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- subnet::rpc::sync::pagination::test_paginate --exact --show-output
+#[test]
+fn test_paginate() {
+    let kv = |k: u8, n: usize| KeyValueJson {
+        key: vec![k],
+        value: vec![0u8; n],
+    };
+
+    // empty range is immediately done.
+    let page = paginate(&[], 100);
+    assert!(page.key_values.is_empty());
+    assert_eq!(page.next_start_key, None);
+
+    // budget of 0 takes everything in one page.
+    let all = vec![kv(1, 10), kv(2, 10), kv(3, 10)];
+    let page = paginate(&all, 0);
+    assert_eq!(page.key_values.len(), 3);
+    assert_eq!(page.next_start_key, None);
+
+    // a real budget splits the walk and hands back a resume cursor.
+    // each entry is 1 (key) + 20 (value) + 8 (overhead) = 29 bytes.
+    let items = vec![kv(1, 20), kv(2, 20), kv(3, 20)];
+    let page = paginate(&items, 60);
+    assert_eq!(page.key_values.len(), 2);
+    assert_eq!(page.next_start_key, Some(vec![3]));
+    assert!(!page.oversized);
+
+    // resuming from the cursor drains the rest.
+    let rest: Vec<_> = items
+        .iter()
+        .filter(|kv| kv.key >= vec![3])
+        .cloned()
+        .collect();
+    let page = paginate(&rest, 60);
+    assert_eq!(page.key_values.len(), 1);
+    assert_eq!(page.next_start_key, None);
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- subnet::rpc::sync::pagination::test_paginate_oversized --exact --show-output
+#[test]
+fn test_paginate_oversized() {
+    let big = KeyValueJson {
+        key: vec![1],
+        value: vec![0u8; 1_000],
+    };
+    let next = KeyValueJson {
+        key: vec![2],
+        value: vec![0u8; 10],
+    };
+
+    // a single entry larger than the whole budget is still emitted alone and
+    // flagged, so the walk makes progress instead of stalling.
+    let page = paginate(&[big, next], 100);
+    assert_eq!(page.key_values.len(), 1);
+    assert!(page.oversized);
+    assert_eq!(page.next_start_key, Some(vec![2]));
+}
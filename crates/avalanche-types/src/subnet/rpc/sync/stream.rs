@@ -0,0 +1,203 @@
+//! Server-streaming range-proof transfer for `sync.DB`.
+//!
+//! The unary `get_range_proof` must pack every key-value pair and boundary
+//! proof node for a range into a single response, which collides with the 4MB
+//! default `max_decoding_message_size` on large subtrees. The streaming variant
+//! emits an ordered sequence of bounded frames — a start-boundary proof, then
+//! successive key-value batches, then an end-boundary proof with a `done` flag —
+//! so a syncer can pull an arbitrarily large range as a backpressured stream and
+//! reassemble it incrementally.
+
+use std::io::{Error, ErrorKind, Result};
+
+use super::json::{KeyValueJson, ProofNodeJson};
+
+/// One frame of a streamed range proof. Frames arrive in order: exactly one
+/// [`Start`](RangeProofChunk::Start), then zero or more
+/// [`KeyValues`](RangeProofChunk::KeyValues), then exactly one
+/// [`End`](RangeProofChunk::End) carrying `done = true`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RangeProofChunk {
+    /// The start-boundary proof path for the range.
+    Start { start_proof: Vec<ProofNodeJson> },
+    /// A bounded batch of key-values, in ascending key order.
+    KeyValues { key_values: Vec<KeyValueJson> },
+    /// The end-boundary proof path and stream terminator.
+    End {
+        end_proof: Vec<ProofNodeJson>,
+        done: bool,
+    },
+}
+
+/// State of the incremental reassembler.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Phase {
+    /// Awaiting the start-boundary frame.
+    Start,
+    /// Accepting key-value batches or the end-boundary frame.
+    Body,
+    /// Terminated; no further frames accepted.
+    Done,
+}
+
+/// Reassembles a streamed range proof frame by frame, enforcing ordering and
+/// ascending, non-overlapping keys so a caller can verify against the target
+/// root as frames arrive rather than buffering one giant message.
+#[derive(Debug)]
+pub struct RangeProofStreamAssembler {
+    phase: Phase,
+    start_proof: Vec<ProofNodeJson>,
+    end_proof: Vec<ProofNodeJson>,
+    key_values: Vec<KeyValueJson>,
+}
+
+impl Default for RangeProofStreamAssembler {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RangeProofStreamAssembler {
+    pub fn new() -> Self {
+        Self {
+            phase: Phase::Start,
+            start_proof: Vec::new(),
+            end_proof: Vec::new(),
+            key_values: Vec::new(),
+        }
+    }
+
+    /// Whether the terminating frame has been accepted.
+    pub fn is_done(&self) -> bool {
+        self.phase == Phase::Done
+    }
+
+    /// The key-values accumulated so far, for incremental verification.
+    pub fn key_values(&self) -> &[KeyValueJson] {
+        &self.key_values
+    }
+
+    /// Feeds the next frame. Returns an error on an out-of-order frame or a key
+    /// that is not strictly greater than the previous one.
+    pub fn push(&mut self, chunk: RangeProofChunk) -> Result<()> {
+        match (self.phase, chunk) {
+            (Phase::Start, RangeProofChunk::Start { start_proof }) => {
+                self.start_proof = start_proof;
+                self.phase = Phase::Body;
+                Ok(())
+            }
+            (Phase::Body, RangeProofChunk::KeyValues { key_values }) => {
+                for kv in key_values {
+                    if let Some(last) = self.key_values.last() {
+                        if kv.key <= last.key {
+                            return Err(Error::new(
+                                ErrorKind::InvalidData,
+                                "streamed range proof keys are not strictly ascending",
+                            ));
+                        }
+                    }
+                    self.key_values.push(kv);
+                }
+                Ok(())
+            }
+            (Phase::Body, RangeProofChunk::End { end_proof, done }) => {
+                if !done {
+                    return Err(Error::new(
+                        ErrorKind::InvalidData,
+                        "end-boundary frame must set done = true",
+                    ));
+                }
+                self.end_proof = end_proof;
+                self.phase = Phase::Done;
+                Ok(())
+            }
+            _ => Err(Error::new(
+                ErrorKind::InvalidData,
+                "out-of-order range-proof stream frame",
+            )),
+        }
+    }
+
+    /// Consumes a fully-received stream into a [`RangeProofJson`]. Fails if the
+    /// terminating frame has not been seen.
+    pub fn finish(self) -> Result<super::json::RangeProofJson> {
+        if self.phase != Phase::Done {
+            return Err(Error::new(
+                ErrorKind::UnexpectedEof,
+                "range-proof stream ended before the end-boundary frame",
+            ));
+        }
+        Ok(super::json::RangeProofJson {
+            start_proof: self.start_proof,
+            end_proof: self.end_proof,
+            key_values: self.key_values,
+        })
+    }
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- subnet::rpc::sync::stream::test_reassemble --exact --show-output
+#[test]
+fn test_reassemble() {
+    let kv = |k: u8, v: u8| KeyValueJson {
+        key: vec![k],
+        value: vec![v],
+    };
+
+    let mut asm = RangeProofStreamAssembler::new();
+    asm.push(RangeProofChunk::Start {
+        start_proof: vec![],
+    })
+    .unwrap();
+    asm.push(RangeProofChunk::KeyValues {
+        key_values: vec![kv(1, 10), kv(2, 20)],
+    })
+    .unwrap();
+    asm.push(RangeProofChunk::KeyValues {
+        key_values: vec![kv(3, 30)],
+    })
+    .unwrap();
+    assert_eq!(asm.key_values().len(), 3);
+    asm.push(RangeProofChunk::End {
+        end_proof: vec![],
+        done: true,
+    })
+    .unwrap();
+    assert!(asm.is_done());
+
+    let proof = asm.finish().unwrap();
+    assert_eq!(proof.key_values.len(), 3);
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- subnet::rpc::sync::stream::test_reassemble_rejects_bad_order --exact --show-output
+#[test]
+fn test_reassemble_rejects_bad_order() {
+    let mut asm = RangeProofStreamAssembler::new();
+    // a body frame before the start boundary is rejected.
+    assert!(asm
+        .push(RangeProofChunk::KeyValues { key_values: vec![] })
+        .is_err());
+
+    asm.push(RangeProofChunk::Start {
+        start_proof: vec![],
+    })
+    .unwrap();
+    // non-ascending keys are rejected.
+    let err = asm
+        .push(RangeProofChunk::KeyValues {
+            key_values: vec![
+                KeyValueJson {
+                    key: vec![2],
+                    value: vec![],
+                },
+                KeyValueJson {
+                    key: vec![1],
+                    value: vec![],
+                },
+            ],
+        })
+        .unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidData);
+
+    // finishing before the end boundary fails.
+    assert!(asm.finish().is_err());
+}
@@ -0,0 +1,159 @@
+//! Per-method telemetry for the `sync.DB` server.
+//!
+//! This plugs into the same [`Interceptor`] extension point the RPC Chain VM
+//! server uses, so a `DbServer` can be wrapped in
+//! [`Intercepted`](crate::subnet::rpc::vm::metrics::Intercepted) with a
+//! [`SyncServerMetrics`] to record request counts, error counts, in-flight
+//! gauges, and latency histograms keyed by method. Proof handlers additionally
+//! report payload shape — response bytes and key/node counts — and commit
+//! durations through [`SyncServerMetrics::observe_payload`] and
+//! [`SyncServerMetrics::observe_commit`], since those are only known inside the
+//! handler, not at the transport layer.
+//!
+//! The metrics live in a [`prometheus::Registry`] that can be scraped directly
+//! or bridged to an OTLP collector by a process-level exporter; the server owns
+//! no exporter of its own. Gated behind the `subnet_metrics` feature alongside
+//! the rest of the crate's metrics surface.
+
+use std::time::Duration;
+
+use prometheus::{
+    HistogramOpts, HistogramVec, IntCounterVec, IntGaugeVec, Opts, Registry,
+};
+
+use crate::subnet::rpc::vm::metrics::Interceptor;
+
+/// Records `sync.DB` server activity into a Prometheus registry.
+#[derive(Clone)]
+pub struct SyncServerMetrics {
+    requests: IntCounterVec,
+    errors: IntCounterVec,
+    in_flight: IntGaugeVec,
+    latency: HistogramVec,
+    response_bytes: HistogramVec,
+    entries: HistogramVec,
+    commit_seconds: HistogramVec,
+}
+
+impl SyncServerMetrics {
+    /// Registers the metric families in `registry` under the `sync_db_` prefix.
+    pub fn new(registry: &Registry) -> prometheus::Result<Self> {
+        let requests = IntCounterVec::new(
+            Opts::new("sync_db_requests_total", "sync.DB requests by method"),
+            &["method"],
+        )?;
+        let errors = IntCounterVec::new(
+            Opts::new("sync_db_errors_total", "sync.DB errored requests by method"),
+            &["method"],
+        )?;
+        let in_flight = IntGaugeVec::new(
+            Opts::new("sync_db_in_flight", "in-flight sync.DB requests by method"),
+            &["method"],
+        )?;
+        let latency = HistogramVec::new(
+            HistogramOpts::new("sync_db_latency_seconds", "sync.DB request latency"),
+            &["method"],
+        )?;
+        let response_bytes = HistogramVec::new(
+            HistogramOpts::new(
+                "sync_db_response_bytes",
+                "serialized proof response size by method",
+            )
+            .buckets(prometheus::exponential_buckets(1_024.0, 2.0, 14)?),
+            &["method"],
+        )?;
+        let entries = HistogramVec::new(
+            HistogramOpts::new(
+                "sync_db_response_entries",
+                "key-values or proof nodes in a response by method",
+            )
+            .buckets(prometheus::exponential_buckets(1.0, 2.0, 16)?),
+            &["method"],
+        )?;
+        let commit_seconds = HistogramVec::new(
+            HistogramOpts::new("sync_db_commit_seconds", "proof commit duration by method"),
+            &["method"],
+        )?;
+
+        registry.register(Box::new(requests.clone()))?;
+        registry.register(Box::new(errors.clone()))?;
+        registry.register(Box::new(in_flight.clone()))?;
+        registry.register(Box::new(latency.clone()))?;
+        registry.register(Box::new(response_bytes.clone()))?;
+        registry.register(Box::new(entries.clone()))?;
+        registry.register(Box::new(commit_seconds.clone()))?;
+
+        Ok(Self {
+            requests,
+            errors,
+            in_flight,
+            latency,
+            response_bytes,
+            entries,
+            commit_seconds,
+        })
+    }
+
+    /// Records the payload shape of a proof response, called by the handler once
+    /// the response has been built.
+    pub fn observe_payload(&self, method: &str, response_bytes: usize, entries: usize) {
+        self.response_bytes
+            .with_label_values(&[method])
+            .observe(response_bytes as f64);
+        self.entries
+            .with_label_values(&[method])
+            .observe(entries as f64);
+    }
+
+    /// Records how long a `Commit*` handler took to apply a proof.
+    pub fn observe_commit(&self, method: &str, elapsed: Duration) {
+        self.commit_seconds
+            .with_label_values(&[method])
+            .observe(elapsed.as_secs_f64());
+    }
+}
+
+impl Interceptor for SyncServerMetrics {
+    fn on_request(&self, method: &str) -> std::result::Result<(), tonic::Status> {
+        self.requests.with_label_values(&[method]).inc();
+        self.in_flight.with_label_values(&[method]).inc();
+        Ok(())
+    }
+
+    fn on_response(&self, method: &str, elapsed: Duration, ok: bool) {
+        self.in_flight.with_label_values(&[method]).dec();
+        self.latency
+            .with_label_values(&[method])
+            .observe(elapsed.as_secs_f64());
+        if !ok {
+            self.errors.with_label_values(&[method]).inc();
+        }
+    }
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --features subnet_metrics --lib -- subnet::rpc::sync::telemetry::test_sync_server_metrics --exact --show-output
+#[test]
+fn test_sync_server_metrics() {
+    let registry = Registry::new();
+    let m = SyncServerMetrics::new(&registry).unwrap();
+
+    m.on_request("/sync.DB/GetRangeProof").unwrap();
+    m.observe_payload("/sync.DB/GetRangeProof", 4_096, 120);
+    m.on_response("/sync.DB/GetRangeProof", Duration::from_millis(3), true);
+
+    m.on_request("/sync.DB/CommitRangeProof").unwrap();
+    m.observe_commit("/sync.DB/CommitRangeProof", Duration::from_millis(12));
+    m.on_response("/sync.DB/CommitRangeProof", Duration::from_millis(15), false);
+
+    let families = registry.gather();
+    let names: Vec<_> = families.iter().map(|f| f.get_name().to_string()).collect();
+    assert!(names.iter().any(|n| n == "sync_db_requests_total"));
+    assert!(names.iter().any(|n| n == "sync_db_commit_seconds"));
+    // the errored commit bumped the error counter.
+    assert_eq!(
+        m.errors
+            .with_label_values(&["/sync.DB/CommitRangeProof"])
+            .get(),
+        1
+    );
+}
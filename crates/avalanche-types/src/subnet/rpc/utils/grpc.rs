@@ -67,6 +67,20 @@ pub fn default_server() -> tonic::transport::Server {
         .tcp_keepalive(Some(DEFAULT_KEEP_ALIVE_MIN_TIME))
 }
 
+/// Like [`default_server`] but, when `tls` is supplied, requires callers to
+/// complete a mutual-TLS handshake against the trusted-peer keyset currently in
+/// force. The reloaded material is picked up on the next server build, so a
+/// rotation does not disturb the listening socket.
+pub fn default_server_with_tls(tls: Option<&super::tls::Rotating>) -> Result<tonic::transport::Server> {
+    let server = default_server();
+    match tls {
+        Some(rotating) => server
+            .tls_config(rotating.current().server_tls_config())
+            .map_err(|e| Error::new(ErrorKind::Other, format!("invalid server TLS config: {e}"))),
+        None => Ok(server),
+    }
+}
+
 /// Creates a tonic Endpoint with avalanche defaults. The endpoint input is
 /// expected in `<ip>:<port>` format.
 pub fn default_client(endpoint: &str) -> Result<Endpoint> {
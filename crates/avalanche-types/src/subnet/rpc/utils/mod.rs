@@ -1,4 +1,5 @@
 pub mod grpc;
+pub mod tls;
 
 use std::{
     io::{Error, Result},
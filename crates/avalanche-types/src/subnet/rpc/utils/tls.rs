@@ -0,0 +1,179 @@
+//! Mutually-authenticated (mTLS) transport for the RPC Chain VM gRPC server.
+//!
+//! The VM and the avalanchego runtime talk over a local gRPC channel that, by
+//! default, is plaintext and trusts any caller on the socket. This module adds
+//! an optional mTLS mode: each side holds a fixed identity and a list of
+//! trusted peer certificates that serve as its only TLS trust roots, and a
+//! handshake from a peer whose certificate does not chain to one of them is
+//! rejected.
+//!
+//! [`Rotating`] reloads the trusted material from disk on a schedule and
+//! publishes each changed [`Keyset`] over a [`watch`] channel.
+//! [`default_server_with_tls`](super::grpc::default_server_with_tls) reads the
+//! keyset in force when the server is built, so a running server keeps that
+//! keyset: a reload does not reach the connections of a server that is already
+//! listening.
+//! ref. <https://github.com/ava-labs/avalanchego/blob/master/network/peer/tls_config.go>
+
+use std::{
+    io::{Error, ErrorKind, Result},
+    path::{Path, PathBuf},
+    sync::Arc,
+    time::Duration,
+};
+
+use tokio::sync::watch;
+use tonic::transport::{Certificate, ClientTlsConfig, Identity, ServerTlsConfig};
+
+/// How often the trusted-key material is reloaded from disk when a reload
+/// interval is not supplied explicitly.
+pub const DEFAULT_RELOAD_INTERVAL: Duration = Duration::from_secs(5 * 60);
+
+/// A server identity plus the explicit allow-list of trusted peer certificates.
+///
+/// The `trusted_peers` certificates are installed as the TLS trust roots
+/// (`client_ca_root` on the server, `ca_certificate` on the client), so a peer
+/// completes the handshake when its certificate is one of them or chains to one
+/// of them; they are not pinned as exact end-entity certificates.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Keyset {
+    /// PEM-encoded server (or client) certificate presented on the handshake.
+    pub cert_pem: Vec<u8>,
+    /// PEM-encoded private key for `cert_pem`.
+    pub key_pem: Vec<u8>,
+    /// PEM-encoded certificates of the peers this endpoint trusts.
+    pub trusted_peers: Vec<Vec<u8>>,
+}
+
+impl Keyset {
+    /// Loads the identity and trusted-peer certificates from disk.
+    pub fn load<P: AsRef<Path>>(
+        cert_path: P,
+        key_path: P,
+        trusted_peer_cert_paths: &[PathBuf],
+    ) -> Result<Self> {
+        let cert_pem = read(cert_path)?;
+        let key_pem = read(key_path)?;
+        let mut trusted_peers = Vec::with_capacity(trusted_peer_cert_paths.len());
+        for p in trusted_peer_cert_paths {
+            trusted_peers.push(read(p)?);
+        }
+        Ok(Self {
+            cert_pem,
+            key_pem,
+            trusted_peers,
+        })
+    }
+
+    /// Concatenates the trusted-peer certificates into a single PEM bundle used
+    /// to authenticate the remote end of the channel.
+    fn trusted_bundle(&self) -> Vec<u8> {
+        let mut bundle = Vec::new();
+        for cert in &self.trusted_peers {
+            bundle.extend_from_slice(cert);
+            if !cert.ends_with(b"\n") {
+                bundle.push(b'\n');
+            }
+        }
+        bundle
+    }
+
+    /// Builds the tonic [`ServerTlsConfig`] requiring every client to present a
+    /// certificate from the trusted-peer set.
+    pub fn server_tls_config(&self) -> ServerTlsConfig {
+        ServerTlsConfig::new()
+            .identity(Identity::from_pem(&self.cert_pem, &self.key_pem))
+            .client_ca_root(Certificate::from_pem(self.trusted_bundle()))
+    }
+
+    /// Builds the tonic [`ClientTlsConfig`] the runtime handshake uses so the
+    /// `client.initialize` call negotiates the same credentials.
+    pub fn client_tls_config(&self, domain: &str) -> ClientTlsConfig {
+        ClientTlsConfig::new()
+            .domain_name(domain)
+            .identity(Identity::from_pem(&self.cert_pem, &self.key_pem))
+            .ca_certificate(Certificate::from_pem(self.trusted_bundle()))
+    }
+}
+
+/// Paths backing a [`Keyset`], used to reload it from disk on a schedule.
+#[derive(Debug, Clone)]
+pub struct Paths {
+    pub cert_path: PathBuf,
+    pub key_path: PathBuf,
+    pub trusted_peer_cert_paths: Vec<PathBuf>,
+}
+
+impl Paths {
+    /// Reads the current on-disk [`Keyset`].
+    pub fn load(&self) -> Result<Keyset> {
+        Keyset::load(
+            &self.cert_path,
+            &self.key_path,
+            &self.trusted_peer_cert_paths,
+        )
+    }
+}
+
+/// A [`Keyset`] that is reloaded from disk on a schedule and published to
+/// subscribers over a [`watch`] channel; subscribers observe the latest value.
+#[derive(Debug, Clone)]
+pub struct Rotating {
+    current: watch::Receiver<Arc<Keyset>>,
+}
+
+impl Rotating {
+    /// Loads the initial keyset and spawns a background task that reloads it
+    /// from `paths` every `interval`, publishing each change to subscribers.
+    /// A reload failure is logged and the previous keyset is retained.
+    pub fn spawn(paths: Paths, interval: Option<Duration>) -> Result<Self> {
+        let initial = Arc::new(paths.load()?);
+        let (tx, rx) = watch::channel(initial);
+        let interval = interval.unwrap_or(DEFAULT_RELOAD_INTERVAL);
+
+        tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(interval);
+            ticker.tick().await; // consume the immediate first tick
+            loop {
+                ticker.tick().await;
+                match paths.load() {
+                    Ok(next) => {
+                        let next = Arc::new(next);
+                        // only notify subscribers when the material actually changed
+                        if *tx.borrow() != next {
+                            log::info!("reloaded mTLS keyset from disk");
+                            if tx.send(next).is_err() {
+                                // all subscribers dropped; the server is gone
+                                break;
+                            }
+                        }
+                    }
+                    Err(e) => log::warn!("failed to reload mTLS keyset, keeping current: {e}"),
+                }
+            }
+        });
+
+        Ok(Self { current: rx })
+    }
+
+    /// Returns the keyset in force right now.
+    pub fn current(&self) -> Arc<Keyset> {
+        self.current.borrow().clone()
+    }
+
+    /// Returns a clonable handle to observe subsequent rotations.
+    pub fn subscribe(&self) -> watch::Receiver<Arc<Keyset>> {
+        self.current.clone()
+    }
+}
+
+/// Reads a file into a byte vector, mapping IO failures to the local
+/// [`Result`] type used across the subnet runtime.
+fn read<P: AsRef<Path>>(path: P) -> Result<Vec<u8>> {
+    std::fs::read(&path).map_err(|e| {
+        Error::new(
+            ErrorKind::Other,
+            format!("failed to read {}: {e}", path.as_ref().display()),
+        )
+    })
+}
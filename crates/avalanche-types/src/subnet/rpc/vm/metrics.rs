@@ -0,0 +1,368 @@
+//! Prometheus metrics and pluggable request interceptors for the RPC Chain VM
+//! gRPC server.
+//!
+//! The [`Intercepted`] service wraps the generated [`VmServer`] without touching
+//! any `VmImpl` handler: it times every `Initialize`/`BuildBlock`/... call,
+//! feeds the observation to a list of [`Interceptor`]s, and short-circuits the
+//! request when an interceptor rejects it (e.g. a rate limiter). [`ServerMetrics`]
+//! is the built-in interceptor that records request counts, latency histograms,
+//! in-flight gauges, and error counters, and can export them in Prometheus text
+//! format over a sidecar endpoint.
+//!
+//! [`VmServer`]: crate::proto::pb::vm::vm_server::VmServer
+
+use std::{
+    convert::Infallible,
+    future::Future,
+    io::{Error, ErrorKind, Result},
+    net::SocketAddr,
+    pin::Pin,
+    sync::{Arc, Mutex},
+    task::{Context, Poll},
+    time::{Duration, Instant},
+};
+
+use http::{Request, Response};
+use hyper::Body;
+use prometheus::{
+    Encoder, HistogramOpts, HistogramVec, IntCounterVec, IntGaugeVec, Opts, Registry, TextEncoder,
+};
+use tonic::{body::BoxBody, server::NamedService};
+use tower_service::Service;
+
+/// A pluggable hook invoked for every gRPC call the VM server handles.
+///
+/// Implementors observe the `/vm.VM/<Method>` path before and after the inner
+/// handler runs. Returning an error from [`on_request`](Interceptor::on_request)
+/// short-circuits the call with that gRPC status, which is how per-method rate
+/// limiting rejects a request without ever reaching the handler.
+pub trait Interceptor: Send + Sync + 'static {
+    /// Called before the inner handler. Returning `Err` rejects the request with
+    /// the given status. The default admits every request.
+    fn on_request(&self, method: &str) -> std::result::Result<(), tonic::Status> {
+        let _ = method;
+        Ok(())
+    }
+
+    /// Called once the inner handler completes. `ok` is false when the response
+    /// carries a non-zero `grpc-status` header. The default does nothing.
+    fn on_response(&self, method: &str, elapsed: Duration, ok: bool) {
+        let (_, _, _) = (method, elapsed, ok);
+    }
+}
+
+/// Shared, ordered list of interceptors applied to each request.
+pub type Interceptors = Arc<Vec<Arc<dyn Interceptor>>>;
+
+/// Wraps a tonic service so each call is timed and routed through the configured
+/// [`Interceptor`]s. Clone is cheap: the interceptor list is shared via `Arc`.
+#[derive(Clone)]
+pub struct Intercepted<S> {
+    inner: S,
+    interceptors: Interceptors,
+}
+
+impl<S> Intercepted<S> {
+    pub fn new(inner: S, interceptors: Interceptors) -> Self {
+        Self {
+            inner,
+            interceptors,
+        }
+    }
+}
+
+impl<S: NamedService> NamedService for Intercepted<S> {
+    const NAME: &'static str = S::NAME;
+}
+
+impl<S> Service<Request<Body>> for Intercepted<S>
+where
+    S: Service<Request<Body>, Response = Response<BoxBody>, Error = Infallible>
+        + Clone
+        + Send
+        + 'static,
+    S::Future: Send + 'static,
+{
+    type Response = Response<BoxBody>;
+    type Error = Infallible;
+    type Future = Pin<Box<dyn Future<Output = std::result::Result<Response<BoxBody>, Infallible>> + Send>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<std::result::Result<(), Infallible>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, req: Request<Body>) -> Self::Future {
+        let method = req.uri().path().to_string();
+        let interceptors = self.interceptors.clone();
+
+        // `self.inner` may not be ready after being moved, so swap in a clone and
+        // drive the already-ready instance, per the tower cloning convention.
+        let clone = self.inner.clone();
+        let mut inner = std::mem::replace(&mut self.inner, clone);
+
+        Box::pin(async move {
+            for ic in interceptors.iter() {
+                if let Err(status) = ic.on_request(&method) {
+                    return Ok(status.to_http());
+                }
+            }
+
+            let start = Instant::now();
+            let resp = inner.call(req).await?;
+            let elapsed = start.elapsed();
+
+            let ok = grpc_ok(&resp);
+            for ic in interceptors.iter() {
+                ic.on_response(&method, elapsed, ok);
+            }
+            Ok(resp)
+        })
+    }
+}
+
+/// Reads the response's `grpc-status` header, treating absent or `0` as success.
+/// For streaming calls the final status is delivered in trailers rather than
+/// headers, so this reflects only statuses known at response-head time.
+fn grpc_ok(resp: &Response<BoxBody>) -> bool {
+    match resp.headers().get("grpc-status") {
+        Some(v) => v.to_str().map(|s| s == "0").unwrap_or(false),
+        None => true,
+    }
+}
+
+/// Latency histogram buckets, in seconds, spanning sub-millisecond handlers up
+/// to multi-second block builds.
+const LATENCY_BUCKETS: &[f64] = &[
+    0.0005, 0.001, 0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0,
+];
+
+/// Built-in [`Interceptor`] exporting per-method VM server metrics into a
+/// Prometheus [`Registry`].
+#[derive(Clone)]
+pub struct ServerMetrics {
+    registry: Registry,
+    requests: IntCounterVec,
+    errors: IntCounterVec,
+    in_flight: IntGaugeVec,
+    latency: HistogramVec,
+}
+
+impl ServerMetrics {
+    /// Registers the metric families under `namespace` (e.g. `vm`) in a fresh
+    /// registry.
+    pub fn new(namespace: &str) -> Result<Self> {
+        let registry = Registry::new();
+
+        let requests = IntCounterVec::new(
+            Opts::new("grpc_requests_total", "total gRPC requests by method")
+                .namespace(namespace.to_string()),
+            &["method"],
+        )
+        .map_err(reg_err)?;
+        let errors = IntCounterVec::new(
+            Opts::new("grpc_request_errors_total", "total gRPC errors by method")
+                .namespace(namespace.to_string()),
+            &["method"],
+        )
+        .map_err(reg_err)?;
+        let in_flight = IntGaugeVec::new(
+            Opts::new("grpc_requests_in_flight", "in-flight gRPC requests by method")
+                .namespace(namespace.to_string()),
+            &["method"],
+        )
+        .map_err(reg_err)?;
+        let latency = HistogramVec::new(
+            HistogramOpts::new("grpc_request_duration_seconds", "gRPC request latency by method")
+                .namespace(namespace.to_string())
+                .buckets(LATENCY_BUCKETS.to_vec()),
+            &["method"],
+        )
+        .map_err(reg_err)?;
+
+        registry.register(Box::new(requests.clone())).map_err(reg_err)?;
+        registry.register(Box::new(errors.clone())).map_err(reg_err)?;
+        registry.register(Box::new(in_flight.clone())).map_err(reg_err)?;
+        registry.register(Box::new(latency.clone())).map_err(reg_err)?;
+
+        Ok(Self {
+            registry,
+            requests,
+            errors,
+            in_flight,
+            latency,
+        })
+    }
+
+    /// The registry holding the VM server metric families, for callers that want
+    /// to register additional collectors alongside them.
+    pub fn registry(&self) -> &Registry {
+        &self.registry
+    }
+
+    /// Encodes the current metrics in Prometheus text exposition format.
+    pub fn gather_text(&self) -> Result<String> {
+        let mut buf = Vec::new();
+        let encoder = TextEncoder::new();
+        encoder
+            .encode(&self.registry.gather(), &mut buf)
+            .map_err(|e| Error::new(ErrorKind::Other, format!("failed to encode metrics: {e}")))?;
+        String::from_utf8(buf)
+            .map_err(|e| Error::new(ErrorKind::Other, format!("metrics not utf-8: {e}")))
+    }
+}
+
+impl Interceptor for ServerMetrics {
+    fn on_request(&self, method: &str) -> std::result::Result<(), tonic::Status> {
+        self.requests.with_label_values(&[method]).inc();
+        self.in_flight.with_label_values(&[method]).inc();
+        Ok(())
+    }
+
+    fn on_response(&self, method: &str, elapsed: Duration, ok: bool) {
+        self.in_flight.with_label_values(&[method]).dec();
+        self.latency
+            .with_label_values(&[method])
+            .observe(elapsed.as_secs_f64());
+        if !ok {
+            self.errors.with_label_values(&[method]).inc();
+        }
+    }
+}
+
+/// [`Interceptor`] that logs each completed call at debug level, for operators
+/// who want request-scoped tracing without editing handlers.
+#[derive(Clone, Debug, Default)]
+pub struct LoggingInterceptor;
+
+impl Interceptor for LoggingInterceptor {
+    fn on_response(&self, method: &str, elapsed: Duration, ok: bool) {
+        log::debug!("grpc {} took {:?} (ok={})", method, elapsed, ok);
+    }
+}
+
+/// Simple per-method concurrency limiter: rejects a call with
+/// `ResourceExhausted` once the configured number of in-flight requests for that
+/// method is reached, and releases the slot when the call completes.
+#[derive(Clone)]
+pub struct RateLimiter {
+    max_in_flight: u64,
+    current: Arc<Mutex<std::collections::HashMap<String, u64>>>,
+}
+
+impl RateLimiter {
+    pub fn new(max_in_flight: u64) -> Self {
+        Self {
+            max_in_flight,
+            current: Arc::new(Mutex::new(std::collections::HashMap::new())),
+        }
+    }
+}
+
+impl Interceptor for RateLimiter {
+    fn on_request(&self, method: &str) -> std::result::Result<(), tonic::Status> {
+        let mut current = self.current.lock().unwrap();
+        let n = current.entry(method.to_string()).or_insert(0);
+        if *n >= self.max_in_flight {
+            return Err(tonic::Status::resource_exhausted(format!(
+                "per-method rate limit reached for {method}"
+            )));
+        }
+        *n += 1;
+        Ok(())
+    }
+
+    fn on_response(&self, method: &str, _elapsed: Duration, _ok: bool) {
+        let mut current = self.current.lock().unwrap();
+        if let Some(n) = current.get_mut(method) {
+            *n = n.saturating_sub(1);
+        }
+    }
+}
+
+/// Serves the metrics in Prometheus text format over a dedicated HTTP endpoint
+/// (`GET /metrics`) until `stop_ch` fires. Runs alongside the gRPC server so the
+/// exposition format stays off the gRPC wire.
+pub async fn serve_metrics(
+    addr: SocketAddr,
+    metrics: ServerMetrics,
+    mut stop_ch: tokio::sync::broadcast::Receiver<()>,
+) -> Result<()> {
+    use hyper::service::{make_service_fn, service_fn};
+    use jsonrpc_core::futures::FutureExt;
+
+    let make_svc = make_service_fn(move |_conn| {
+        let metrics = metrics.clone();
+        async move {
+            Ok::<_, Infallible>(service_fn(move |req: Request<Body>| {
+                let metrics = metrics.clone();
+                async move {
+                    if req.uri().path() != "/metrics" {
+                        return Ok::<_, Infallible>(
+                            Response::builder()
+                                .status(http::StatusCode::NOT_FOUND)
+                                .body(Body::empty())
+                                .unwrap(),
+                        );
+                    }
+                    let body = match metrics.gather_text() {
+                        Ok(s) => s,
+                        Err(e) => {
+                            return Ok(Response::builder()
+                                .status(http::StatusCode::INTERNAL_SERVER_ERROR)
+                                .body(Body::from(e.to_string()))
+                                .unwrap());
+                        }
+                    };
+                    Ok(Response::new(Body::from(body)))
+                }
+            }))
+        }
+    });
+
+    hyper::Server::bind(&addr)
+        .serve(make_svc)
+        .with_graceful_shutdown(stop_ch.recv().map(|_| ()))
+        .await
+        .map_err(|e| Error::new(ErrorKind::Other, format!("metrics server failed: {e}")))?;
+    log::info!("metrics server shutdown complete: {}", addr);
+    Ok(())
+}
+
+fn reg_err<E: std::fmt::Display>(e: E) -> Error {
+    Error::new(ErrorKind::Other, format!("failed to register metric: {e}"))
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- subnet::rpc::vm::metrics::test_server_metrics --exact --show-output
+#[test]
+fn test_server_metrics() {
+    let metrics = ServerMetrics::new("vm").unwrap();
+
+    metrics.on_request("/vm.VM/BuildBlock").unwrap();
+    metrics.on_response("/vm.VM/BuildBlock", Duration::from_millis(5), true);
+    metrics.on_request("/vm.VM/BuildBlock").unwrap();
+    metrics.on_response("/vm.VM/BuildBlock", Duration::from_millis(5), false);
+
+    let text = metrics.gather_text().unwrap();
+    assert!(text.contains("vm_grpc_requests_total"));
+    assert!(text.contains("vm_grpc_request_errors_total"));
+    assert!(text.contains("vm_grpc_request_duration_seconds"));
+    // in-flight returns to zero after both calls complete.
+    assert!(text.contains("vm_grpc_requests_in_flight{method=\"/vm.VM/BuildBlock\"} 0"));
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- subnet::rpc::vm::metrics::test_rate_limiter --exact --show-output
+#[test]
+fn test_rate_limiter() {
+    let limiter = RateLimiter::new(1);
+
+    // first call admitted, second rejected while the first is in flight.
+    assert!(limiter.on_request("/vm.VM/SetState").is_ok());
+    assert!(limiter.on_request("/vm.VM/SetState").is_err());
+
+    // releasing the slot admits the next call.
+    limiter.on_response("/vm.VM/SetState", Duration::from_millis(1), true);
+    assert!(limiter.on_request("/vm.VM/SetState").is_ok());
+
+    // a different method has its own independent budget.
+    assert!(limiter.on_request("/vm.VM/BuildBlock").is_ok());
+}
@@ -1,10 +1,13 @@
 //! RPC Chain VM implementation.
+pub mod metrics;
 pub mod server;
+pub mod test;
 
 use std::{
     env,
     io::{Error, ErrorKind, Result},
     net::SocketAddr,
+    sync::Arc,
     time::Duration,
 };
 
@@ -80,16 +83,148 @@ where
             )
         })?;
 
-    serve_with_address(vm, vm_server_addr, stop_ch).await
+    // when the runtime provisions mTLS material via the environment, bring up
+    // the server in mutually-authenticated mode with a trusted-peer keyset that
+    // reloads on a schedule; otherwise fall back to the plaintext transport.
+    let tls = mtls_from_env()?;
+    serve_with_address_and_readiness(vm, vm_server_addr, stop_ch, None, tls).await
 }
 
-pub async fn serve_with_address<V>(vm: V, addr: SocketAddr, mut stop_ch: Receiver<()>) -> Result<()>
+pub async fn serve_with_address<V>(vm: V, addr: SocketAddr, stop_ch: Receiver<()>) -> Result<()>
+where
+    V: VmImpl,
+{
+    serve_with_address_and_readiness(vm, addr, stop_ch, None, None).await
+}
+
+/// Environment variables the runtime uses to hand the VM its mTLS identity and
+/// the allow-list of trusted peer certificates. When `VM_SERVER_TLS_CERT` is
+/// unset the server stays on the plaintext transport.
+const TLS_CERT_KEY: &str = "VM_SERVER_TLS_CERT";
+const TLS_KEY_KEY: &str = "VM_SERVER_TLS_KEY";
+const TLS_TRUSTED_PEERS_KEY: &str = "VM_SERVER_TLS_TRUSTED_PEERS";
+
+/// Builds the rotating mTLS keyset from the runtime-provided environment, or
+/// `None` when no certificate is configured. `VM_SERVER_TLS_TRUSTED_PEERS` is a
+/// comma-separated list of trusted peer certificate paths.
+fn mtls_from_env() -> Result<Option<utils::tls::Rotating>> {
+    let cert_path = match env::var(TLS_CERT_KEY) {
+        Ok(v) => v,
+        Err(_) => return Ok(None),
+    };
+    let key_path = env::var(TLS_KEY_KEY).map_err(|e| {
+        Error::new(
+            ErrorKind::Other,
+            format!("{TLS_CERT_KEY} set but {TLS_KEY_KEY} missing: {e}"),
+        )
+    })?;
+    let trusted_peer_cert_paths = env::var(TLS_TRUSTED_PEERS_KEY)
+        .map(|v| v.split(',').filter(|s| !s.is_empty()).map(Into::into).collect())
+        .unwrap_or_default();
+
+    let paths = utils::tls::Paths {
+        cert_path: cert_path.into(),
+        key_path: key_path.into(),
+        trusted_peer_cert_paths,
+    };
+    Ok(Some(utils::tls::Rotating::spawn(paths, None)?))
+}
+
+/// Address for the Prometheus metrics sidecar endpoint. When unset the metrics
+/// are still collected but not exported over HTTP.
+const METRICS_ADDR_KEY: &str = "VM_SERVER_METRICS_ADDR";
+/// When set, enables request-scoped debug logging of every gRPC call.
+const METRICS_LOG_KEY: &str = "VM_SERVER_REQUEST_LOG";
+/// When set to a positive integer, caps in-flight requests per method.
+const METRICS_RATE_LIMIT_KEY: &str = "VM_SERVER_RATE_LIMIT";
+
+/// Builds the interceptor stack from the environment. Metrics are always
+/// collected; logging and per-method rate limiting are opt-in. Returns the
+/// metrics handle (for the sidecar), the shared interceptor list, and the
+/// optional sidecar address.
+fn interceptors_from_env() -> Result<(
+    metrics::ServerMetrics,
+    metrics::Interceptors,
+    Option<SocketAddr>,
+)> {
+    let server_metrics = metrics::ServerMetrics::new("vm")?;
+
+    let mut interceptors: Vec<Arc<dyn metrics::Interceptor>> =
+        vec![Arc::new(server_metrics.clone())];
+    if env::var(METRICS_LOG_KEY).is_ok() {
+        interceptors.push(Arc::new(metrics::LoggingInterceptor));
+    }
+    if let Ok(v) = env::var(METRICS_RATE_LIMIT_KEY) {
+        let max = v.parse::<u64>().map_err(|e| {
+            Error::new(
+                ErrorKind::Other,
+                format!("invalid {METRICS_RATE_LIMIT_KEY}: {e}"),
+            )
+        })?;
+        if max > 0 {
+            interceptors.push(Arc::new(metrics::RateLimiter::new(max)));
+        }
+    }
+
+    let metrics_addr = match env::var(METRICS_ADDR_KEY) {
+        Ok(v) => Some(v.parse::<SocketAddr>().map_err(|e| {
+            Error::new(ErrorKind::Other, format!("invalid {METRICS_ADDR_KEY}: {e}"))
+        })?),
+        Err(_) => None,
+    };
+
+    Ok((server_metrics, Arc::new(interceptors), metrics_addr))
+}
+
+/// Same as [`serve_with_address`] but, when `readiness` is supplied, flips the
+/// gRPC health reporter between serving and not-serving as the `Vm`'s own
+/// readiness changes so those transitions propagate to `health::watch`
+/// subscribers.
+pub async fn serve_with_address_and_readiness<V>(
+    vm: V,
+    addr: SocketAddr,
+    mut stop_ch: Receiver<()>,
+    readiness: Option<tokio::sync::watch::Receiver<bool>>,
+    tls: Option<utils::tls::Rotating>,
+) -> Result<()>
 where
     V: VmImpl,
 {
     let (mut health_reporter, health_svc) = health_reporter();
     health_reporter.set_serving::<HealthServer>().await;
 
+    // instrument the tonic stack with per-method metrics and any operator-enabled
+    // logging / rate-limiting interceptors, and optionally expose the metrics over
+    // a sidecar HTTP endpoint.
+    let (server_metrics, interceptors, metrics_addr) = interceptors_from_env()?;
+    if let Some(metrics_addr) = metrics_addr {
+        let server_metrics = server_metrics.clone();
+        let stop_ch = stop_ch.resubscribe();
+        tokio::spawn(async move {
+            if let Err(e) = metrics::serve_metrics(metrics_addr, server_metrics, stop_ch).await {
+                log::warn!("metrics server exited: {e}");
+            }
+        });
+    }
+
+    if let Some(mut readiness) = readiness {
+        let mut health_reporter = health_reporter.clone();
+        tokio::spawn(async move {
+            loop {
+                let ready = *readiness.borrow_and_update();
+                if ready {
+                    health_reporter.set_serving::<HealthServer>().await;
+                } else {
+                    health_reporter.set_not_serving::<HealthServer>().await;
+                }
+                if readiness.changed().await.is_err() {
+                    // sender dropped; stop propagating readiness transitions
+                    break;
+                }
+            }
+        });
+    }
+
     // ref. https://github.com/hyperium/tonic/blob/v0.7.2/examples/src/reflection/server.rs
     // ref. https://docs.rs/prost-types/latest/prost_types/struct.FileDescriptorSet.html
     let reflection_service = tonic_reflection::server::Builder::configure()
@@ -106,10 +241,10 @@ where
             )
         })?;
 
-    utils::grpc::default_server()
+    utils::grpc::default_server_with_tls(tls.as_ref())?
         .add_service(health_svc)
         .add_service(reflection_service)
-        .add_service(VmServer::new(vm))
+        .add_service(metrics::Intercepted::new(VmServer::new(vm), interceptors))
         .serve_with_shutdown(addr, stop_ch.recv().map(|_| ()))
         .await
         .map_err(|e| Error::new(ErrorKind::Other, format!("grpc server failed: {:?}", e)))?;
@@ -0,0 +1,175 @@
+//! In-process test harness for exercising a [`Vm`](crate::proto::pb::vm::vm_server::Vm)
+//! implementation end-to-end without spinning up avalanchego.
+//!
+//! [`TestServer::start`] binds `VmServer` (plus the health-reporter and reflection
+//! services that the real plugin server registers) on an ephemeral loopback port
+//! and hands back a connected [`VmClient`]. Crate users can then write
+//! `#[tokio::test]` coverage of `initialize`, `build_block`, `set_preference`, etc.
+//! against their own `Vm` trait impl.
+use std::{
+    io::{Error, ErrorKind, Result},
+    net::SocketAddr,
+    time::Duration,
+};
+
+use crate::{
+    proto::{
+        pb::{
+            self,
+            vm::{vm_client::VmClient, vm_server::Vm as VmImpl, vm_server::VmServer},
+        },
+        PROTOCOL_VERSION,
+    },
+    subnet::rpc::utils,
+};
+use jsonrpc_core::futures::FutureExt;
+use tokio::sync::broadcast;
+use tonic::transport::{server::NamedService, Channel};
+use tonic_health::server::health_reporter;
+
+/// Health Service for the test VM server, mirroring the real server registration.
+struct HealthServer;
+
+impl NamedService for HealthServer {
+    const NAME: &'static str = "vm server";
+}
+
+/// A running in-process `VmServer` bound to an ephemeral loopback port.
+///
+/// Dropping the server (or calling [`TestServer::stop`]) signals the shutdown
+/// channel so the background task exits.
+pub struct TestServer {
+    addr: SocketAddr,
+    stop_tx: broadcast::Sender<()>,
+}
+
+impl TestServer {
+    /// Starts `VmServer` on an ephemeral loopback port and returns a handle once
+    /// the listener accepts a connection, or after 50 failed connection attempts
+    /// 10ms apart, whichever comes first.
+    pub async fn start<V>(vm: V) -> Result<Self>
+    where
+        V: VmImpl,
+    {
+        let addr = utils::new_socket_addr();
+        let (stop_tx, mut stop_ch) = broadcast::channel(1);
+
+        let (mut health_reporter, health_svc) = health_reporter();
+        health_reporter.set_serving::<HealthServer>().await;
+
+        let reflection_service = tonic_reflection::server::Builder::configure()
+            .register_encoded_file_descriptor_set(pb::rpcdb::FILE_DESCRIPTOR_SET)
+            .register_encoded_file_descriptor_set(pb::vm::FILE_DESCRIPTOR_SET)
+            .register_encoded_file_descriptor_set(pb::google::protobuf::FILE_DESCRIPTOR_SET)
+            .register_encoded_file_descriptor_set(pb::io::prometheus::client::FILE_DESCRIPTOR_SET)
+            .register_encoded_file_descriptor_set(tonic_health::pb::FILE_DESCRIPTOR_SET)
+            .build()
+            .map_err(|e| {
+                Error::new(
+                    ErrorKind::Other,
+                    format!("failed to create gRPC reflection service: {:?}", e),
+                )
+            })?;
+
+        tokio::spawn(async move {
+            if let Err(e) = utils::grpc::default_server()
+                .add_service(health_svc)
+                .add_service(reflection_service)
+                .add_service(VmServer::new(vm))
+                .serve_with_shutdown(addr, stop_ch.recv().map(|_| ()))
+                .await
+            {
+                log::warn!("test vm server failed: {:?}", e);
+            }
+        });
+
+        // Wait until the server is accepting connections so callers can connect
+        // immediately after `start` returns.
+        for _ in 0..50 {
+            if Channel::from_shared(format!("http://{addr}"))
+                .expect("valid endpoint")
+                .connect()
+                .await
+                .is_ok()
+            {
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+
+        Ok(Self { addr, stop_tx })
+    }
+
+    /// The loopback address the server is bound to.
+    pub fn address(&self) -> SocketAddr {
+        self.addr
+    }
+
+    /// Opens a connected [`VmClient`] to the running server.
+    pub async fn client(&self) -> Result<VmClient<Channel>> {
+        let conn = utils::grpc::default_client(&self.addr.to_string())?
+            .connect()
+            .await
+            .map_err(|e| {
+                Error::new(
+                    ErrorKind::Other,
+                    format!("failed to connect test vm client: {e}"),
+                )
+            })?;
+        Ok(VmClient::new(conn))
+    }
+
+    /// Signals the server to shut down.
+    pub fn stop(&self) {
+        let _ = self.stop_tx.send(());
+    }
+}
+
+impl Drop for TestServer {
+    fn drop(&mut self) {
+        self.stop();
+    }
+}
+
+/// Formats the go-plugin handshake string the real plugin server prints to
+/// stdout, matching [`crate::subnet::rpc::plugin::serve_with_address`].
+pub fn handshake_message(addr: SocketAddr) -> String {
+    format!("1|{}|tcp|{}|grpc|", PROTOCOL_VERSION, addr)
+}
+
+/// Parses a go-plugin handshake string into its `(core_protocol, app_protocol,
+/// network, address)` components, erroring on a malformed line. Useful for
+/// asserting the handshake format and negotiated protocol version in tests.
+pub fn parse_handshake(msg: &str) -> Result<(u32, String, String, String)> {
+    let parts: Vec<&str> = msg.trim_end_matches('|').split('|').collect();
+    if parts.len() != 5 || parts[4] != "grpc" {
+        return Err(Error::new(
+            ErrorKind::InvalidData,
+            format!("malformed handshake message '{msg}'"),
+        ));
+    }
+    let core_protocol = parts[0]
+        .parse::<u32>()
+        .map_err(|e| Error::new(ErrorKind::InvalidData, format!("invalid core protocol: {e}")))?;
+    Ok((
+        core_protocol,
+        parts[1].to_owned(),
+        parts[2].to_owned(),
+        parts[3].to_owned(),
+    ))
+}
+
+#[test]
+fn test_parse_handshake() {
+    let addr: SocketAddr = "127.0.0.1:9651".parse().unwrap();
+    let msg = handshake_message(addr);
+
+    let (core, app, network, address) = parse_handshake(&msg).unwrap();
+    assert_eq!(core, 1);
+    assert_eq!(app, PROTOCOL_VERSION.to_string());
+    assert_eq!(network, "tcp");
+    assert_eq!(address, addr.to_string());
+
+    assert!(parse_handshake("1|2|tcp|127.0.0.1:1|netrpc|").is_err());
+    assert!(parse_handshake("bogus").is_err());
+}
@@ -8,9 +8,17 @@ use super::{
     errors::{Error, Result},
     hash, ids, key, packer, platformvm,
 };
+use async_trait::async_trait;
 use serde::{Deserialize, Serialize};
 use serde_with::serde_as;
 
+/// Codec versions this module knows how to encode and decode. `pack`/`unpack`
+/// reject any version outside this set up front -- mirroring how Wormhole's VAA
+/// parser refuses unsupported versions before reading the body -- so the
+/// per-version body layout can be dispatched through the codec registry rather
+/// than a fixed field walk.
+pub const SUPPORTED_CODEC_VERSIONS: &[u16] = &[codec::VERSION];
+
 /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/components/avax#BaseTx>
 #[serde_as]
 #[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
@@ -59,6 +67,15 @@ impl Tx {
         *(codec::X_TYPES.get(&Self::type_name()).unwrap()) as u32
     }
 
+    /// Most recent codec version supported by this crate, so callers building
+    /// transactions don't have to hardcode `0`.
+    pub fn latest_codec_version() -> u16 {
+        *SUPPORTED_CODEC_VERSIONS
+            .iter()
+            .max()
+            .expect("SUPPORTED_CODEC_VERSIONS must not be empty")
+    }
+
     /// "Tx.Unsigned" is implemented by "avax.BaseTx"
     /// but for marshal, it's passed as an interface.
     /// Then marshaled via "avalanchego/codec/linearcodec.linearCodec"
@@ -81,6 +98,13 @@ impl Tx {
     pub fn pack(&self, codec_version: u16, type_id: u32) -> Result<packer::Packer> {
         // ref. "avalanchego/codec.manager.Marshal", "vms/avm.newCustomCodecs"
         // ref. "math.MaxInt32" and "constants.DefaultByteSliceCap" in Go
+        if !SUPPORTED_CODEC_VERSIONS.contains(&codec_version) {
+            return Err(Error::Other {
+                message: format!("unsupported codec version {codec_version}"),
+                retryable: false,
+            });
+        }
+
         let packer = packer::Packer::new((1 << 31) - 1, 128);
 
         // codec version
@@ -95,228 +119,30 @@ impl Tx {
         packer.pack_u32(self.network_id)?;
         packer.pack_bytes(self.blockchain_id.as_ref())?;
 
-        // "transferable_outputs" field; pack the number of slice elements
-        if self.transferable_outputs.is_some() {
-            let transferable_outputs = self.transferable_outputs.as_ref().unwrap();
+        // "transferable_outputs" field; pack the number of slice elements then
+        // delegate each element to its own [`packer::Packable`] implementation
+        if let Some(transferable_outputs) = &self.transferable_outputs {
             packer.pack_u32(transferable_outputs.len() as u32)?;
-
             for transferable_output in transferable_outputs.iter() {
-                // "TransferableOutput.Asset" is struct and serialize:"true"
-                // but embedded inline in the struct "TransferableOutput"
-                // so no need to encode type ID
-                // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/components/avax#TransferableOutput
-                // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/components/avax#Asset
-                packer.pack_bytes(transferable_output.asset_id.as_ref())?;
-
-                // fx_id is serialize:"false" thus skipping serialization
-
-                // decide the type
-                // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/components/avax#TransferableOutput
-                if transferable_output.transfer_output.is_none()
-                    && transferable_output.stakeable_lock_out.is_none()
-                {
-                    return Err(Error::Other {
-                        message: "unexpected Nones in TransferableOutput transfer_output and stakeable_lock_out".to_string(),
-                        retryable: false,
-                    });
-                }
-                let type_id_transferable_out = {
-                    if transferable_output.transfer_output.is_some() {
-                        key::secp256k1::txs::transfer::Output::type_id()
-                    } else {
-                        platformvm::txs::StakeableLockOut::type_id()
-                    }
-                };
-                // marshal type ID for "key::secp256k1::txs::transfer::Output" or "platformvm::txs::StakeableLockOut"
-                packer.pack_u32(type_id_transferable_out)?;
-
-                match type_id_transferable_out {
-                    7 => {
-                        // "key::secp256k1::txs::transfer::Output"
-                        // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/secp256k1fx#TransferOutput
-                        let transfer_output = transferable_output.transfer_output.clone().unwrap();
-
-                        // marshal "secp256k1fx.TransferOutput.Amt" field
-                        packer.pack_u64(transfer_output.amount)?;
-
-                        // "secp256k1fx.TransferOutput.OutputOwners" is struct and serialize:"true"
-                        // but embedded inline in the struct "TransferOutput"
-                        // so no need to encode type ID
-                        // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/secp256k1fx#TransferOutput
-                        // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/secp256k1fx#OutputOwners
-                        packer.pack_u64(transfer_output.output_owners.locktime)?;
-                        packer.pack_u32(transfer_output.output_owners.threshold)?;
-                        packer.pack_u32(transfer_output.output_owners.addresses.len() as u32)?;
-                        for addr in transfer_output.output_owners.addresses.iter() {
-                            packer.pack_bytes(addr.as_ref())?;
-                        }
-                    }
-                    22 => {
-                        // "platformvm::txs::StakeableLockOut"
-                        // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm#StakeableLockOut
-                        let stakeable_lock_out =
-                            transferable_output.stakeable_lock_out.clone().unwrap();
-
-                        // marshal "platformvm::txs::StakeableLockOut.locktime" field
-                        packer.pack_u64(stakeable_lock_out.locktime)?;
-
-                        // secp256k1fx.TransferOutput type ID
-                        packer.pack_u32(7)?;
-
-                        // "platformvm.StakeableLockOut.TransferOutput" is struct and serialize:"true"
-                        // but embedded inline in the struct "StakeableLockOut"
-                        // so no need to encode type ID
-                        // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm#StakeableLockOut
-                        // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/secp256k1fx#TransferOutput
-                        // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/secp256k1fx#OutputOwners
-                        //
-                        // marshal "secp256k1fx.TransferOutput.Amt" field
-                        packer.pack_u64(stakeable_lock_out.transfer_output.amount)?;
-                        packer
-                            .pack_u64(stakeable_lock_out.transfer_output.output_owners.locktime)?;
-                        packer
-                            .pack_u32(stakeable_lock_out.transfer_output.output_owners.threshold)?;
-                        packer.pack_u32(
-                            stakeable_lock_out
-                                .transfer_output
-                                .output_owners
-                                .addresses
-                                .len() as u32,
-                        )?;
-                        for addr in stakeable_lock_out
-                            .transfer_output
-                            .output_owners
-                            .addresses
-                            .iter()
-                        {
-                            packer.pack_bytes(addr.as_ref())?;
-                        }
-                    }
-                    _ => {
-                        return Err(Error::Other {
-                            message: format!(
-                                "unexpected type ID {} for TransferableOutput",
-                                type_id_transferable_out
-                            ),
-                            retryable: false,
-                        })
-                    }
-                }
+                packer.pack(transferable_output)?;
             }
         } else {
             packer.pack_u32(0_u32)?;
         }
 
-        // "transferable_inputs" field; pack the number of slice elements
-        if self.transferable_inputs.is_some() {
-            let transferable_inputs = self.transferable_inputs.as_ref().unwrap();
+        // "transferable_inputs" field; pack the number of slice elements then
+        // delegate each element to its own [`packer::Packable`] implementation
+        if let Some(transferable_inputs) = &self.transferable_inputs {
             packer.pack_u32(transferable_inputs.len() as u32)?;
-
             for transferable_input in transferable_inputs.iter() {
-                // "TransferableInput.UTXOID" is struct and serialize:"true"
-                // but embedded inline in the struct "TransferableInput"
-                // so no need to encode type ID
-                // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/components/avax#TransferableInput
-                // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/components/avax#UTXOID
-                packer.pack_bytes(transferable_input.utxo_id.tx_id.as_ref())?;
-                packer.pack_u32(transferable_input.utxo_id.output_index)?;
-
-                // "TransferableInput.Asset" is struct and serialize:"true"
-                // but embedded inline in the struct "TransferableInput"
-                // so no need to encode type ID
-                // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/components/avax#TransferableInput
-                // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/components/avax#Asset
-                packer.pack_bytes(transferable_input.asset_id.as_ref())?;
-
-                // fx_id is serialize:"false" thus skipping serialization
-
-                // decide the type
-                // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/components/avax#TransferableInput
-                if transferable_input.transfer_input.is_none()
-                    && transferable_input.stakeable_lock_in.is_none()
-                {
-                    return Err(Error::Other {
-                        message: "unexpected Nones in TransferableInput transfer_input and stakeable_lock_in".to_string(),
-                        retryable: false,
-                    });
-                }
-                let type_id_transferable_in = {
-                    if transferable_input.transfer_input.is_some() {
-                        key::secp256k1::txs::transfer::Input::type_id()
-                    } else {
-                        platformvm::txs::StakeableLockIn::type_id()
-                    }
-                };
-                // marshal type ID for "key::secp256k1::txs::transfer::Input" or "platformvm::txs::StakeableLockIn"
-                packer.pack_u32(type_id_transferable_in)?;
-
-                match type_id_transferable_in {
-                    5 => {
-                        // "key::secp256k1::txs::transfer::Input"
-                        // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/secp256k1fx#TransferInput
-                        let transfer_input = transferable_input.transfer_input.clone().unwrap();
-
-                        // marshal "secp256k1fx.TransferInput.Amt" field
-                        packer.pack_u64(transfer_input.amount)?;
-
-                        // "secp256k1fx.TransferInput.Input" is struct and serialize:"true"
-                        // but embedded inline in the struct "TransferInput"
-                        // so no need to encode type ID
-                        // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/secp256k1fx#TransferInput
-                        // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/secp256k1fx#Input
-                        packer.pack_u32(transfer_input.sig_indices.len() as u32)?;
-                        for idx in transfer_input.sig_indices.iter() {
-                            packer.pack_u32(*idx)?;
-                        }
-                    }
-                    21 => {
-                        // "platformvm::txs::StakeableLockIn"
-                        // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm#StakeableLockIn
-                        let stakeable_lock_in =
-                            transferable_input.stakeable_lock_in.clone().unwrap();
-
-                        // marshal "platformvm::txs::StakeableLockIn.locktime" field
-                        packer.pack_u64(stakeable_lock_in.locktime)?;
-
-                        // "platformvm.StakeableLockIn.TransferableIn" is struct and serialize:"true"
-                        // but embedded inline in the struct "StakeableLockIn"
-                        // so no need to encode type ID
-                        // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm#StakeableLockIn
-                        // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/secp256k1fx#TransferInput
-                        // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/secp256k1fx#Input
-                        //
-                        // marshal "secp256k1fx.TransferInput.Amt" field
-                        packer.pack_u64(stakeable_lock_in.transfer_input.amount)?;
-                        //
-                        // "secp256k1fx.TransferInput.Input" is struct and serialize:"true"
-                        // but embedded inline in the struct "TransferInput"
-                        // so no need to encode type ID
-                        // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/secp256k1fx#TransferInput
-                        // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/secp256k1fx#Input
-                        packer
-                            .pack_u32(stakeable_lock_in.transfer_input.sig_indices.len() as u32)?;
-                        for idx in stakeable_lock_in.transfer_input.sig_indices.iter() {
-                            packer.pack_u32(*idx)?;
-                        }
-                    }
-                    _ => {
-                        return Err(Error::Other {
-                            message: format!(
-                                "unexpected type ID {} for TransferableInput",
-                                type_id_transferable_in
-                            ),
-                            retryable: false,
-                        })
-                    }
-                }
+                packer.pack(transferable_input)?;
             }
         } else {
             packer.pack_u32(0_u32)?;
         }
 
         // marshal "BaseTx.memo"
-        if self.memo.is_some() {
-            let memo = self.memo.as_ref().unwrap();
+        if let Some(memo) = &self.memo {
             packer.pack_u32(memo.len() as u32)?;
             packer.pack_bytes(memo)?;
         } else {
@@ -325,6 +151,101 @@ impl Tx {
 
         Ok(packer)
     }
+
+    /// Inverse of [`Tx::pack`]: reconstructs a `Tx` from AvalancheGo codec bytes.
+    ///
+    /// Reads the 2-byte codec version and 4-byte type ID, then walks the same
+    /// field order `pack` uses against a cursor -- `network_id`, `blockchain_id`,
+    /// the length-prefixed `transferable_outputs` (dispatching on the per-output
+    /// type ID: 7 for "secp256k1fx.TransferOutput", 22 for
+    /// "platformvm.StakeableLockOut"), the length-prefixed `transferable_inputs`
+    /// (5 for "secp256k1fx.TransferInput", 21 for "platformvm.StakeableLockIn"),
+    /// and finally the length-prefixed `memo`. The declared codec version is
+    /// validated against the supported set and unknown type IDs or truncated
+    /// input are returned as errors rather than panicking.
+    ///
+    /// Returns the decoded `(codec_version, type_id, Tx)`.
+    pub fn unpack(bytes: &[u8]) -> Result<(u16, u32, Tx)> {
+        let packer = packer::Packer::load_bytes_for_unpack((1 << 31) - 1, bytes);
+
+        let codec_version = packer.unpack_u16()?;
+        if !SUPPORTED_CODEC_VERSIONS.contains(&codec_version) {
+            return Err(Error::Other {
+                message: format!("unsupported codec version {codec_version}"),
+                retryable: false,
+            });
+        }
+        let type_id = packer.unpack_u32()?;
+
+        let tx = Tx::unpack_base(&packer)?;
+
+        Ok((codec_version, type_id, tx))
+    }
+
+    /// Reads the "avax.BaseTx" body -- everything after the 2-byte codec version
+    /// and 4-byte type ID -- from `packer`'s current cursor and leaves the cursor
+    /// positioned at the first tx-specific field. Shared by [`Tx::unpack`] and
+    /// the per-vm [`Decodable`] decoders, which continue reading from the same
+    /// cursor once the base tx has been consumed.
+    pub fn unpack_base(packer: &packer::Packer) -> Result<Tx> {
+        let network_id = packer.unpack_u32()?;
+        let blockchain_id = ids::Id::from_slice(&packer.unpack_bytes(ids::LEN)?);
+
+        // "transferable_outputs" field; each element decodes itself through the
+        // per-version codec registry rather than a type-ID match inlined here
+        let outs_len = packer.unpack_u32()? as usize;
+        let mut transferable_outputs: Vec<transferable::Output> = Vec::with_capacity(outs_len);
+        for _ in 0..outs_len {
+            transferable_outputs.push(packer.unpack()?);
+        }
+
+        // "transferable_inputs" field; likewise delegated to the registry
+        let ins_len = packer.unpack_u32()? as usize;
+        let mut transferable_inputs: Vec<transferable::Input> = Vec::with_capacity(ins_len);
+        for _ in 0..ins_len {
+            transferable_inputs.push(packer.unpack()?);
+        }
+
+        // "BaseTx.memo"
+        let memo_len = packer.unpack_u32()? as usize;
+        let memo = if memo_len > 0 {
+            Some(packer.unpack_bytes(memo_len)?)
+        } else {
+            None
+        };
+
+        Ok(Tx {
+            network_id,
+            blockchain_id,
+            transferable_outputs: (outs_len > 0).then_some(transferable_outputs),
+            transferable_inputs: (ins_len > 0).then_some(transferable_inputs),
+            memo,
+            ..Tx::default()
+        })
+    }
+
+    /// Canonical unsigned-transaction ID: the sha256 of the unsigned marshaled
+    /// bytes, which the network uses to reference UTXOs and tx status. Lets
+    /// callers obtain the on-chain tx ID without manually re-packing and hashing.
+    pub fn unsigned_id(&self, codec_version: u16) -> Result<ids::Id> {
+        let packer = self.pack(codec_version, Self::type_id())?;
+        let tx_bytes_with_no_signature = packer.take_bytes();
+        let id = hash::sha256(&tx_bytes_with_no_signature);
+        Ok(ids::Id::from_slice(&id))
+    }
+
+    /// Packs the unsigned bytes and, together with `signed_bytes`, fills
+    /// `self.metadata` via [`Metadata::new`], then runs [`Metadata::verify`].
+    pub fn initialize_metadata(&mut self, signed_bytes: &[u8]) -> Result<()> {
+        let packer = self.pack(Self::latest_codec_version(), Self::type_id())?;
+        let tx_bytes_with_no_signature = packer.take_bytes();
+
+        let metadata = Metadata::new(&tx_bytes_with_no_signature, signed_bytes);
+        metadata.verify()?;
+
+        self.metadata = Some(metadata);
+        Ok(())
+    }
 }
 
 /// RUST_LOG=debug cargo test --package avalanche-types --lib -- txs::test_base_tx_serialization --exact --show-output
@@ -475,6 +396,21 @@ fn test_base_tx_serialization() {
         &expected_unsigned_tx_bytes,
         &unsigned_tx_bytes
     ));
+
+    // round-trip: unpack the bytes and re-pack to the same vector
+    let (codec_version, type_id, unpacked_tx) =
+        Tx::unpack(&unsigned_tx_bytes).expect("failed to unpack unsigned_tx");
+    assert_eq!(codec_version, 0);
+    assert_eq!(type_id, Tx::type_id());
+
+    let repacked_tx_bytes = unpacked_tx
+        .pack(codec_version, type_id)
+        .expect("failed to re-pack unpacked_tx")
+        .take_bytes();
+    assert!(cmp_manager::eq_vectors(
+        &expected_unsigned_tx_bytes,
+        &repacked_tx_bytes
+    ));
 }
 
 /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/components/avax#Metadata>
@@ -520,3 +456,225 @@ impl Metadata {
         Ok(())
     }
 }
+
+/// Shared signing logic for the platformvm/avm unsigned tx types.
+///
+/// Every tx wraps an [`avax.BaseTx`](Tx) followed by a handful of type-specific
+/// fields and a trailing `secp256k1fx.Credential` section. The field packing
+/// differs per tx, but the tail -- hashing the unsigned bytes, assembling one
+/// credential per signer group, appending them, and initializing
+/// [`Metadata`] -- is identical everywhere. Implementors supply only
+/// [`Signable::type_id`], access to the embedded base tx, and
+/// [`Signable::pack_unsigned_fields`] (everything between the base tx and the
+/// credential section); the default [`Signable::sign`] handles the rest.
+/// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm/txs#Tx.Sign>
+#[async_trait]
+pub trait Signable {
+    /// Codec type ID of the unsigned tx, packed into the codec header.
+    fn type_id() -> u32
+    where
+        Self: Sized;
+
+    /// Immutable access to the embedded base tx, used to marshal the header.
+    fn base_tx(&self) -> &Tx;
+
+    /// Mutable access to the embedded base tx, used to populate [`Metadata`].
+    fn base_tx_mut(&mut self) -> &mut Tx;
+
+    /// Packs the tx-specific fields sitting between the base tx and the
+    /// credential section into `packer`.
+    fn pack_unsigned_fields(&self, packer: &packer::Packer) -> Result<()>;
+
+    /// Stores the credentials assembled during [`Signable::sign`] back onto the
+    /// tx -- a hook so avm txs can wrap them in their `fx::Credential`.
+    fn set_credentials(&mut self, creds: Vec<key::secp256k1::txs::Credential>);
+
+    /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm/txs#Tx.Sign>
+    /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/utils/crypto#PrivateKeyED25519.SignHash>
+    async fn sign<T: key::secp256k1::SignOnly>(&mut self, signers: Vec<Vec<T>>) -> Result<()>
+    where
+        Self: Sized,
+    {
+        // marshal "unsigned tx" with the codec version
+        let packer = self.base_tx().pack(codec::VERSION, Self::type_id())?;
+
+        // "avalanchego" marshals the whole struct again for signed bytes
+        // even when the underlying "unsigned_tx" is already once marshaled
+        // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm#Tx.Sign
+        //
+        // reuse the underlying packer to avoid marshaling the unsigned tx twice
+        // just marshal the next fields in the struct and pack them all together
+        // in the existing packer
+        let base = packer.take_bytes();
+        packer.set_bytes(&base);
+
+        // pack the type-specific fields (everything before the credentials)
+        self.pack_unsigned_fields(&packer)?;
+
+        // take bytes just for hashing computation
+        let tx_bytes_with_no_signature = packer.take_bytes();
+        packer.set_bytes(&tx_bytes_with_no_signature);
+
+        // compute sha256 for marshaled "unsigned tx" bytes
+        // IMPORTANT: take the hash only for the unsigned tx fields
+        // not other fields -- only hash the "*Tx.*" fields but not "Tx.Creds"
+        let tx_bytes_hash = hash::sha256(&tx_bytes_with_no_signature);
+
+        // number of of credentials
+        let creds_len = signers.len() as u32;
+        // pack the credential count
+        packer.pack_u32(creds_len)?;
+
+        // sign the hash with the signers (in case of multi-sig)
+        // and combine all signatures into a secp256k1fx credential.
+        //
+        // every signature is over the same `tx_bytes_hash`, so the per-key
+        // round-trips are independent and can be awaited concurrently -- this
+        // matters for the Ledger/HSM backends where each `sign_digest` carries
+        // real device/network latency. `try_join_all` preserves the order of
+        // the futures it is handed, so collecting the signatures (and the
+        // credentials) in `signers` order keeps the wire bytes byte-identical
+        // to the serial implementation.
+        let mut creds: Vec<key::secp256k1::txs::Credential> =
+            futures::future::try_join_all(signers.iter().map(|keys| async {
+                let sigs = futures::future::try_join_all(
+                    keys.iter().map(|k| k.sign_digest(&tx_bytes_hash)),
+                )
+                .await?
+                .into_iter()
+                .map(Vec::from)
+                .collect();
+                Ok::<_, Error>(key::secp256k1::txs::Credential { signatures: sigs })
+            }))
+            .await?;
+
+        // AvalancheGo rejects non-canonical (high-S) signatures; normalize
+        // each credential in place so what gets packed below is always
+        // acceptable to the network, regardless of what the signer returned.
+        for cred in creds.iter_mut() {
+            cred.normalize_s()?;
+        }
+        if creds_len > 0 {
+            // pack each "cred" which is "secp256k1fx.Credential"
+            let cred_type_id = key::secp256k1::txs::Credential::type_id();
+            for cred in creds.iter() {
+                // marshal type ID for "secp256k1fx.Credential"
+                packer.pack_u32(cred_type_id)?;
+
+                // marshal fields for "secp256k1fx.Credential"
+                packer.pack_u32(cred.signatures.len() as u32)?;
+                for sig in cred.signatures.iter() {
+                    packer.pack_bytes(sig)?;
+                }
+            }
+        }
+        self.set_credentials(creds);
+
+        let tx_bytes_with_signatures = packer.take_bytes();
+        let tx_id = hash::sha256(&tx_bytes_with_signatures);
+
+        // update "BaseTx.Metadata" with id/unsigned bytes/bytes
+        // ref. "avalanchego/vms/platformvm.Tx.Sign"
+        // ref. "avalanchego/vms/components/avax.BaseTx.Metadata.Initialize"
+        self.base_tx_mut().metadata = Some(Metadata {
+            id: ids::Id::from_slice(&tx_id),
+            tx_bytes_with_no_signature: tx_bytes_with_no_signature.to_vec(),
+            tx_bytes_with_signatures: tx_bytes_with_signatures.to_vec(),
+        });
+
+        Ok(())
+    }
+}
+
+/// Symmetric counterpart to [`Signable`]: reconstructs a signed transaction
+/// from its AvalancheGo wire bytes. Mirrors the Encodable/Decodable split in
+/// rust-bitcoin's `consensus::encode` so a type that implements both can
+/// round-trip raw bytes -- decoding `sign`'s output yields an equal value whose
+/// metadata re-encodes byte-for-byte.
+///
+/// The scaffolding that is identical everywhere -- validating the codec header,
+/// reading the base tx, reading the trailing `secp256k1fx.Credential` vector,
+/// and repopulating [`Metadata`] -- lives in the default
+/// [`Decodable::from_signed_bytes`]. Implementors supply only
+/// [`Decodable::type_id`], mutable access to the embedded base tx,
+/// [`Decodable::unpack_unsigned_fields`] (everything between the base tx and the
+/// credential section), and [`Decodable::set_credentials`].
+pub trait Decodable: Sized {
+    /// Codec type ID expected in the header, matching [`Signable::type_id`].
+    fn type_id() -> u32;
+
+    /// Mutable access to the embedded base tx, used to repopulate [`Metadata`]
+    /// once the wire bytes have been decoded.
+    fn base_tx_mut(&mut self) -> &mut Tx;
+
+    /// Reads the tx-specific fields sitting between the decoded `base_tx` and the
+    /// credential section, returning the assembled tx with empty credentials.
+    fn unpack_unsigned_fields(packer: &packer::Packer, base_tx: Tx) -> Result<Self>;
+
+    /// Stores the decoded credentials back onto the tx -- the inverse of
+    /// [`Signable::set_credentials`].
+    fn set_credentials(&mut self, creds: Vec<key::secp256k1::txs::Credential>);
+
+    /// Decodes a full signed tx from its wire bytes: validates the codec header,
+    /// reads the base tx and the tx-specific fields, then the `fx_creds` vector,
+    /// and repopulates [`Metadata`] so the result re-encodes to `bytes`.
+    fn from_signed_bytes(bytes: &[u8]) -> Result<Self> {
+        let packer = packer::Packer::load_bytes_for_unpack((1 << 31) - 1, bytes);
+
+        let codec_version = packer.unpack_u16()?;
+        if !SUPPORTED_CODEC_VERSIONS.contains(&codec_version) {
+            return Err(Error::Other {
+                message: format!("unsupported codec version {codec_version}"),
+                retryable: false,
+            });
+        }
+
+        let type_id = packer.unpack_u32()?;
+        if type_id != Self::type_id() {
+            return Err(Error::Other {
+                message: format!(
+                    "unexpected type ID {type_id} (expected {})",
+                    Self::type_id()
+                ),
+                retryable: false,
+            });
+        }
+
+        let base_tx = Tx::unpack_base(&packer)?;
+        let mut tx = Self::unpack_unsigned_fields(&packer, base_tx)?;
+
+        // everything read so far is the unsigned tx; the credential section
+        // follows and is excluded from the unsigned-tx hash
+        let unsigned_end = packer.get_offset();
+        let tx_bytes_with_no_signature = bytes[..unsigned_end].to_vec();
+
+        // "fx_creds" vector of "secp256k1fx.Credential"
+        let creds_len = packer.unpack_u32()? as usize;
+        let mut creds = Vec::with_capacity(creds_len);
+        for _ in 0..creds_len {
+            let cred_type_id = packer.unpack_u32()?;
+            if cred_type_id != key::secp256k1::txs::Credential::type_id() {
+                return Err(Error::Other {
+                    message: format!("unexpected type ID {cred_type_id} for Credential"),
+                    retryable: false,
+                });
+            }
+            let sigs_len = packer.unpack_u32()? as usize;
+            let mut signatures = Vec::with_capacity(sigs_len);
+            for _ in 0..sigs_len {
+                signatures.push(packer.unpack_bytes(key::secp256k1::signature::LEN)?);
+            }
+            creds.push(key::secp256k1::txs::Credential::new(signatures));
+        }
+        tx.set_credentials(creds);
+
+        // the signed-tx ID is the sha256 of the full signed bytes
+        tx.base_tx_mut().metadata = Some(Metadata {
+            id: ids::Id::from_slice(&hash::sha256(bytes)),
+            tx_bytes_with_no_signature,
+            tx_bytes_with_signatures: bytes.to_vec(),
+        });
+
+        Ok(tx)
+    }
+}
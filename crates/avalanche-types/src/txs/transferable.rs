@@ -1,9 +1,9 @@
 use std::cmp::Ordering;
 
 use crate::{
-    errors::Result,
+    errors::{Error, Result},
     ids, key,
-    packer::{Packable, Packer},
+    packer::{Packable, Packer, Unpackable},
     platformvm, txs,
 };
 use serde::{Deserialize, Serialize};
@@ -37,23 +37,22 @@ impl TransferableOut {
 
 impl Packable for TransferableOut {
     fn pack(&self, packer: &Packer) -> Result<()> {
+        // each variant emits its own type-ID prefix followed by its body
         match self {
-            TransferableOut::TransferOutput(transfer_output) => {
-                packer.pack(transfer_output)?;
-            }
+            TransferableOut::TransferOutput(transfer_output) => packer.pack(transfer_output),
             TransferableOut::StakeableLockOut(stakeable_lock_out) => {
-                // marshal type ID "platformvm::txs::StakeableLockOut"
-                packer.pack_u32(platformvm::txs::StakeableLockOut::type_id())?;
-
-                // "platformvm::txs::StakeableLockOut"
-                // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm#StakeableLockOut
-
-                // marshal "platformvm::txs::StakeableLockOut.locktime" field
-                packer.pack_u64(stakeable_lock_out.locktime)?;
-                packer.pack(&stakeable_lock_out.transfer_output)?;
+                packer.pack(stakeable_lock_out)
             }
         }
-        Ok(())
+    }
+}
+
+impl Unpackable for TransferableOut {
+    fn unpack(packer: &Packer) -> Result<Self> {
+        // dispatch on the interface type ID via the codec registry rather than
+        // matching integer literals here
+        let type_id = packer.unpack_peek_u32()?;
+        crate::codec::registry::unpack_output(crate::codec::VERSION, type_id, packer)
     }
 }
 
@@ -132,6 +131,27 @@ impl PartialEq for Output {
     }
 }
 
+impl Packable for Output {
+    /// Emits the embedded asset ID then delegates to the inner [`TransferableOut`],
+    /// which carries its own type-ID prefix.
+    fn pack(&self, packer: &Packer) -> Result<()> {
+        packer.pack_bytes(self.asset_id.as_ref())?;
+        packer.pack(&self.out)
+    }
+}
+
+impl Unpackable for Output {
+    fn unpack(packer: &Packer) -> Result<Self> {
+        let asset_id = ids::Id::from_slice(&packer.unpack_bytes(ids::LEN)?);
+        let out = packer.unpack()?;
+        Ok(Output {
+            asset_id,
+            out,
+            ..Output::default()
+        })
+    }
+}
+
 /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/components/avax#SortTransferableOutputs>
 /// ref. "avalanchego/vms/components/avax.TestTransferableOutputSorting"
 /// RUST_LOG=debug cargo test --package avalanche-types --lib -- txs::transferable::test_sort_transferable_outputs --exact --show-output
@@ -432,6 +452,54 @@ impl Default for Input {
     }
 }
 
+impl Packable for Input {
+    /// Emits the embedded "UTXOID" and asset ID then delegates to the inner
+    /// input, which carries its own type-ID prefix.
+    fn pack(&self, packer: &Packer) -> Result<()> {
+        packer.pack_bytes(self.utxo_id.tx_id.as_ref())?;
+        packer.pack_u32(self.utxo_id.output_index)?;
+        packer.pack_bytes(self.asset_id.as_ref())?;
+
+        if let Some(transfer_input) = &self.transfer_input {
+            packer.pack(transfer_input)
+        } else if let Some(stakeable_lock_in) = &self.stakeable_lock_in {
+            packer.pack(stakeable_lock_in)
+        } else {
+            Err(Error::Other {
+                message:
+                    "unexpected Nones in TransferableInput transfer_input and stakeable_lock_in"
+                        .to_string(),
+                retryable: false,
+            })
+        }
+    }
+}
+
+impl Unpackable for Input {
+    fn unpack(packer: &Packer) -> Result<Self> {
+        let tx_id = ids::Id::from_slice(&packer.unpack_bytes(ids::LEN)?);
+        let output_index = packer.unpack_u32()?;
+        let asset_id = ids::Id::from_slice(&packer.unpack_bytes(ids::LEN)?);
+
+        let utxo_id = txs::utxo::Id {
+            tx_id,
+            output_index,
+            ..txs::utxo::Id::default()
+        };
+
+        let input = Input {
+            utxo_id,
+            asset_id,
+            ..Input::default()
+        };
+
+        // dispatch on the interface type ID via the codec registry rather than
+        // matching integer literals here
+        let type_id = packer.unpack_peek_u32()?;
+        crate::codec::registry::unpack_input(crate::codec::VERSION, type_id, packer, input)
+    }
+}
+
 /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/components/avax#SortTransferableInputs>
 ///
 /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/components/avax#SortTransferableInputsWithSigners>
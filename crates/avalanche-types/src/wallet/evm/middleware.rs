@@ -0,0 +1,203 @@
+//! Composable middleware for the GSN relay path.
+//!
+//! The relay examples repeatedly hand-craft `getNonce` calldata, issue a raw
+//! `provider.call`, and decode `U256::from_big_endian`, while hardcoding the
+//! meta-transaction gas. This module ports the ethers-rs middleware-stacking
+//! idea into the wallet: a [`ForwarderNonceMiddleware`] that transparently
+//! resolves/caches/increments the trusted-forwarder nonce for a signer, and a
+//! [`GasOracleMiddleware`] that fills EIP-1559 fees and estimates gas for a
+//! meta-transaction before `Tx::sign_to_request_with_estimated_gas`.
+//!
+//! ref. <https://github.com/gakonst/ethers-rs/blob/master/ethers-middleware/tests/nonce_manager.rs>
+
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+};
+
+use crate::{
+    errors::{Error, Result},
+    evm::abi,
+};
+use ethers_core::{
+    abi::{Function, Param, ParamType, StateMutability, Token},
+    types::transaction::eip2718::TypedTransaction,
+};
+use ethers_providers::{Http, Middleware, Provider, RetryClient};
+use primitive_types::{H160, U256};
+
+/// Resolves and caches the trusted-forwarder nonce for a given signer via the
+/// forwarder's `getNonce(address)` view, incrementing the cached value as
+/// meta-transactions are signed so callers stop re-implementing the raw
+/// `provider.call` + `U256::from_big_endian` plumbing in every example.
+#[derive(Clone)]
+pub struct ForwarderNonceMiddleware {
+    provider: Arc<Provider<RetryClient<Http>>>,
+    forwarder: H160,
+    cache: Arc<Mutex<HashMap<H160, U256>>>,
+}
+
+impl ForwarderNonceMiddleware {
+    /// Wraps the provider with a nonce resolver for the given trusted forwarder.
+    #[must_use]
+    pub fn new(provider: Arc<Provider<RetryClient<Http>>>, forwarder: H160) -> Self {
+        Self {
+            provider,
+            forwarder,
+            cache: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    /// Returns the forwarder nonce for `from`, querying `getNonce(address)`
+    /// on-chain the first time and serving the cached value afterwards.
+    pub async fn get_nonce(&self, from: H160) -> Result<U256> {
+        if let Some(nonce) = self.cache.lock().unwrap().get(&from).copied() {
+            return Ok(nonce);
+        }
+        let nonce = self.fetch_nonce(from).await?;
+        self.cache.lock().unwrap().insert(from, nonce);
+        Ok(nonce)
+    }
+
+    /// Marks the cached nonce for `from` as consumed, bumping it by one so the
+    /// next signed meta-transaction uses a fresh value without another RPC.
+    pub fn increment(&self, from: H160) {
+        let mut cache = self.cache.lock().unwrap();
+        let entry = cache.entry(from).or_default();
+        *entry = entry.saturating_add(U256::one());
+    }
+
+    /// Resolves the nonce to use for the *next* signed meta-transaction:
+    /// queries the forwarder's on-chain nonce, reconciles it against the
+    /// local cache (the cached value wins when it's ahead, e.g. a batch of
+    /// requests signed back-to-back that haven't been mined yet; the
+    /// on-chain value wins when it's ahead, e.g. another process consumed
+    /// nonces in the meantime), caches the value one past what's returned,
+    /// and returns it.
+    pub async fn next_nonce(&self, from: H160) -> Result<U256> {
+        let on_chain = self.fetch_nonce(from).await?;
+        let mut cache = self.cache.lock().unwrap();
+        let next = match cache.get(&from) {
+            Some(cached) if *cached > on_chain => *cached,
+            _ => on_chain,
+        };
+        cache.insert(from, next.saturating_add(U256::one()));
+        Ok(next)
+    }
+
+    /// Clears the cached nonce for `from`, forcing the next [`Self::get_nonce`]
+    /// or [`Self::next_nonce`] call to re-query the forwarder. Use this when a
+    /// signed request that consumed a cached nonce is dropped instead of
+    /// submitted, so the next signed request doesn't skip ahead of the
+    /// on-chain nonce.
+    pub fn reset(&self, from: H160) {
+        self.cache.lock().unwrap().remove(&from);
+    }
+
+    async fn fetch_nonce(&self, from: H160) -> Result<U256> {
+        // "getNonce(address from) returns (uint256 nonce)"
+        let func = Function {
+            name: "getNonce".to_string(),
+            inputs: vec![Param {
+                name: "from".to_string(),
+                kind: ParamType::Address,
+                internal_type: None,
+            }],
+            outputs: vec![Param {
+                name: "nonce".to_string(),
+                kind: ParamType::Uint(256),
+                internal_type: None,
+            }],
+            constant: None,
+            state_mutability: StateMutability::NonPayable,
+        };
+        let calldata =
+            abi::encode_calldata(func, &[Token::Address(from)]).map_err(|e| Error::Other {
+                message: format!("failed to encode getNonce calldata '{}'", e),
+                retryable: false,
+            })?;
+
+        let tx: TypedTransaction = ethers::prelude::Eip1559TransactionRequest::new()
+            .to(self.forwarder)
+            .data(calldata)
+            .into();
+        let out = self
+            .provider
+            .call(&tx, None)
+            .await
+            .map_err(|e| Error::API {
+                message: format!("failed forwarder getNonce call '{}'", e),
+                retryable: true,
+            })?;
+        Ok(U256::from_big_endian(&out))
+    }
+}
+
+/// Fills EIP-1559 fee fields and estimates gas for a meta-transaction, so the
+/// relay path no longer hardcodes `U256::from(300000)`.
+#[derive(Clone, Debug)]
+pub struct GasOracleMiddleware {
+    provider: Arc<Provider<RetryClient<Http>>>,
+    /// Multiplier applied to the latest block base fee when computing
+    /// `maxFeePerGas`.
+    pub base_fee_multiplier: u64,
+    /// Fallback priority fee (wei) used when the node does not expose
+    /// `eth_maxPriorityFeePerGas`.
+    pub default_priority_fee: U256,
+}
+
+impl GasOracleMiddleware {
+    /// Wraps the provider with default fee policy (2x base fee, 2 GWEI tip).
+    #[must_use]
+    pub fn new(provider: Arc<Provider<RetryClient<Http>>>) -> Self {
+        Self {
+            provider,
+            base_fee_multiplier: 2,
+            default_priority_fee: U256::from(2_000_000_000u64),
+        }
+    }
+
+    /// Populates `maxFeePerGas`, `maxPriorityFeePerGas`, and the gas limit on a
+    /// meta-transaction from current chain state.
+    pub async fn fill(&self, tx: &mut TypedTransaction) -> Result<()> {
+        let priority_fee = match self
+            .provider
+            .request::<_, U256>("eth_maxPriorityFeePerGas", ())
+            .await
+        {
+            Ok(fee) => fee,
+            Err(_) => self.default_priority_fee,
+        };
+
+        let block = self
+            .provider
+            .get_block(ethers_core::types::BlockNumber::Latest)
+            .await
+            .map_err(|e| Error::API {
+                message: format!("failed get_block '{}'", e),
+                retryable: true,
+            })?;
+        let base_fee = block
+            .and_then(|b| b.base_fee_per_gas)
+            .unwrap_or_else(U256::zero);
+        let max_fee = base_fee
+            .saturating_mul(U256::from(self.base_fee_multiplier))
+            .saturating_add(priority_fee);
+
+        if let TypedTransaction::Eip1559(ref mut inner) = tx {
+            inner.max_fee_per_gas = Some(max_fee);
+            inner.max_priority_fee_per_gas = Some(priority_fee);
+        }
+
+        let estimated = self
+            .provider
+            .estimate_gas(tx, None)
+            .await
+            .map_err(|e| Error::API {
+                message: format!("failed estimate_gas '{}'", e),
+                retryable: true,
+            })?;
+        tx.set_gas(estimated);
+        Ok(())
+    }
+}
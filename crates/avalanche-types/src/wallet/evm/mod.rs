@@ -1,4 +1,5 @@
 pub mod eip1559;
+pub mod middleware;
 
 use std::{ops::Div, sync::Arc, time::Duration};
 
@@ -14,6 +15,7 @@ use ethers::{
     },
     utils::Units::Gwei,
 };
+use ethers_core::types::transaction::eip2718;
 use ethers_providers::{Http, HttpRateLimitRetryPolicy, Provider, RetryClient};
 use lazy_static::lazy_static;
 use primitive_types::U256;
@@ -176,6 +178,93 @@ where
     Ok(nonce_middleware)
 }
 
+/// Default multiplier applied to the latest block base fee when deriving
+/// `maxFeePerGas` in [`fill_1559_fees`].
+pub const DEFAULT_BASE_FEE_MULTIPLIER: u64 = 2;
+
+/// Default priority fee (2 GWEI, in wei) used when the node does not expose
+/// `eth_maxPriorityFeePerGas`.
+pub const DEFAULT_PRIORITY_FEE: u64 = 2_000_000_000;
+
+/// Fills the EIP-1559 fee fields and gas limit on `tx` from current chain state,
+/// replacing the magic-constant gas guesses scattered across the EVM examples.
+///
+/// Queries the latest block base fee and `eth_maxPriorityFeePerGas` (falling
+/// back to `priority_fee_default` when the node does not implement it), computes
+/// `maxFeePerGas = base_fee * base_fee_multiplier + priority_fee`, and runs
+/// `eth_estimateGas` to populate the gas limit. Transient RPC errors are retried
+/// up to `max_retries` with `backoff` between attempts, mirroring the
+/// [`new_provider`] retry parameters.
+pub async fn fill_1559_fees(
+    provider: Arc<Provider<RetryClient<Http>>>,
+    tx: &mut eip2718::TypedTransaction,
+    base_fee_multiplier: u64,
+    priority_fee_default: U256,
+    max_retries: u32,
+    backoff: Duration,
+) -> Result<()> {
+    use ethers_providers::Middleware;
+
+    let priority_fee = provider
+        .request::<_, U256>("eth_maxPriorityFeePerGas", ())
+        .await
+        .unwrap_or(priority_fee_default);
+
+    let mut attempt = 0;
+    loop {
+        let block = provider
+            .get_block(ethers_core::types::BlockNumber::Latest)
+            .await;
+        match block {
+            Ok(block) => {
+                let base_fee = block
+                    .and_then(|b| b.base_fee_per_gas)
+                    .unwrap_or_else(U256::zero);
+                let max_fee = base_fee
+                    .saturating_mul(U256::from(base_fee_multiplier))
+                    .saturating_add(priority_fee);
+                if let eip2718::TypedTransaction::Eip1559(ref mut inner) = tx {
+                    inner.max_fee_per_gas = Some(max_fee);
+                    inner.max_priority_fee_per_gas = Some(priority_fee);
+                }
+                break;
+            }
+            Err(e) => {
+                attempt += 1;
+                if attempt > max_retries {
+                    return Err(Error::API {
+                        message: format!("failed get_block after {max_retries} retries '{}'", e),
+                        retryable: false,
+                    });
+                }
+                log::warn!("[retry {attempt:02}] failed get_block, retrying: {e}");
+                tokio::time::sleep(backoff).await;
+            }
+        }
+    }
+
+    let mut attempt = 0;
+    loop {
+        match provider.estimate_gas(tx, None).await {
+            Ok(estimated) => {
+                tx.set_gas(estimated);
+                return Ok(());
+            }
+            Err(e) => {
+                attempt += 1;
+                if attempt > max_retries {
+                    return Err(Error::API {
+                        message: format!("failed estimate_gas after {max_retries} retries '{}'", e),
+                        retryable: false,
+                    });
+                }
+                log::warn!("[retry {attempt:02}] failed estimate_gas, retrying: {e}");
+                tokio::time::sleep(backoff).await;
+            }
+        }
+    }
+}
+
 lazy_static! {
     pub static ref GWEI: U256 = U256::from(10).checked_pow(Gwei.as_num().into()).unwrap();
 }
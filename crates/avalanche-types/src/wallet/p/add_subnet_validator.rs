@@ -5,7 +5,8 @@ use crate::{
     formatting,
     ids::{self, node},
     jsonrpc::client::p as client_p,
-    key, platformvm, txs,
+    key, platformvm,
+    txs::{self, Signable},
 };
 use chrono::{DateTime, NaiveDateTime, Utc};
 use tokio::time::{sleep, Duration, Instant};
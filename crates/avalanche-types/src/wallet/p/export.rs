@@ -2,7 +2,8 @@ use crate::{
     errors::{Error, Result},
     formatting, ids,
     jsonrpc::client::p as client_p,
-    key, platformvm, txs,
+    key, platformvm,
+    txs::{self, Signable},
 };
 use tokio::time::{sleep, Duration, Instant};
 
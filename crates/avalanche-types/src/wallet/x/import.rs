@@ -5,8 +5,9 @@ use crate::{
     choices::status::Status,
     errors::{Error, Result},
     formatting, ids,
-    jsonrpc::client::x as client_x,
-    key, txs,
+    jsonrpc::client::{provider as client_provider, x as client_x},
+    key,
+    txs::{self, Signable},
 };
 use tokio::time::{sleep, Duration, Instant};
 
@@ -34,6 +35,15 @@ where
 
     /// Set to true to return transaction Id for "issue" in dry mode.
     pub dry_mode: bool,
+
+    /// Maximum number of UTXOs requested per "getUTXOs" page while
+    /// collecting all spendable inputs.
+    pub utxos_page_size: u32,
+
+    /// Optional retry policy wrapping "issue_tx"/"get_tx_status" calls, so
+    /// transient rate-limit/transport errors are retried instead of failing
+    /// the whole issuance/polling flow. "None" makes a single attempt per call.
+    pub retry_policy: Option<client_provider::RetryPolicy>,
 }
 
 impl<T> Tx<T>
@@ -49,6 +59,8 @@ where
             poll_interval: Duration::from_millis(700),
             poll_timeout: Duration::from_secs(300),
             dry_mode: false,
+            utxos_page_size: 1024,
+            retry_policy: None,
         }
     }
 
@@ -94,6 +106,36 @@ where
         self
     }
 
+    /// Sets the page size used to paginate "getUTXOs" while collecting all
+    /// spendable inputs.
+    #[must_use]
+    pub fn utxos_page_size(mut self, utxos_page_size: u32) -> Self {
+        self.utxos_page_size = utxos_page_size;
+        self
+    }
+
+    /// Sets the retry policy wrapping "issue_tx"/"get_tx_status" calls.
+    #[must_use]
+    pub fn retry_policy(mut self, retry_policy: client_provider::RetryPolicy) -> Self {
+        self.retry_policy = Some(retry_policy);
+        self
+    }
+
+    /// Runs `f` once, or through `self.retry_policy` when one is set.
+    async fn with_retry<F, Fut, R>(&self, f: F) -> Result<R>
+    where
+        F: FnMut() -> Fut,
+        Fut: std::future::Future<Output = Result<R>>,
+    {
+        match &self.retry_policy {
+            Some(policy) => policy.retry(f).await,
+            None => {
+                let mut f = f;
+                f().await
+            }
+        }
+    }
+
     /// Issues the import transaction and returns the transaction Id.
     pub async fn issue(&self) -> Result<ids::Id> {
         let picked_http_rpc = self.inner.inner.pick_base_http_url();
@@ -103,16 +145,38 @@ where
             picked_http_rpc.1
         );
 
-        // TODO: paginate next results
-        let utxos = client_x::get_utxos(&picked_http_rpc.1, &self.inner.inner.x_address).await?;
-        let utxos_result = utxos.result.unwrap();
-        let utxos = utxos_result.utxos.unwrap();
-        log::debug!(
-            "fetched UTXOs for inputs: numFetched {:?}, endIndex {:?} and {} UTXOs",
-            utxos_result.num_fetched,
-            utxos_result.end_index,
-            utxos.len()
-        );
+        let mut utxos: Vec<txs::utxo::Utxo> = Vec::new();
+        let mut start_index = None;
+        loop {
+            let resp = client_x::get_utxos_page(
+                &picked_http_rpc.1,
+                &self.inner.inner.x_address,
+                self.utxos_page_size,
+                start_index.clone(),
+            )
+            .await?;
+            let result = resp.result.unwrap();
+            let num_fetched = result.num_fetched;
+            let page = result.utxos.unwrap_or_default();
+            log::debug!(
+                "fetched UTXOs page: numFetched {}, endIndex {:?} and {} UTXOs",
+                num_fetched,
+                result.end_index,
+                page.len()
+            );
+            utxos.extend(page);
+
+            // stop once the node returns a short page, or the cursor stops
+            // advancing (guards against an infinite loop on a stuck node).
+            if num_fetched < self.utxos_page_size || result.end_index == start_index {
+                break;
+            }
+            if result.end_index.is_none() {
+                break;
+            }
+            start_index = result.end_index;
+        }
+        log::debug!("fetched {} total UTXOs for inputs", utxos.len());
 
         // ref. "avalanchego/vms/avm#Service.SendMultiple"
         let now_unix = SystemTime::now()
@@ -211,7 +275,9 @@ where
             .unwrap()
             .tx_bytes_with_signatures;
         let hex_tx = formatting::encode_hex_with_checksum(&tx_bytes_with_signatures);
-        let resp = client_x::issue_tx(&picked_http_rpc.1, &hex_tx).await?;
+        let resp = self
+            .with_retry(|| client_x::issue_tx(&picked_http_rpc.1, &hex_tx))
+            .await?;
 
         if resp.result.is_none() {
             return Err(Error::API {
@@ -240,7 +306,9 @@ where
                 break;
             }
 
-            let resp = client_x::get_tx_status(&picked_http_rpc.1, &tx_id.to_string()).await?;
+            let resp = self
+                .with_retry(|| client_x::get_tx_status(&picked_http_rpc.1, &tx_id.to_string()))
+                .await?;
 
             let status = resp.result.unwrap().status;
             if status == Status::Accepted {
@@ -0,0 +1,311 @@
+//! Avalanche Warp Message construction and threshold verification.
+//!
+//! A Warp Message lets one Avalanche chain prove to another that a payload was
+//! endorsed by a weighted threshold of a source subnet's validators. The flow
+//! mirrors a sync-committee aggregate check: every validator signs the same
+//! [`UnsignedMessage`] bytes, the participating public keys (selected by a
+//! [`BitSet`] over the canonical validator order) are aggregated, and a single
+//! aggregate BLS signature is verified against the aggregate public key.
+//! ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm/warp>
+use std::io::{self, Error, ErrorKind};
+
+use crate::{
+    ids,
+    key::bls::{public_key, signature},
+    packer::Packer,
+};
+
+/// Codec version prefixed to the serialized unsigned message bytes.
+pub const CODEC_VERSION: u16 = 0;
+
+/// Maps a packer error into the [`std::io`] error surface used across the
+/// `warp` and `key::bls` modules.
+fn pack_err<T>(r: crate::errors::Result<T>) -> io::Result<T> {
+    r.map_err(|e| Error::new(ErrorKind::Other, format!("failed to pack warp message: {e}")))
+}
+
+/// The canonical, unsigned payload every validator signs: the network the
+/// message originates on, the chain it is emitted from, and the opaque payload.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnsignedMessage {
+    pub network_id: u32,
+    pub source_chain_id: ids::Id,
+    pub payload: Vec<u8>,
+}
+
+impl UnsignedMessage {
+    pub fn new(network_id: u32, source_chain_id: ids::Id, payload: Vec<u8>) -> Self {
+        Self {
+            network_id,
+            source_chain_id,
+            payload,
+        }
+    }
+
+    /// Serializes the message to its canonical wire bytes -- the exact bytes
+    /// each validator's BLS signature is computed over.
+    pub fn to_bytes(&self) -> io::Result<Vec<u8>> {
+        let packer = Packer::new((1 << 31) - 1, 128);
+        pack_err(packer.pack_u16(CODEC_VERSION))?;
+        pack_err(packer.pack_u32(self.network_id))?;
+        pack_err(packer.pack_bytes(self.source_chain_id.as_ref()))?;
+        pack_err(packer.pack_bytes_with_header(&self.payload))?;
+        Ok(packer.take_bytes().to_vec())
+    }
+}
+
+/// A validator entry in the canonical validator-set order: its BLS public key
+/// and its stake weight.
+#[derive(Debug, Clone)]
+pub struct Validator {
+    pub public_key: public_key::Key,
+    pub weight: u64,
+}
+
+/// The fraction of total stake weight that must sign for a [`BitSetSignature`]
+/// to verify: `signed_weight * denominator >= total_weight * numerator`.
+#[derive(Debug, Clone, Copy)]
+pub struct Threshold {
+    pub numerator: u64,
+    pub denominator: u64,
+}
+
+/// A compact set of validator indices, one bit per validator with the lowest
+/// index stored in the most-significant bit of the first byte.
+/// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/utils/set#Bits>
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BitSet {
+    bytes: Vec<u8>,
+}
+
+impl BitSet {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Marks the validator at `i` as a signer, growing the backing storage as
+    /// needed.
+    pub fn add(&mut self, i: usize) {
+        let byte = i / 8;
+        if byte >= self.bytes.len() {
+            self.bytes.resize(byte + 1, 0);
+        }
+        self.bytes[byte] |= 0x80 >> (i % 8);
+    }
+
+    /// Returns whether the validator at `i` is marked.
+    pub fn contains(&self, i: usize) -> bool {
+        let byte = i / 8;
+        byte < self.bytes.len() && self.bytes[byte] & (0x80 >> (i % 8)) != 0
+    }
+
+    /// Number of set bits.
+    pub fn len(&self) -> usize {
+        self.bytes.iter().map(|b| b.count_ones() as usize).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.bytes.iter().all(|b| *b == 0)
+    }
+
+    /// Index one past the highest set bit, i.e. the smallest validator-set size
+    /// this bitset could have been built against.
+    fn min_capacity(&self) -> usize {
+        for (byte_idx, b) in self.bytes.iter().enumerate().rev() {
+            if *b != 0 {
+                return byte_idx * 8 + (8 - b.trailing_zeros() as usize);
+            }
+        }
+        0
+    }
+
+    pub fn to_bytes(&self) -> Vec<u8> {
+        self.bytes.clone()
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Self {
+        Self {
+            bytes: bytes.to_vec(),
+        }
+    }
+}
+
+/// An aggregate signature over an [`UnsignedMessage`]: which validators signed
+/// (by index, canonical order) plus the single aggregated BLS signature.
+#[derive(Debug, Clone)]
+pub struct BitSetSignature {
+    pub signers: BitSet,
+    pub signature: signature::Sig,
+}
+
+/// Aggregates the collected per-validator signatures -- each paired with the
+/// signer's index in the canonical validator set -- into a [`BitSetSignature`].
+/// Rejects duplicate or out-of-range indices so the resulting bitset is
+/// canonical.
+pub fn aggregate_signatures(
+    num_validators: usize,
+    signed: &[(usize, signature::Sig)],
+) -> io::Result<BitSetSignature> {
+    let mut signers = BitSet::new();
+    let mut sigs = Vec::with_capacity(signed.len());
+    for (index, sig) in signed {
+        if *index >= num_validators {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                format!("signer index {index} out of range for {num_validators} validators"),
+            ));
+        }
+        if signers.contains(*index) {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                format!("duplicate signer index {index}"),
+            ));
+        }
+        signers.add(*index);
+        sigs.push(sig.clone());
+    }
+
+    let signature = signature::aggregate(&sigs)?;
+    Ok(BitSetSignature { signers, signature })
+}
+
+impl BitSetSignature {
+    /// Verifies the aggregate signature against the ordered validator set for
+    /// `message` at the requested stake-weight `threshold`.
+    ///
+    /// Rejects a bitset whose highest set bit falls outside the validator set
+    /// and requires the participating stake weight to meet the threshold before
+    /// the aggregate BLS signature is checked.
+    pub fn verify(
+        &self,
+        message: &UnsignedMessage,
+        validators: &[Validator],
+        threshold: Threshold,
+    ) -> io::Result<()> {
+        if self.signers.min_capacity() > validators.len() {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "bitset length disagrees with validator-set size".to_string(),
+            ));
+        }
+        if threshold.denominator == 0 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "threshold denominator must be non-zero".to_string(),
+            ));
+        }
+
+        let mut pubkeys = Vec::with_capacity(self.signers.len());
+        let mut signed_weight: u128 = 0;
+        let mut total_weight: u128 = 0;
+        for (i, v) in validators.iter().enumerate() {
+            total_weight += u128::from(v.weight);
+            if self.signers.contains(i) {
+                pubkeys.push(v.public_key);
+                signed_weight += u128::from(v.weight);
+            }
+        }
+
+        if pubkeys.is_empty() {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "no validators signed the message".to_string(),
+            ));
+        }
+
+        // signed_weight / total_weight >= numerator / denominator,
+        // evaluated via cross-multiplication to stay in integer arithmetic.
+        if signed_weight * u128::from(threshold.denominator)
+            < total_weight * u128::from(threshold.numerator)
+        {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                format!(
+                    "insufficient signed weight {signed_weight} of {total_weight} for threshold {}/{}",
+                    threshold.numerator, threshold.denominator
+                ),
+            ));
+        }
+
+        let agg_pubkey = public_key::aggregate(&pubkeys)?;
+        if !self.signature.verify(&message.to_bytes()?, &agg_pubkey) {
+            return Err(Error::new(
+                ErrorKind::Other,
+                "aggregate signature verification failed".to_string(),
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- warp::message::test_bit_set_signature --exact --show-output
+#[test]
+fn test_bit_set_signature() {
+    use crate::key::bls::private_key::Key;
+
+    let message = UnsignedMessage::new(
+        9999,
+        ids::Id::from_slice(&[0x07; ids::LEN]),
+        vec![0x01, 0x02, 0x03, 0x04],
+    );
+    let msg_bytes = message.to_bytes().unwrap();
+
+    // three validators signing, one (index 1) abstaining
+    let sks: Vec<Key> = (0..4).map(|_| Key::generate().unwrap()).collect();
+    let validators: Vec<Validator> = sks
+        .iter()
+        .map(|sk| Validator {
+            public_key: sk.to_public_key(),
+            weight: 25,
+        })
+        .collect();
+
+    let signed: Vec<(usize, signature::Sig)> = [0usize, 2, 3]
+        .iter()
+        .map(|i| (*i, sks[*i].sign(&msg_bytes)))
+        .collect();
+
+    let bss = aggregate_signatures(validators.len(), &signed).unwrap();
+    assert_eq!(bss.signers.len(), 3);
+    assert!(bss.signers.contains(0));
+    assert!(!bss.signers.contains(1));
+
+    // 75% signed, require 2/3 -> passes
+    bss.verify(
+        &message,
+        &validators,
+        Threshold {
+            numerator: 2,
+            denominator: 3,
+        },
+    )
+    .unwrap();
+
+    // require unanimity -> fails on weight
+    assert!(bss
+        .verify(
+            &message,
+            &validators,
+            Threshold {
+                numerator: 1,
+                denominator: 1,
+            },
+        )
+        .is_err());
+
+    // duplicate and out-of-range indices are rejected at aggregation time
+    assert!(aggregate_signatures(4, &[(0, sks[0].sign(&msg_bytes)), (0, sks[0].sign(&msg_bytes))]).is_err());
+    assert!(aggregate_signatures(4, &[(4, sks[0].sign(&msg_bytes))]).is_err());
+
+    // a bitset that references more validators than exist is rejected
+    assert!(bss
+        .verify(
+            &message,
+            &validators[..2],
+            Threshold {
+                numerator: 1,
+                denominator: 2,
+            },
+        )
+        .is_err());
+}
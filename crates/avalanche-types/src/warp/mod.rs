@@ -1,4 +1,5 @@
 pub mod client;
+pub mod message;
 
 use std::io::Result;
 use crate::proto::warp::SignResponse;
@@ -0,0 +1,47 @@
+//! Drift test for the vendored `sync.DB` gRPC bindings.
+//!
+//! The generated `db_client`/`db_server` modules are checked in under
+//! `src/proto/pb/sync.tonic.rs` so that `protoc`/`tonic-build` are not required
+//! for a normal build. This test regenerates the bindings from the `.proto`
+//! sources into a temporary directory and byte-compares them against the
+//! committed file, failing if the two have drifted.
+//!
+//! It is `#[ignore]`d by default because it needs the protobuf toolchain and a
+//! checkout of the `.proto` sources; point `AVALANCHEGO_PROTO_DIR` at a
+//! directory containing `sync/sync.proto` (plus its imports) and run with
+//! `cargo test --test bootstrap -- --ignored`.
+
+use std::{env, fs, path::PathBuf};
+
+#[test]
+#[ignore]
+fn sync_bindings_do_not_drift() {
+    let proto_dir = match env::var("AVALANCHEGO_PROTO_DIR") {
+        Ok(v) => PathBuf::from(v),
+        Err(_) => {
+            eprintln!("AVALANCHEGO_PROTO_DIR not set; skipping drift check");
+            return;
+        }
+    };
+
+    let out_dir = tempfile::tempdir().expect("failed to create temp dir");
+
+    tonic_build::configure()
+        .build_client(true)
+        .build_server(true)
+        .out_dir(out_dir.path())
+        .compile(&[proto_dir.join("sync/sync.proto")], &[proto_dir])
+        .expect("failed to regenerate sync bindings");
+
+    let regenerated = fs::read_to_string(out_dir.path().join("sync.tonic.rs"))
+        .expect("regenerated sync.tonic.rs missing");
+    let committed = fs::read_to_string(
+        PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("src/proto/pb/sync.tonic.rs"),
+    )
+    .expect("committed sync.tonic.rs missing");
+
+    assert_eq!(
+        regenerated, committed,
+        "vendored sync bindings are stale; regenerate with the proto codegen script"
+    );
+}
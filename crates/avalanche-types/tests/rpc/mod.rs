@@ -32,7 +32,7 @@ async fn test_http_service() {
     let mut client = HttpClient::new(client_conn);
 
     let foo_request = generate_http_request("foo", "http://127.0.0.1:1234", &[]);
-    let foo_resp = client.serve_http_simple(foo_request).await;
+    let foo_resp = client.serve_http_simple(foo_request.into()).await;
     assert!(foo_resp.is_ok());
     let foo_resp = foo_resp.unwrap();
 
@@ -49,7 +49,7 @@ async fn test_http_service() {
     }
 
     let bar_request = generate_http_request("bar", "http://127.0.0.1:1234", &["John"]);
-    let bar_resp = client.serve_http_simple(bar_request).await;
+    let bar_resp = client.serve_http_simple(bar_request.into()).await;
     assert!(bar_resp.is_ok());
     let bar_resp = bar_resp.unwrap();
 